@@ -8,6 +8,7 @@ struct CallAssumptions {
     uint256 settleChainId; // Chain id for which assumptions was made.
     uint256 settleBlockNumber; // Block number for which assumptions was made.
     bytes32 settleBlockHash; // Hash of the block at the specified block number.
+    bytes32 trustedNotaryKeysHash; // Hash of the notary keys web proofs were restricted to. Zero if not restricted.
 }
 
 library CallAssumptionsLib {
@@ -18,8 +19,14 @@ library CallAssumptionsLib {
     uint256 public constant SETTLE_CHAIN_ID_ENCODING_LENGTH = ETH_WORD_SIZE;
     uint256 public constant SETTLE_BLOCK_NUMBER_ENCODING_LENGTH = ETH_WORD_SIZE;
     uint256 public constant SETTLE_BLOCK_HASH_ENCODING_LENGTH = ETH_WORD_SIZE;
+    uint256 public constant TRUSTED_NOTARY_KEYS_HASH_ENCODING_LENGTH = ETH_WORD_SIZE;
 
     uint256 public constant CALL_ASSUMPTIONS_ENCODING_LENGTH = PROVER_CONTRACT_ADDRESS_ENCODING_LENGTH
         + FUNCTION_SELECTOR_ENCODING_LENGTH + SETTLE_CHAIN_ID_ENCODING_LENGTH + SETTLE_BLOCK_NUMBER_ENCODING_LENGTH
-        + SETTLE_BLOCK_HASH_ENCODING_LENGTH;
+        + SETTLE_BLOCK_HASH_ENCODING_LENGTH + TRUSTED_NOTARY_KEYS_HASH_ENCODING_LENGTH;
+
+    /// @notice Computes `trustedNotaryKeysHash` for the given PEM-encoded notary public keys.
+    function trustedNotaryKeysHash(string[] memory notaryPubKeys) internal pure returns (bytes32) {
+        return keccak256(abi.encode(notaryPubKeys));
+    }
 }
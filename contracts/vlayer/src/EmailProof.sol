@@ -18,12 +18,39 @@ struct VerificationData {
     uint64 validUntil;
     bytes signature;
     bytes pubKey;
+    bytes32 keyId; // sha256(pubKey), checked by the precompile
+    bytes dnssecProof; // abi.encode(DnssecProof), replaces the notary signature and key if not empty
+}
+
+// Records in DNS wire format
+struct SignedRRset {
+    bytes[] records;
+    bytes[] signatures; // RRSIG records covering the RRset
+}
+
+struct DnssecProof {
+    SignedRRset[] dnskeys; // DNSKEY records of each zone, starting with the root
+    SignedRRset[] delegations; // DS records of each zone but the root, signed by its parent
+    SignedRRset answer; // TXT records containing the DNS record
 }
 
 struct UnverifiedEmail {
     string email;
-    DnsRecord dnsRecord;
-    VerificationData verificationData;
+    DnsRecord[] dnsRecords; // Candidate DKIM keys of the sender domain, e.g. both keys during a rollover
+    VerificationData[] verificationData; // One entry per DNS record, in the same order
+    string[] requiredSignedHeaders; // Headers the signature must cover, empty for the default: from, to, subject
+}
+
+struct UnverifiedArcEmail {
+    string email;
+    DnsRecord[] dnsRecords; // Keys of all ARC sealers and of the most recent ARC-Message-Signature
+    VerificationData[] verificationData; // One entry per DNS record, in the same order
+    string[] requiredSignedHeaders; // Headers the signature must cover, empty for the default: from, to, subject
+}
+
+struct EmailHeader {
+    string name; // Lowercase header name
+    string value;
 }
 
 struct VerifiedEmail {
@@ -31,6 +58,32 @@ struct VerifiedEmail {
     string to;
     string subject;
     string body;
+    string htmlBody; // Empty if the email has no text/html part
+    EmailHeader[] signedHeaders; // Headers covered by the DKIM signature, in the order they were signed
+    string[] requiredSignedHeaders; // Lowercase names of the headers the email was required to sign, always with `from`
+    bytes32 nullifier; // keccak256(abi.encode(fromDomain, messageId)) of the signed Message-ID, zero if it isn't signed
+}
+
+struct VerifiedArcEmail {
+    VerifiedEmail email;
+    string[] sealingDomains; // ARC-Seal signing domains, starting from the first sealer
+}
+
+struct AttachmentQuery {
+    string filename; // Ignored if empty
+    string contentType; // MIME type, ignored if empty
+}
+
+struct EmailAttachment {
+    string filename;
+    string contentType;
+    bytes content; // Decoded according to Content-Transfer-Encoding
+    bytes32 contentHash; // sha256(content)
+}
+
+struct VerifiedEmailWithAttachment {
+    VerifiedEmail email;
+    EmailAttachment attachment;
 }
 
 // Generated with: `openssl pkey -pubin -in rust/verifiable_dns/assets/public_key.pem -outform DER | xxd -p`
@@ -39,28 +92,77 @@ bytes32 constant TEST_DNS_PUBLIC_KEY_HASH = 0xc16646301c7615357b8f8ee125956b0e5f
 
 library EmailProofLib {
     function verify(UnverifiedEmail memory unverifiedEmail) internal view returns (VerifiedEmail memory) {
-        require(unverifiedEmail.verificationData.validUntil > block.timestamp, "EmailProof: expired DNS verification");
+        verifyDnsVerificationData(unverifiedEmail.verificationData);
+
+        (bool success, bytes memory returnData) = Precompiles.VERIFY_EMAIL.staticcall(abi.encode(unverifiedEmail));
+        Address.verifyCallResult(success, returnData);
+
+        VerifiedEmail memory email = abi.decode(returnData, (VerifiedEmail));
+        return email;
+    }
+
+    /// @notice Verifies the email and returns the only attachment matching the query.
+    /// @dev Reverts if no attachment or more than one attachment matches.
+    function verifyWithAttachment(UnverifiedEmail memory unverifiedEmail, AttachmentQuery memory query)
+        internal
+        view
+        returns (VerifiedEmailWithAttachment memory)
+    {
+        verifyDnsVerificationData(unverifiedEmail.verificationData);
+
+        (bool success, bytes memory returnData) =
+            Precompiles.VERIFY_EMAIL_ATTACHMENT.staticcall(abi.encode(unverifiedEmail, query));
+        Address.verifyCallResult(success, returnData);
+
+        return abi.decode(returnData, (VerifiedEmailWithAttachment));
+    }
+
+    /// @notice Verifies a forwarded email through its ARC chain instead of the sender's DKIM signature.
+    /// @dev The email content is vouched for by the sealers only - check `sealingDomains` before trusting it.
+    function verifyArc(UnverifiedArcEmail memory unverifiedEmail) internal view returns (VerifiedArcEmail memory) {
+        verifyDnsVerificationData(unverifiedEmail.verificationData);
+
+        (bool success, bytes memory returnData) = Precompiles.VERIFY_ARC_EMAIL.staticcall(abi.encode(unverifiedEmail));
+        Address.verifyCallResult(success, returnData);
+
+        return abi.decode(returnData, (VerifiedArcEmail));
+    }
+
+    function verifyDnsVerificationData(VerificationData[] memory verificationData) private view {
+        for (uint256 i = 0; i < verificationData.length; i++) {
+            verifyDnsVerificationData(verificationData[i]);
+        }
+    }
+
+    function verifyDnsVerificationData(VerificationData memory verificationData) private view {
+        require(verificationData.validUntil > block.timestamp, "EmailProof: expired DNS verification");
+        if (verificationData.dnssecProof.length > 0) {
+            // Validated by the precompile against the root trust anchors, no notary key involved
+            return;
+        }
         if (ChainIdLibrary.isTestEnv()) {
-            require(
-                keccak256(unverifiedEmail.verificationData.pubKey) == TEST_DNS_PUBLIC_KEY_HASH,
-                "Not a valid VDNS hardcoded key"
-            );
+            require(keccak256(verificationData.pubKey) == TEST_DNS_PUBLIC_KEY_HASH, "Not a valid VDNS hardcoded key");
         } else if (ChainIdLibrary.isMainnet()) {
             require(
-                MainnetStableDeployment.repository().isDnsKeyValid(unverifiedEmail.verificationData.pubKey),
+                MainnetStableDeployment.repository().isDnsKeyValid(verificationData.pubKey),
                 "Not a valid VDNS public key"
             );
         } else {
             require(
-                TestnetStableDeployment.repository().isDnsKeyValid(unverifiedEmail.verificationData.pubKey),
+                TestnetStableDeployment.repository().isDnsKeyValid(verificationData.pubKey),
                 "Not a valid VDNS public key"
             );
         }
+    }
 
-        (bool success, bytes memory returnData) = Precompiles.VERIFY_EMAIL.staticcall(abi.encode(unverifiedEmail));
-        Address.verifyCallResult(success, returnData);
-
-        VerifiedEmail memory email = abi.decode(returnData, (VerifiedEmail));
-        return email;
+    /// @notice Returns the value of the first signed header with the given lowercase name.
+    function signedHeader(VerifiedEmail memory email, string memory name) internal pure returns (string memory) {
+        bytes32 nameHash = keccak256(bytes(name));
+        for (uint256 i = 0; i < email.signedHeaders.length; i++) {
+            if (keccak256(bytes(email.signedHeaders[i].name)) == nameHash) {
+                return email.signedHeaders[i].value;
+            }
+        }
+        revert("EmailProof: header not signed");
     }
 }
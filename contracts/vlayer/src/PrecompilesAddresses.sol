@@ -16,8 +16,17 @@ library Precompiles {
     address public constant JSON_GET_INT = address(uint160(PRECOMPILES) + 3);
     address public constant JSON_GET_BOOL = address(uint160(PRECOMPILES) + 4);
     address public constant JSON_GET_FLOAT_AS_INT = address(uint160(PRECOMPILES) + 5);
+    address public constant JSON_GET_ARRAY_LENGTH = address(uint160(PRECOMPILES) + 6);
+    address public constant JSON_GET_KEYS = address(uint160(PRECOMPILES) + 7);
+    address public constant JSON_GET_STRING_ARRAY = address(uint160(PRECOMPILES) + 8);
+    address public constant JSON_GET_INT_ARRAY = address(uint160(PRECOMPILES) + 9);
+    address public constant JSON_GET_BOOL_ARRAY = address(uint160(PRECOMPILES) + 0x0A);
+    address public constant JSON_GET_BATCH = address(uint160(PRECOMPILES) + 0x0B);
     address public constant REGEX_MATCH = address(uint160(PRECOMPILES) + 0x10);
     address public constant REGEX_CAPTURE = address(uint160(PRECOMPILES) + 0x11);
     address public constant URL_PATTERN_TEST = address(uint160(PRECOMPILES) + 0x20);
+    address public constant HTML_GET_TEXT = address(uint160(PRECOMPILES) + 0x40);
+    address public constant VERIFY_ARC_EMAIL = address(uint160(PRECOMPILES) + 0x50);
+    address public constant VERIFY_EMAIL_ATTACHMENT = address(uint160(PRECOMPILES) + 0x51);
     address public constant IS_VLAYER_TEST = address(uint160(PRECOMPILES) + 0x1E);
 }
@@ -2,6 +2,8 @@
 pragma solidity ^0.8.21;
 
 import {Proof, ProofLib} from "./Proof.sol";
+import {IReceipts, Receipt} from "./Receipts.sol";
+import {ITransactions, Transaction} from "./Transactions.sol";
 
 interface ITraveler {
     // These functions need to return something because otherwise Solidity compiler won't generate CALL opcode when they're called.
@@ -14,6 +16,13 @@ contract Prover {
     // Address generated from first 20-bytes of "vlayer.traveler"'s keccak256.
     // 0x76dc9aa45aa006a0f63942d8f9f21bd4537972a3
     ITraveler private constant TRAVELER = ITraveler(address(uint160(uint256(keccak256("vlayer.traveler")))));
+    // Address generated from first 20-bytes of "vlayer.receipts"'s keccak256.
+    // 0xf19dfbed081a379f8a71536215b9ec789ce8b02a
+    IReceipts private constant RECEIPTS = IReceipts(address(uint160(uint256(keccak256("vlayer.receipts")))));
+    // Address generated from first 20-bytes of "vlayer.transactions"'s keccak256.
+    // 0x32942406f6143bb15844bb3206780a9a7a13c912
+    ITransactions private constant TRANSACTIONS =
+        ITransactions(address(uint160(uint256(keccak256("vlayer.transactions")))));
 
     function setBlock(uint256 blockNo) public {
         require(TRAVELER.setBlock(blockNo), "Failed cheatcode invocation");
@@ -23,6 +32,23 @@ contract Prover {
         require(TRAVELER.setChain(chainId, blockNo), "Failed cheatcode invocation");
     }
 
+    // Transaction at `txIndex` in the same block `getReceipt` reads from, with its sender recovered
+    // from the signature.
+    function getTransaction(uint256 txIndex) public view returns (Transaction memory) {
+        return TRANSACTIONS.getTransaction(txIndex);
+    }
+
+    // Transaction with `txHash` from the same block `getTransaction` reads from.
+    function getTransactionByHash(bytes32 txHash) public view returns (Transaction memory) {
+        return TRANSACTIONS.getTransactionByHash(txHash);
+    }
+
+    // Receipt of a transaction from the block set with `setBlock`, or the settlement block otherwise.
+    // It is verified against the block's receipts root in the guest.
+    function getReceipt(uint256 txIndex) public view returns (Receipt memory) {
+        return RECEIPTS.getReceipt(txIndex);
+    }
+
     function proof() public pure returns (Proof memory) {
         return ProofLib.emptyProof();
     }
@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity ^0.8.21;
+
+struct Log {
+    address emitter;
+    bytes32[] topics;
+    bytes data;
+}
+
+struct Receipt {
+    uint8 txType;
+    bool status;
+    uint64 cumulativeGasUsed;
+    Log[] logs;
+}
+
+interface IReceipts {
+    // Returns the receipt of the transaction at `txIndex` within the current block.
+    // Reverts if the block has no such transaction.
+    function getReceipt(uint256 txIndex) external view returns (Receipt memory);
+}
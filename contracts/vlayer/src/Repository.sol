@@ -17,6 +17,8 @@ interface IVDnsKeyRepository {
     event DnsKeyRevoked(address indexed who, bytes key);
 
     function isDnsKeyValid(bytes memory key) external view returns (bool);
+    /// @param keyId SHA-256 of the DER-encoded key, as published by the DNS service
+    function isDnsKeyIdValid(bytes32 keyId) external view returns (bool);
 }
 
 interface INotaryKeyRepository {
@@ -32,6 +34,7 @@ contract Repository is AccessControlEnumerable, IImageIdRepository, IVDnsKeyRepo
     mapping(bytes32 => bool) internal dnsKeys;
     mapping(bytes32 => bool) internal imageIds;
     mapping(bytes32 => bool) internal notaryKeys;
+    mapping(bytes32 => bool) internal dnsKeyIds;
 
     constructor(address admin, address owner) {
         _grantRole(DEFAULT_ADMIN_ROLE, admin);
@@ -76,6 +79,7 @@ contract Repository is AccessControlEnumerable, IImageIdRepository, IVDnsKeyRepo
         require(!dnsKeys[hash], "Key is already valid");
 
         dnsKeys[hash] = true;
+        dnsKeyIds[sha256(key)] = true;
         emit DnsKeyAdded(msg.sender, key);
     }
 
@@ -84,6 +88,7 @@ contract Repository is AccessControlEnumerable, IImageIdRepository, IVDnsKeyRepo
         require(dnsKeys[hash], "Cannot revoke invalid key");
 
         dnsKeys[hash] = false;
+        dnsKeyIds[sha256(key)] = false;
         emit DnsKeyRevoked(msg.sender, key);
     }
 
@@ -91,6 +96,10 @@ contract Repository is AccessControlEnumerable, IImageIdRepository, IVDnsKeyRepo
         return dnsKeys[keccak256(key)];
     }
 
+    function isDnsKeyIdValid(bytes32 keyId) external view override returns (bool) {
+        return dnsKeyIds[keyId];
+    }
+
     ///===============*** NOTARY KEYS ***===============///
 
     function addNotaryKey(string memory key) external onlyRole(OWNER_ROLE) {
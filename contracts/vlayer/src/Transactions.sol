@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity ^0.8.21;
+
+struct Transaction {
+    uint8 txType;
+    // Zero for legacy transactions signed without replay protection and for deposit transactions.
+    uint64 chainId;
+    uint64 nonce;
+    uint64 gasLimit;
+    address from;
+    // Zero address for contract creations.
+    address to;
+    uint256 value;
+    bytes data;
+}
+
+interface ITransactions {
+    // Returns the transaction at `txIndex` within the current block.
+    // Reverts if the block has no such transaction.
+    function getTransaction(uint256 txIndex) external view returns (Transaction memory);
+
+    // Returns the transaction with `txHash` from the current block.
+    // Reverts if the block has no such transaction.
+    function getTransactionByHash(bytes32 txHash) external view returns (Transaction memory);
+}
@@ -13,10 +13,33 @@ struct WebProof {
     string webProofJson;
 }
 
+struct HttpHeader {
+    string name;
+    string value;
+}
+
 struct Web {
     string body;
     string notaryPubKey;
     string url;
+    uint16 status;
+    string method;
+    // Only the response headers requested when verifying the proof
+    HttpHeader[] headers;
+    // UNIX timestamp (in seconds) of the notarized TLS connection
+    uint64 notarizationTime;
+}
+
+// Layout of a single exchange returned by the `VERIFY_AND_PARSE` precompile
+struct VerifiedWeb {
+    string url;
+    string serverName;
+    string body;
+    string notaryPubKey;
+    uint16 status;
+    string method;
+    HttpHeader[] headers;
+    uint64 notarizationTime;
 }
 
 struct FloatInput {
@@ -25,6 +48,30 @@ struct FloatInput {
     uint8 precision;
 }
 
+enum JsonValueType {
+    String,
+    Int,
+    Bool,
+    FloatAsInt,
+    ArrayLength,
+    Keys,
+    StringArray,
+    IntArray,
+    BoolArray
+}
+
+struct JsonQuery {
+    string path;
+    JsonValueType valueType;
+    // Only used by `FloatAsInt` queries
+    uint8 precision;
+}
+
+struct JsonBatchInput {
+    string json;
+    JsonQuery[] queries;
+}
+
 library WebProofLib {
     using Strings for string;
     using UrlLib for string;
@@ -39,15 +86,27 @@ library WebProofLib {
         Enabled_UNSAFE
     }
 
+    enum ContentType {
+        Json,
+        Html,
+        Text,
+        FormUrlEncoded
+    }
+
     // Generated using command `curl -s https://notary.pse.dev/v0.1.0-alpha.7/info | jq -r '.publicKey' | openssl ec -pubin -inform PEM -pubout -conv_form uncompressed`
     string private constant NOTARY_PUB_KEY =
         "-----BEGIN PUBLIC KEY-----\nMFYwEAYHKoZIzj0CAQYFK4EEAAoDQgAEe0jxnBObaIj7Xjg6TXLCM1GG/VhY5650\nOrS/jgcbBufo/QDfFvL/irzIv1JSmhGiVcsCHCwolhDXWcge7v2IsQ==\n-----END PUBLIC KEY-----\n";
 
     function verify(WebProof memory webProof, string memory url) internal view returns (Web memory) {
-        Web memory web = recover(webProof, UrlTestMode.Full, BodyRedactionMode.Disabled);
-        verifyNotaryKey(web.notaryPubKey);
-        require(web.url.equal(url), "URL mismatch");
-        return web;
+        return verify(webProof, url, jsonOnly());
+    }
+
+    function verify(WebProof memory webProof, string memory url, ContentType[] memory allowedContentTypes)
+        internal
+        view
+        returns (Web memory)
+    {
+        return verify(webProof, url, allowedContentTypes, new string[](0));
     }
 
     function verifyWithUrlPrefix(WebProof memory webProof, string memory urlPrefix)
@@ -55,24 +114,73 @@ library WebProofLib {
         view
         returns (Web memory)
     {
-        Web memory web = recover(webProof, UrlTestMode.Prefix, BodyRedactionMode.Disabled);
+        return verifyWithUrlPrefix(webProof, urlPrefix, jsonOnly());
+    }
+
+    function verifyWithUrlPrefix(
+        WebProof memory webProof,
+        string memory urlPrefix,
+        ContentType[] memory allowedContentTypes
+    ) internal view returns (Web memory) {
+        return verifyWithUrlPrefix(webProof, urlPrefix, allowedContentTypes, new string[](0));
+    }
+
+    // When the proof contains several exchanges, the first one whose URL matches is returned.
+    function verify(
+        WebProof memory webProof,
+        string memory url,
+        ContentType[] memory allowedContentTypes,
+        string[] memory responseHeaders
+    ) internal view returns (Web memory) {
+        Web[] memory webs =
+            recoverAll(webProof, UrlTestMode.Full, BodyRedactionMode.Disabled, allowedContentTypes, responseHeaders);
+        Web memory web = findByUrl(webs, url);
         verifyNotaryKey(web.notaryPubKey);
-        require(web.url.startsWith(urlPrefix), "URL prefix mismatch");
         return web;
     }
 
+    function verifyWithUrlPrefix(
+        WebProof memory webProof,
+        string memory urlPrefix,
+        ContentType[] memory allowedContentTypes,
+        string[] memory responseHeaders
+    ) internal view returns (Web memory) {
+        Web[] memory webs =
+            recoverAll(webProof, UrlTestMode.Prefix, BodyRedactionMode.Disabled, allowedContentTypes, responseHeaders);
+        Web memory web = findByUrlPrefix(webs, urlPrefix);
+        verifyNotaryKey(web.notaryPubKey);
+        return web;
+    }
+
+    // Returns all request/response exchanges of the proof, in the order they were sent.
+    // URLs are not checked, so callers must check `url` of every exchange they rely on.
+    function verifyExchanges(
+        WebProof memory webProof,
+        ContentType[] memory allowedContentTypes,
+        string[] memory responseHeaders
+    ) internal view returns (Web[] memory) {
+        Web[] memory webs =
+            recoverAll(webProof, UrlTestMode.Full, BodyRedactionMode.Disabled, allowedContentTypes, responseHeaders);
+        require(webs.length > 0, "No exchanges in web proof");
+        verifyNotaryKey(webs[0].notaryPubKey);
+        return webs;
+    }
+
     function unsafeVerifyWithRedactedBody(
         WebProof memory webProof,
         string memory urlOrUrlPrefix,
         UrlTestMode urlTestMode
     ) internal view returns (Web memory) {
-        Web memory web = recover(webProof, UrlTestMode.Prefix, BodyRedactionMode.Enabled_UNSAFE);
-        verifyNotaryKey(web.notaryPubKey);
+        Web[] memory webs = recoverAll(
+            webProof, UrlTestMode.Prefix, BodyRedactionMode.Enabled_UNSAFE, jsonOnly(), new string[](0)
+        );
+        Web memory web;
         if (urlTestMode == UrlTestMode.Full) {
-            require(web.url.equal(urlOrUrlPrefix), "URL mismatch");
-        } else if (urlTestMode == UrlTestMode.Prefix) {
-            require(web.url.startsWith(urlOrUrlPrefix), "URL prefix mismatch");
+            web = findByUrl(webs, urlOrUrlPrefix);
+        } else {
+            web = findByUrlPrefix(webs, urlOrUrlPrefix);
         }
+        verifyNotaryKey(web.notaryPubKey);
         return web;
     }
 
@@ -81,14 +189,86 @@ library WebProofLib {
         view
         returns (Web memory)
     {
-        (bool success, bytes memory returnData) =
-            Precompiles.VERIFY_AND_PARSE.staticcall(abi.encode(webProof, urlTestMode, bodyRedactionMode));
+        return recover(webProof, urlTestMode, bodyRedactionMode, jsonOnly());
+    }
+
+    function recover(
+        WebProof memory webProof,
+        UrlTestMode urlTestMode,
+        BodyRedactionMode bodyRedactionMode,
+        ContentType[] memory allowedContentTypes
+    ) internal view returns (Web memory) {
+        return recover(webProof, urlTestMode, bodyRedactionMode, allowedContentTypes, new string[](0));
+    }
+
+    // `responseHeaders` are matched case-insensitively and returned in `Web.headers` in the same order.
+    // Reverts if any of them is missing from the response or redacted.
+    // Reverts for proofs with several exchanges, as it has no URL to pick one of them by:
+    // use `verify`, `verifyWithUrlPrefix` or `verifyExchanges` for those.
+    function recover(
+        WebProof memory webProof,
+        UrlTestMode urlTestMode,
+        BodyRedactionMode bodyRedactionMode,
+        ContentType[] memory allowedContentTypes,
+        string[] memory responseHeaders
+    ) internal view returns (Web memory) {
+        Web[] memory webs = recoverAll(webProof, urlTestMode, bodyRedactionMode, allowedContentTypes, responseHeaders);
+        require(webs.length == 1, "Expected a single exchange in web proof");
+        return webs[0];
+    }
+
+    function recoverAll(
+        WebProof memory webProof,
+        UrlTestMode urlTestMode,
+        BodyRedactionMode bodyRedactionMode,
+        ContentType[] memory allowedContentTypes,
+        string[] memory responseHeaders
+    ) internal view returns (Web[] memory) {
+        (bool success, bytes memory returnData) = Precompiles.VERIFY_AND_PARSE.staticcall(
+            abi.encode(webProof, urlTestMode, bodyRedactionMode, allowedContentTypes, responseHeaders)
+        );
 
         Address.verifyCallResult(success, returnData);
 
-        string[4] memory data = abi.decode(returnData, (string[4]));
+        VerifiedWeb[] memory data = abi.decode(returnData, (VerifiedWeb[]));
+
+        Web[] memory webs = new Web[](data.length);
+        for (uint256 i = 0; i < data.length; i++) {
+            webs[i] = Web(
+                data[i].body,
+                data[i].notaryPubKey,
+                data[i].url,
+                data[i].status,
+                data[i].method,
+                data[i].headers,
+                data[i].notarizationTime
+            );
+        }
+        return webs;
+    }
+
+    function findByUrl(Web[] memory webs, string memory url) private pure returns (Web memory) {
+        for (uint256 i = 0; i < webs.length; i++) {
+            if (webs[i].url.equal(url)) {
+                return webs[i];
+            }
+        }
+        revert("URL mismatch");
+    }
+
+    function findByUrlPrefix(Web[] memory webs, string memory urlPrefix) private pure returns (Web memory) {
+        for (uint256 i = 0; i < webs.length; i++) {
+            if (webs[i].url.startsWith(urlPrefix)) {
+                return webs[i];
+            }
+        }
+        revert("URL prefix mismatch");
+    }
 
-        return Web(data[2], data[3], data[0]);
+    function jsonOnly() private pure returns (ContentType[] memory) {
+        ContentType[] memory contentTypes = new ContentType[](1);
+        contentTypes[0] = ContentType.Json;
+        return contentTypes;
     }
 
     function verifyNotaryKey(string memory pubKey) internal view {
@@ -103,6 +283,25 @@ library WebProofLib {
 }
 
 library WebLib {
+    using Strings for string;
+
+    // Returns the value of a response header requested when verifying the proof.
+    function header(Web memory web, string memory name) internal pure returns (string memory) {
+        for (uint256 i = 0; i < web.headers.length; i++) {
+            if (web.headers[i].name.equal(name)) {
+                return web.headers[i].value;
+            }
+        }
+        revert("Header not found");
+    }
+
+    // Reverts if the web proof was notarized more than `maxAge` seconds before the current block.
+    // Can be combined with storing `notarizationTime` on-chain to reject replayed proofs.
+    function requireNotarizedWithin(Web memory web, uint256 maxAge) internal view {
+        require(web.notarizationTime <= block.timestamp, "Notarization time in the future");
+        require(block.timestamp - web.notarizationTime <= maxAge, "Web proof too old");
+    }
+
     function jsonGetString(Web memory web, string memory jsonPath) internal view returns (string memory) {
         require(bytes(web.body).length > 0, "Body is empty");
 
@@ -147,4 +346,78 @@ library WebLib {
 
         return abi.decode(returnData, (int256));
     }
+
+    function jsonGetArrayLength(Web memory web, string memory jsonPath) internal view returns (uint256) {
+        require(bytes(web.body).length > 0, "Body is empty");
+
+        bytes memory encodedParams = abi.encode([web.body, jsonPath]);
+        (bool success, bytes memory returnData) = Precompiles.JSON_GET_ARRAY_LENGTH.staticcall(encodedParams);
+        Address.verifyCallResult(success, returnData);
+
+        return abi.decode(returnData, (uint256));
+    }
+
+    function jsonGetKeys(Web memory web, string memory jsonPath) internal view returns (string[] memory) {
+        require(bytes(web.body).length > 0, "Body is empty");
+
+        bytes memory encodedParams = abi.encode([web.body, jsonPath]);
+        (bool success, bytes memory returnData) = Precompiles.JSON_GET_KEYS.staticcall(encodedParams);
+        Address.verifyCallResult(success, returnData);
+
+        return abi.decode(returnData, (string[]));
+    }
+
+    function jsonGetStringArray(Web memory web, string memory jsonPath) internal view returns (string[] memory) {
+        require(bytes(web.body).length > 0, "Body is empty");
+
+        bytes memory encodedParams = abi.encode([web.body, jsonPath]);
+        (bool success, bytes memory returnData) = Precompiles.JSON_GET_STRING_ARRAY.staticcall(encodedParams);
+        Address.verifyCallResult(success, returnData);
+
+        return abi.decode(returnData, (string[]));
+    }
+
+    function jsonGetIntArray(Web memory web, string memory jsonPath) internal view returns (int256[] memory) {
+        require(bytes(web.body).length > 0, "Body is empty");
+
+        bytes memory encodedParams = abi.encode([web.body, jsonPath]);
+        (bool success, bytes memory returnData) = Precompiles.JSON_GET_INT_ARRAY.staticcall(encodedParams);
+        Address.verifyCallResult(success, returnData);
+
+        return abi.decode(returnData, (int256[]));
+    }
+
+    function jsonGetBoolArray(Web memory web, string memory jsonPath) internal view returns (bool[] memory) {
+        require(bytes(web.body).length > 0, "Body is empty");
+
+        bytes memory encodedParams = abi.encode([web.body, jsonPath]);
+        (bool success, bytes memory returnData) = Precompiles.JSON_GET_BOOL_ARRAY.staticcall(encodedParams);
+        Address.verifyCallResult(success, returnData);
+
+        return abi.decode(returnData, (bool[]));
+    }
+
+    // Parses the body once and evaluates all the queries against it.
+    // Returns the results ABI-encoded in the order of the queries, ready to be passed to `abi.decode`.
+    function jsonGetBatch(Web memory web, JsonQuery[] memory queries) internal view returns (bytes memory) {
+        require(bytes(web.body).length > 0, "Body is empty");
+
+        JsonBatchInput memory input = JsonBatchInput({json: web.body, queries: queries});
+        bytes memory encodedParams = abi.encode(input);
+        (bool success, bytes memory returnData) = Precompiles.JSON_GET_BATCH.staticcall(encodedParams);
+        Address.verifyCallResult(success, returnData);
+
+        return returnData;
+    }
+
+    // Returns the trimmed text content of the first element matching the CSS selector.
+    function htmlGetText(Web memory web, string memory selector) internal view returns (string memory) {
+        require(bytes(web.body).length > 0, "Body is empty");
+
+        bytes memory encodedParams = abi.encode([web.body, selector]);
+        (bool success, bytes memory returnData) = Precompiles.HTML_GET_TEXT.staticcall(encodedParams);
+        Address.verifyCallResult(success, returnData);
+
+        return abi.decode(returnData, (string));
+    }
 }
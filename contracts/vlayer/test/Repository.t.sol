@@ -260,6 +260,14 @@ contract Repository_DnsKeys is Test {
         assertTrue(repository.isDnsKeyValid(key));
     }
 
+    function test_addKeyMakesKeyIdValid() public {
+        bytes memory key = "0x1234";
+        assertFalse(repository.isDnsKeyIdValid(sha256(key)));
+
+        repository.addDnsKey(key);
+        assertTrue(repository.isDnsKeyIdValid(sha256(key)));
+    }
+
     function test_revertsIf_keyIsAlreadyValid() public {
         bytes memory key = "0x1234";
         repository.addDnsKey(key);
@@ -295,6 +303,7 @@ contract Repository_DnsKeys is Test {
 
         repository.revokeDnsKey(key);
         assertFalse(repository.isDnsKeyValid(key));
+        assertFalse(repository.isDnsKeyIdValid(sha256(key)));
     }
 
     function test_onlyOwnerCanRevokeKey() public {
@@ -73,7 +73,8 @@ contract Verifier_OnlyVerified_Modifier_Tests is Test {
             ExampleProver.doSomething.selector,
             block.chainid,
             block.number - 1,
-            blockhash(block.number - 1)
+            blockhash(block.number - 1),
+            bytes32(0)
         );
     }
 
@@ -156,7 +157,8 @@ contract Verifier_SetTestVerifier is Test {
             ExampleProver.doSomething.selector,
             block.chainid,
             block.number - 1,
-            blockhash(block.number - 1)
+            blockhash(block.number - 1),
+            bytes32(0)
         );
     }
 
@@ -42,8 +42,9 @@ contract TestHelpers {
     }
 
     function createProof() public view returns (Proof memory, bytes32) {
-        CallAssumptions memory assumptions =
-            CallAssumptions(PROVER, SELECTOR, block.chainid, block.number - 1, blockhash(block.number - 1));
+        CallAssumptions memory assumptions = CallAssumptions(
+            PROVER, SELECTOR, block.chainid, block.number - 1, blockhash(block.number - 1), bytes32(0)
+        );
         return createProof(assumptions);
     }
 
@@ -2,15 +2,20 @@
 pragma solidity ^0.8.21;
 
 import {VTest} from "../../src/testing/VTest.sol";
-import {EmailProofLib, UnverifiedEmail, VerifiedEmail} from "../../src/EmailProof.sol";
+import {EmailHeader, EmailProofLib, UnverifiedEmail, VerifiedEmail} from "../../src/EmailProof.sol";
 import {Strings} from "@openzeppelin-contracts-5.0.1/utils/Strings.sol";
 
 contract EmailProofLibWrapper {
     using EmailProofLib for UnverifiedEmail;
+    using EmailProofLib for VerifiedEmail;
 
     function verify(UnverifiedEmail calldata email) public view returns (VerifiedEmail memory v) {
         return email.verify();
     }
+
+    function signedHeader(VerifiedEmail memory email, string memory name) public pure returns (string memory) {
+        return email.signedHeader(name);
+    }
 }
 
 contract EmailProofTest is VTest {
@@ -22,8 +27,26 @@ contract EmailProofTest is VTest {
     function test_revertsIf_DnsVerificationIsExpired() public {
         EmailProofLibWrapper wrapper = new EmailProofLibWrapper();
         UnverifiedEmail memory email = getTestEmail("testdata/verify_vlayer.eml");
-        email.verificationData.validUntil = uint64(block.timestamp - 1);
+        email.verificationData[0].validUntil = uint64(block.timestamp - 1);
         vm.expectRevert("EmailProof: expired DNS verification");
         wrapper.verify(email);
     }
+
+    function verifiedEmailWithHeaders() internal pure returns (VerifiedEmail memory email) {
+        email.signedHeaders = new EmailHeader[](2);
+        email.signedHeaders[0] = EmailHeader("date", "Thu, 12 Dec 2024 15:59:07 +0100");
+        email.signedHeaders[1] = EmailHeader("message-id", "<id@mail.gmail.com>");
+    }
+
+    function test_signedHeaderReturnsHeaderValue() public {
+        EmailProofLibWrapper wrapper = new EmailProofLibWrapper();
+        assertEq(wrapper.signedHeader(verifiedEmailWithHeaders(), "message-id"), "<id@mail.gmail.com>");
+    }
+
+    function test_signedHeaderRevertsIf_HeaderIsNotSigned() public {
+        EmailProofLibWrapper wrapper = new EmailProofLibWrapper();
+        VerifiedEmail memory email = verifiedEmailWithHeaders();
+        vm.expectRevert("EmailProof: header not signed");
+        wrapper.signedHeader(email, "reply-to");
+    }
 }
@@ -2,15 +2,19 @@
 pragma solidity ^0.8.21;
 
 import {VTest} from "../../src/testing/VTest.sol";
-import {Web, WebProof, WebProofLib, WebLib} from "../../src/WebProof.sol";
+import {Web, WebProof, WebProofLib, WebLib, HttpHeader, JsonQuery, JsonValueType} from "../../src/WebProof.sol";
 import {Strings} from "@openzeppelin-contracts-5.0.1/utils/Strings.sol";
 
 contract JsonParsingTest is VTest {
     using Strings for string;
     using WebLib for Web;
 
+    function webWithBody(string memory body) private pure returns (Web memory) {
+        return Web(body, "", "", 200, "GET", new HttpHeader[](0), 0);
+    }
+
     function test_parsingStringFromSimpleJson() public {
-        Web memory web = Web("{\"asset\":\"FDUSD\",\"test\":5}", "", "");
+        Web memory web = webWithBody("{\"asset\":\"FDUSD\",\"test\":5}");
 
         callProver();
         string memory assetName = web.jsonGetString("asset");
@@ -19,7 +23,7 @@ contract JsonParsingTest is VTest {
     }
 
     function test_parsingIntFromSimpleJson() public {
-        Web memory web = Web("{\"asset\":\"FDUSD\",\"test\":5}", "", "");
+        Web memory web = webWithBody("{\"asset\":\"FDUSD\",\"test\":5}");
 
         callProver();
         int256 value = web.jsonGetInt("test");
@@ -28,7 +32,7 @@ contract JsonParsingTest is VTest {
     }
 
     function test_parsingBoolFromSimpleJson() public {
-        Web memory web = Web("{\"asset\":\"FDUSD\",\"test\":true}", "", "");
+        Web memory web = webWithBody("{\"asset\":\"FDUSD\",\"test\":true}");
 
         callProver();
         bool value = web.jsonGetBool("test");
@@ -37,7 +41,7 @@ contract JsonParsingTest is VTest {
     }
 
     function test_parsingStringFromArray() public {
-        Web memory web = Web("{\"asset\":[\"FDUSD\",\"test\"]}", "", "");
+        Web memory web = webWithBody("{\"asset\":[\"FDUSD\",\"test\"]}");
 
         callProver();
         string memory assetName = web.jsonGetString("asset[0]");
@@ -46,10 +50,8 @@ contract JsonParsingTest is VTest {
     }
 
     function test_parsingJsonArrayOfObjects() public {
-        Web memory web = Web(
-            "[ { \"asset\": \"FDUSD\", \"free\": \"0.10620008\", \"locked\": \"0\", \"freeze\": \"0\", \"withdrawing\": \"0\", \"ipoable\": \"0\", \"btcValuation\": \"0\" }, { \"asset\": \"MOVR\", \"free\": \"0.0649415\", \"locked\": \"0\", \"freeze\": \"0\", \"withdrawing\": \"0\", \"ipoable\": \"0\", \"btcValuation\": \"0\" }, { \"asset\": \"PYR\", \"free\": \"0.9991\", \"locked\": \"0\", \"freeze\": \"0\", \"withdrawing\": \"0\", \"ipoable\": \"0\", \"btcValuation\": \"0\" }, { \"asset\": \"USDC\", \"free\": \"15.00047635\", \"locked\": \"0\", \"freeze\": \"0\", \"withdrawing\": \"0\", \"ipoable\": \"0\", \"btcValuation\": \"0\" } ]",
-            "",
-            ""
+        Web memory web = webWithBody(
+            "[ { \"asset\": \"FDUSD\", \"free\": \"0.10620008\", \"locked\": \"0\", \"freeze\": \"0\", \"withdrawing\": \"0\", \"ipoable\": \"0\", \"btcValuation\": \"0\" }, { \"asset\": \"MOVR\", \"free\": \"0.0649415\", \"locked\": \"0\", \"freeze\": \"0\", \"withdrawing\": \"0\", \"ipoable\": \"0\", \"btcValuation\": \"0\" }, { \"asset\": \"PYR\", \"free\": \"0.9991\", \"locked\": \"0\", \"freeze\": \"0\", \"withdrawing\": \"0\", \"ipoable\": \"0\", \"btcValuation\": \"0\" }, { \"asset\": \"USDC\", \"free\": \"15.00047635\", \"locked\": \"0\", \"freeze\": \"0\", \"withdrawing\": \"0\", \"ipoable\": \"0\", \"btcValuation\": \"0\" } ]"
         );
 
         callProver();
@@ -59,11 +61,128 @@ contract JsonParsingTest is VTest {
     }
 
     function test_parsingFloatFromSimpleJson() public {
-        Web memory web = Web("{\"asset\":\"FDUSD\",\"test\":5.123}", "", "");
+        Web memory web = webWithBody("{\"asset\":\"FDUSD\",\"test\":5.123}");
 
         callProver();
         int256 value = web.jsonGetFloatAsInt("test", 2);
 
         assertEq(value, 512);
     }
+
+    function test_parsingArrayLength() public {
+        Web memory web = webWithBody("{\"assets\":[\"FDUSD\",\"USDC\",\"PYR\"]}");
+
+        callProver();
+        uint256 length = web.jsonGetArrayLength("assets");
+
+        assertEq(length, 3);
+    }
+
+    function test_parsingObjectKeys() public {
+        Web memory web = webWithBody("{\"test\":5,\"asset\":\"FDUSD\"}");
+
+        callProver();
+        string[] memory keys = web.jsonGetKeys("@");
+
+        assertEq(keys.length, 2);
+        assertEq(keys[0], "asset");
+        assertEq(keys[1], "test");
+    }
+
+    function test_parsingStringArray() public {
+        Web memory web = webWithBody("[{\"asset\":\"FDUSD\"},{\"asset\":\"USDC\"}]");
+
+        callProver();
+        string[] memory assets = web.jsonGetStringArray("[*].asset");
+
+        assertEq(assets.length, 2);
+        assertEq(assets[0], "FDUSD");
+        assertEq(assets[1], "USDC");
+    }
+
+    function test_parsingIntArray() public {
+        Web memory web = webWithBody("{\"values\":[5,-3]}");
+
+        callProver();
+        int256[] memory values = web.jsonGetIntArray("values");
+
+        assertEq(values.length, 2);
+        assertEq(values[0], 5);
+        assertEq(values[1], -3);
+    }
+
+    function test_parsingBoolArray() public {
+        Web memory web = webWithBody("{\"flags\":[true,false]}");
+
+        callProver();
+        bool[] memory flags = web.jsonGetBoolArray("flags");
+
+        assertEq(flags.length, 2);
+        assertTrue(flags[0]);
+        assertFalse(flags[1]);
+    }
+
+    function test_parsingManyFieldsAtOnce() public {
+        Web memory web = webWithBody("{\"asset\":\"FDUSD\",\"test\":5,\"price\":1.25,\"tags\":[\"a\",\"b\"]}");
+        JsonQuery[] memory queries = new JsonQuery[](4);
+        queries[0] = JsonQuery("asset", JsonValueType.String, 0);
+        queries[1] = JsonQuery("test", JsonValueType.Int, 0);
+        queries[2] = JsonQuery("price", JsonValueType.FloatAsInt, 2);
+        queries[3] = JsonQuery("tags", JsonValueType.StringArray, 0);
+
+        callProver();
+        bytes memory results = web.jsonGetBatch(queries);
+
+        (string memory asset, int256 test, int256 price, string[] memory tags) =
+            abi.decode(results, (string, int256, int256, string[]));
+        assertEq(asset, "FDUSD");
+        assertEq(test, 5);
+        assertEq(price, 125);
+        assertEq(tags.length, 2);
+        assertEq(tags[1], "b");
+    }
+
+    function test_htmlGetText() public {
+        Web memory web = webWithBody("<html><body><div class=\"balance\">Balance: <b>42</b> USD</div></body></html>");
+
+        callProver();
+        string memory balance = web.htmlGetText("div.balance");
+
+        assertEq(balance, "Balance: 42 USD");
+    }
+
+    function test_header() public pure {
+        HttpHeader[] memory headers = new HttpHeader[](2);
+        headers[0] = HttpHeader("Date", "Wed, 11 Jun 2025 14:24:07 GMT");
+        headers[1] = HttpHeader("ETag", "\"33a64df5\"");
+        Web memory web = Web("{}", "", "", 200, "GET", headers, 0);
+
+        assertEq(web.header("ETag"), "\"33a64df5\"");
+    }
+
+    function test_headerRevertsWhenNotRequested() public {
+        Web memory web = webWithBody("{}");
+
+        vm.expectRevert("Header not found");
+        this.getHeader(web, "Date");
+    }
+
+    function getHeader(Web memory web, string memory name) external pure returns (string memory) {
+        return web.header(name);
+    }
+
+    function test_requireNotarizedWithin() public {
+        vm.warp(1_000_000);
+        Web memory web = webWithBody("{}");
+        web.notarizationTime = 1_000_000 - 60;
+
+        this.requireNotarizedWithin(web, 60);
+
+        vm.expectRevert("Web proof too old");
+        this.requireNotarizedWithin(web, 59);
+    }
+
+    function requireNotarizedWithin(Web memory web, uint256 maxAge) external view {
+        web.requireNotarizedWithin(maxAge);
+    }
 }
@@ -2,7 +2,7 @@
 pragma solidity ^0.8.21;
 
 import {VTest} from "../../src/testing/VTest.sol";
-import {Web, WebProof, WebProofLib} from "../../src/WebProof.sol";
+import {Web, WebProof, WebProofLib, WebLib} from "../../src/WebProof.sol";
 
 contract WebProofLibWrapper {
     using WebProofLib for WebProof;
@@ -10,10 +10,27 @@ contract WebProofLibWrapper {
     function verify(WebProof calldata webProof, string memory dataUrl) public view returns (Web memory) {
         return webProof.verify(dataUrl);
     }
+
+    function verifyWithHeaders(WebProof calldata webProof, string memory dataUrl, string[] memory responseHeaders)
+        public
+        view
+        returns (Web memory)
+    {
+        WebProofLib.ContentType[] memory allowed = new WebProofLib.ContentType[](1);
+        allowed[0] = WebProofLib.ContentType.Json;
+        return webProof.verify(dataUrl, allowed, responseHeaders);
+    }
+
+    function verifyExchanges(WebProof calldata webProof) public view returns (Web[] memory) {
+        WebProofLib.ContentType[] memory allowed = new WebProofLib.ContentType[](1);
+        allowed[0] = WebProofLib.ContentType.Json;
+        return webProof.verifyExchanges(allowed, new string[](0));
+    }
 }
 
 contract WebProverTest is VTest {
     using WebProofLib for WebProof;
+    using WebLib for Web;
 
     string public constant DATA_URL = "https://lotr-api.online/regular_json?are_you_sure=yes&auth=s3cret_t0ken";
 
@@ -36,6 +53,37 @@ contract WebProverTest is VTest {
 
         Web memory web = wrapper.verify(webProof, DATA_URL);
         assertEq(bytes(web.body)[0], "{");
+        assertEq(web.status, 200);
+        assertEq(web.method, "GET");
+        assertEq(web.headers.length, 0);
+        // Fixture was notarized on Wed, 11 Jun 2025
+        assertApproxEqAbs(web.notarizationTime, 1_749_651_847, 60);
+    }
+
+    function test_exposesRequestedResponseHeaders() public {
+        WebProof memory webProof = WebProof(vm.readFile("testdata/web_proof.json"));
+        string[] memory responseHeaders = new string[](1);
+        responseHeaders[0] = "Content-Type";
+
+        callProver();
+
+        WebProofLibWrapper wrapper = new WebProofLibWrapper();
+
+        Web memory web = wrapper.verifyWithHeaders(webProof, DATA_URL, responseHeaders);
+        assertEq(web.header("Content-Type"), "application/json;charset=utf-8");
+    }
+
+    function test_verifiesAllExchanges() public {
+        WebProof memory webProof = WebProof(vm.readFile("testdata/web_proof.json"));
+
+        callProver();
+
+        WebProofLibWrapper wrapper = new WebProofLibWrapper();
+
+        Web[] memory webs = wrapper.verifyExchanges(webProof);
+        assertEq(webs.length, 1);
+        assertEq(webs[0].url, DATA_URL);
+        assertEq(webs[0].status, 200);
     }
 
     function test_incorrectUrl() public {
@@ -1,5 +1,5 @@
 import { toByteArray } from "base64-js";
-import { toHex } from "viem";
+import { sha256, toHex, zeroHash } from "viem";
 import { handleAuthErrors } from "../lib/handleErrors";
 
 interface DnsResponse {
@@ -41,12 +41,17 @@ function parseVerificationData(response: DnsResponse) {
       validUntil: 0n,
       signature: "0x" as const,
       pubKey: "0x" as const,
+      keyId: zeroHash,
+      dnssecProof: "0x" as const,
     };
   }
+  const pubKey = parseBase64(response.VerificationData.pub_key);
   return {
     validUntil: BigInt(response.VerificationData.valid_until),
     signature: parseBase64(response.VerificationData.signature),
-    pubKey: parseBase64(response.VerificationData.pub_key),
+    pubKey,
+    keyId: sha256(pubKey),
+    dnssecProof: "0x" as const,
   };
 }
 
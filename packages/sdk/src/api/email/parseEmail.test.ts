@@ -1,5 +1,10 @@
 import { describe, expect, test } from "vitest";
-import { getDkimSigners, parseEmail, parseParams } from "./parseEmail";
+import {
+  getArcSigners,
+  getDkimSigners,
+  parseEmail,
+  parseParams,
+} from "./parseEmail";
 
 const emailHeaders = `From: "John Doe" <john@d.oe>
 To: "Jane Doe" <jane@d.oe>
@@ -95,6 +100,31 @@ describe("getDkimSigners", () => {
   });
 });
 
+describe("getArcSigners", () => {
+  const arcSet = (instance: number, domain: string, selector: string) =>
+    `ARC-Seal: i=${instance}; a=rsa-sha256; cv=${instance === 1 ? "none" : "pass"}; d=${domain}; s=${selector}; b=abc;
+ARC-Message-Signature: i=${instance}; a=rsa-sha256; d=${domain}; s=${selector}; h=from; bh=abc; b=abc;
+ARC-Authentication-Results: i=${instance}; ${domain}; dkim=pass
+`;
+
+  test("should get seal signers and latest message signer", async () => {
+    const email = await parseEmail(
+      `${arcSet(2, "forwarder.net", "seal")}${arcSet(1, "lists.org", "arc")}${emailHeaders}\n${body}`,
+    );
+    expect(getArcSigners(email)).toEqual([
+      { domain: "forwarder.net", selector: "seal", algorithm: "rsa-sha256" },
+      { domain: "lists.org", selector: "arc", algorithm: "rsa-sha256" },
+    ]);
+  });
+
+  test("should throw if no arc seal found", async () => {
+    const email = await parseEmail(emailFixture);
+    expect(() => getArcSigners(email)).toThrowError(
+      "No ARC-Seal header found",
+    );
+  });
+});
+
 describe("parseParams", () => {
   test("should parse single parameter", () => {
     const params = parseParams("a=b");
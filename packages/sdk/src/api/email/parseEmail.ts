@@ -7,9 +7,12 @@ export class DkimParsingError extends Error {
   }
 }
 
+export type DkimKeyType = "rsa" | "ed25519";
+
 export interface DkimDomainSelector {
   domain: string;
   selector: string;
+  algorithm?: string;
 }
 
 export async function parseEmail(mime: string) {
@@ -24,6 +27,34 @@ export function getDkimSigners(mail: Email): DkimDomainSelector[] {
   return dkimHeader.map(parseHeader);
 }
 
+// Keys of every ARC-Seal signer and of the most recent ARC-Message-Signature signer are needed to
+// verify an ARC chain
+export function getArcSigners(mail: Email): DkimDomainSelector[] {
+  const sealHeaders = mail.headers.filter((h) => h.key === "arc-seal");
+  if (sealHeaders.length === 0) {
+    throw new DkimParsingError("No ARC-Seal header found");
+  }
+  const instance = (header: Header) => Number(parseParams(header.value).i);
+  const latestInstance = Math.max(...sealHeaders.map(instance));
+  const messageSignature = mail.headers.find(
+    (h) => h.key === "arc-message-signature" && instance(h) === latestInstance,
+  );
+  if (!messageSignature) {
+    throw new DkimParsingError(
+      `No ARC-Message-Signature header found for instance ${latestInstance}`,
+    );
+  }
+
+  const signers = [...sealHeaders, messageSignature].map(parseHeader);
+  return signers.filter(
+    (signer, index) =>
+      signers.findIndex(
+        ({ domain, selector }) =>
+          domain === signer.domain && selector === signer.selector,
+      ) === index,
+  );
+}
+
 export function parseParams(str: string) {
   return Object.fromEntries(
     str.split(";").map((s) =>
@@ -51,5 +82,6 @@ function parseHeader(header: Header): DkimDomainSelector {
   return {
     domain: params.d,
     selector: params.s,
+    algorithm: params.a,
   };
 }
@@ -1,6 +1,10 @@
 import { describe, expect, test, it, vi, beforeEach } from "vitest";
 import { readFile } from "testHelpers/readFile";
-import { findIndicesOfMatchingDomains, preverifyEmail } from "./preverify";
+import {
+  filterIndicesByKeyType,
+  findIndicesOfMatchingDomains,
+  preverifyEmail,
+} from "./preverify";
 import createFetchMock from "vitest-fetch-mock";
 import { HttpAuthorizationError, VLAYER_ERROR_NOTES } from "../lib/errors";
 
@@ -15,13 +19,16 @@ describe("Preverify email: integration", () => {
       dnsResolverUrl: "https://dns.google/resolve",
     });
     expect(preverifiedEmail.email).toBe(rawEmail);
-    expect(preverifiedEmail.dnsRecord).toMatchObject({
+    expect(preverifiedEmail.dnsRecords).toHaveLength(1);
+    expect(preverifiedEmail.verificationData).toHaveLength(1);
+    expect(preverifiedEmail.dnsRecords[0]).toMatchObject({
       name: "20230601._domainkey.google.com.",
       recordType: 16,
       ttl: expect.any(BigInt), // eslint-disable-line @typescript-eslint/no-unsafe-assignment
       data: expect.stringContaining("v=DKIM1; k=rsa; p="), // eslint-disable-line @typescript-eslint/no-unsafe-assignment
     });
-    expect(preverifiedEmail.dnsRecord.data).toContain("v=DKIM1; k=rsa; p=");
+    expect(preverifiedEmail.dnsRecords[0].data).toContain("v=DKIM1; k=rsa; p=");
+    expect(preverifiedEmail.requiredSignedHeaders).toEqual([]);
   });
 
   test("throws error if DKIM not found", async () => {
@@ -75,9 +82,9 @@ X-DKIM-Signature: v=1; a=rsa-sha256; d=example.com;
 DKIM-Signature: a=rsa-sha256; bh=2jUSOH9NhtVGCQWNr9BrIAPreKQjO6Sn7XIkfJVOzv8=;\r
  c=simple/simple; d=google.com;`),
       ).toBeTruthy();
-      expect(email.dnsRecord.data).toEqual(
+      expect(email.dnsRecords.map(({ data }) => data)).toEqual([
         "v=DKIM1; k=rsa; p=MIIBIjANBgkqhkiG9w0BAQEFAAOCAQ8AMIIBCgKCAQEA4zd3nfUoLHWFbfoPZzAb8bvjsFIIFsNypweLuPe4M+vAP1YxObFxRnpvLYz7Z+bORKLber5aGmgFF9iaufsH1z0+aw8Qex7uDaafzWoJOM/6lAS5iI0JggZiUkqNpRQLL7H6E7HcvOMC61nJcO4r0PwLDZKwEaCs8gUHiqRn/SS3wqEZX29v/VOUVcI4BjaOzOCLaz7V8Bkwmj4Rqq4kaLQQrbfpjas1naScHTAmzULj0Rdp+L1vVyGitm+dd460PcTIG3Pn+FYrgQQo2fvnTcGiFFuMa8cpxgfH3rJztf1YFehLWwJWgeXTriuIyuxUabGdRQu7vh7GrObTsHmIHwIDAQAB",
-      );
+      ]);
     });
 
     test("throws error if no DNS record domain matches the sender", async () => {
@@ -123,18 +130,49 @@ DKIM-Signature: a=rsa-sha256; bh=2jUSOH9NhtVGCQWNr9BrIAPreKQjO6Sn7XIkfJVOzv8=;\r
       ).rejects.toThrow("Found 0 DKIM headers matching the sender domain");
     });
 
-    test("throws error if multiple DNS record domains match the sender", async () => {
+    test("keeps all signatures of the sender domain and resolves their keys", async () => {
       let emailWithAddedHeaders = addDkimWithDomain("google.com", rawEmail);
       emailWithAddedHeaders = addDkimWithDomain(
         "google.com",
         emailWithAddedHeaders,
       );
+      const email = await preverifyEmail({
+        mimeEmail: emailWithAddedHeaders,
+        dnsResolverUrl: "https://dns.google/resolve",
+      });
+      expect(email.email).toBe(emailWithAddedHeaders);
+      // selector._domainkey.google.com doesn't resolve and is skipped
+      expect(email.dnsRecords.map(({ name }) => name)).toEqual([
+        "20230601._domainkey.google.com.",
+      ]);
+    });
+
+    test("selects signature by key type if sender domain signed the email twice", async () => {
+      const emailWithEd25519Signature = `DKIM-Signature: v=1; a=ed25519-sha256; d=google.com;
+ s=ed; c=relaxed/relaxed; q=dns/txt; bh=; h=From:Subject:Date:To; b=
+${rawEmail}`;
       await expect(
         preverifyEmail({
-          mimeEmail: emailWithAddedHeaders,
+          mimeEmail: emailWithEd25519Signature,
           dnsResolverUrl: "https://dns.google/resolve",
+          keyType: "ed25519",
         }),
-      ).rejects.toThrow("Found 3 DKIM headers matching the sender domain");
+      ).rejects.toThrow();
+
+      const email = await preverifyEmail({
+        mimeEmail: emailWithEd25519Signature,
+        dnsResolverUrl: "https://dns.google/resolve",
+        keyType: "rsa",
+      });
+      expect(
+        email.email
+          .startsWith(`X-DKIM-Signature: v=1; a=ed25519-sha256; d=google.com;
+ s=ed; c=relaxed/relaxed; q=dns/txt; bh=; h=From:Subject:Date:To; b=
+DKIM-Signature: a=rsa-sha256;`),
+      ).toBeTruthy();
+      expect(email.dnsRecords.map(({ name }) => name)).toEqual([
+        "20230601._domainkey.google.com.",
+      ]);
     });
 
     test("ignores x-dkim-signature headers", async () => {
@@ -219,6 +257,28 @@ describe("findIndicesOfMatchingDomains", () => {
   });
 });
 
+describe("filterIndicesByKeyType", () => {
+  const signers = [
+    { domain: "example.com", selector: "ed", algorithm: "ed25519-sha256" },
+    { domain: "other.other", selector: "rsa", algorithm: "rsa-sha256" },
+    { domain: "example.com", selector: "rsa", algorithm: "RSA-SHA256" },
+  ];
+
+  test("returns indices of signers using the key type", () => {
+    expect(filterIndicesByKeyType(signers, [0, 2], "rsa")).toStrictEqual([2]);
+    expect(filterIndicesByKeyType(signers, [0, 2], "ed25519")).toStrictEqual([
+      0,
+    ]);
+  });
+
+  test("ignores signers without algorithm", () => {
+    const signersWithoutAlgorithm = [{ domain: "example.com", selector: "s" }];
+    expect(
+      filterIndicesByKeyType(signersWithoutAlgorithm, [0], "rsa"),
+    ).toStrictEqual([]);
+  });
+});
+
 describe("fails with readable error if", () => {
   beforeEach(() => {
     fetchMocker.enableMocks();
@@ -1,10 +1,12 @@
 import {
   type DkimDomainSelector,
+  type DkimKeyType,
+  getArcSigners,
   getDkimSigners,
   parseEmail,
 } from "./parseEmail";
 import { DnsResolver } from "./dnsResolver";
-import { prefixAllButNthSubstring } from "../utils/prefixAllButNthSubstring";
+import { prefixUnselectedSubstrings } from "../utils/prefixUnselectedSubstrings";
 import {
   HttpAuthorizationError,
   httpAuthorizationErrorWithNote,
@@ -21,40 +23,69 @@ export function findIndicesOfMatchingDomains(
     .filter((index) => index !== -1);
 }
 
+export function filterIndicesByKeyType(
+  signers: DkimDomainSelector[],
+  indices: number[],
+  keyType: DkimKeyType,
+) {
+  return indices.filter((index) =>
+    signers[index].algorithm?.toLowerCase().startsWith(`${keyType}-`),
+  );
+}
+
 function requireSameOrigin(
   mimeEmail: string,
   signers: DkimDomainSelector[],
   fromAddress: string,
+  keyType?: DkimKeyType,
 ) {
-  const matchingIndices = findIndicesOfMatchingDomains(signers, fromAddress);
-
-  if (matchingIndices.length != 1) {
-    throw new Error(
-      `Found ${matchingIndices.length} DKIM headers matching the sender domain`,
-    );
+  let matchingIndices = findIndicesOfMatchingDomains(signers, fromAddress);
+  if (keyType) {
+    matchingIndices = filterIndicesByKeyType(signers, matchingIndices, keyType);
   }
 
-  const [matchingIndex] = matchingIndices;
+  if (matchingIndices.length === 0) {
+    const signatureKind = keyType ? `${keyType} DKIM headers` : "DKIM headers";
+    throw new Error(`Found 0 ${signatureKind} matching the sender domain`);
+  }
 
   return [
-    prefixAllButNthSubstring(
+    prefixUnselectedSubstrings(
       mimeEmail,
       /^\s*dkim-signature/gim,
       signers.length,
-      matchingIndex,
+      matchingIndices,
     ),
-    [signers[matchingIndex]] as DkimDomainSelector[],
+    matchingIndices.map((index) => signers[index]),
   ] as const;
 }
 
+function uniqueKeyLocations(signers: DkimDomainSelector[]) {
+  const names = new Set<string>();
+  return signers.filter(({ selector, domain }) => {
+    const name = `${selector}._domainkey.${domain}`.toLowerCase();
+    if (names.has(name)) {
+      return false;
+    }
+    names.add(name);
+    return true;
+  });
+}
+
 export async function preverifyEmail({
   mimeEmail,
   dnsResolverUrl,
   token,
+  keyType,
+  requiredSignedHeaders = [],
 }: {
   mimeEmail: string;
   dnsResolverUrl: string;
   token?: string;
+  // Limits verification to one kind of signature when the sender domain signed the email with both RSA and Ed25519 keys
+  keyType?: DkimKeyType;
+  // Headers the DKIM signature must cover, the prover defaults to from, to and subject if empty
+  requiredSignedHeaders?: string[];
 }) {
   const parsedEmail = await parseEmail(mimeEmail);
   let signers = getDkimSigners(parsedEmail);
@@ -66,17 +97,79 @@ export async function preverifyEmail({
   if (signers.length === 0) {
     throw new Error("No DKIM header found");
   }
-  [mimeEmail, signers] = requireSameOrigin(mimeEmail, signers, fromAddress);
+  [mimeEmail, signers] = requireSameOrigin(
+    mimeEmail,
+    signers,
+    fromAddress,
+    keyType,
+  );
+
+  const resolver = new DnsResolver(dnsResolverUrl, token);
+
+  try {
+    // Keys of older signatures may be gone from DNS after a key rollover,
+    // so only the keys that resolve are passed to the prover
+    const results = await Promise.allSettled(
+      uniqueKeyLocations(signers).map(({ selector, domain }) =>
+        resolver.resolveDkimDns(selector, domain),
+      ),
+    );
+    const dnsResponses = results.flatMap((result) =>
+      result.status === "fulfilled" ? [result.value] : [],
+    );
+    if (dnsResponses.length === 0) {
+      throw (results[0] as PromiseRejectedResult).reason;
+    }
+
+    return {
+      email: mimeEmail,
+      dnsRecords: dnsResponses.map(({ dnsRecord }) => dnsRecord),
+      verificationData: dnsResponses.map(
+        ({ verificationData }) => verificationData,
+      ),
+      requiredSignedHeaders,
+    };
+  } catch (error) {
+    const errorWithNote = match(error)
+      .with(P.instanceOf(HttpAuthorizationError), (error) =>
+        httpAuthorizationErrorWithNote(error),
+      )
+      .otherwise((error) => error);
+    throw errorWithNote;
+  }
+}
 
-  const [{ domain, selector }] = signers;
+// Resolves the keys of all ARC signers of an email forwarded by e.g. a mailing list, whose original
+// DKIM signature no longer verifies. The result is meant for `EmailProofLib.verifyArc`.
+export async function preverifyArcEmail({
+  mimeEmail,
+  dnsResolverUrl,
+  token,
+  requiredSignedHeaders = [],
+}: {
+  mimeEmail: string;
+  dnsResolverUrl: string;
+  token?: string;
+  requiredSignedHeaders?: string[];
+}) {
+  const parsedEmail = await parseEmail(mimeEmail);
+  const signers = getArcSigners(parsedEmail);
   const resolver = new DnsResolver(dnsResolverUrl, token);
 
   try {
-    const dnsResponse = await resolver.resolveDkimDns(selector, domain);
+    const dnsResponses = await Promise.all(
+      signers.map(({ selector, domain }) =>
+        resolver.resolveDkimDns(selector, domain),
+      ),
+    );
 
     return {
       email: mimeEmail,
-      ...dnsResponse,
+      dnsRecords: dnsResponses.map(({ dnsRecord }) => dnsRecord),
+      verificationData: dnsResponses.map(
+        ({ verificationData }) => verificationData,
+      ),
+      requiredSignedHeaders,
     };
   } catch (error) {
     const errorWithNote = match(error)
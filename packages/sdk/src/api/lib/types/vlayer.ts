@@ -22,6 +22,7 @@ export type CallParams = {
 
 export type CallContext = {
   chain_id: number;
+  webhook_url?: string;
 };
 
 export type BrandedHash<T, F> = Branded<{ hash: string }, [T, F]>;
@@ -53,6 +54,7 @@ export enum ProofState {
   Proving = "proving",
   EstimatingVgas = "estimating_vgas",
   Done = "done",
+  Cancelled = "cancelled",
   Unknown = "unknown",
 }
 
@@ -123,6 +125,7 @@ export const proofReceiptSchema = z.discriminatedUnion("status", [
         ProofState.Preflight,
         ProofState.EstimatingVgas,
         ProofState.Proving,
+        ProofState.Cancelled,
         ProofState.Unknown,
       ])
       .catch(ProofState.Unknown),
@@ -142,6 +145,7 @@ export const proofReceiptSchema = z.discriminatedUnion("status", [
       .catch(ProofState.Unknown),
     data: z.custom<ProofData>(),
     metrics: z.custom<Metrics>(),
+    queue_position: z.number().optional(),
   }),
 ]);
 
@@ -95,6 +95,9 @@ const handleErrors = ({ status, state, error }: ProofReceipt) => {
       .with(ProofState.Proving, () => {
         throw new Error(`Proving failed with error: ${error}`);
       })
+      .with(ProofState.Cancelled, () => {
+        throw new Error(error);
+      })
       .with(ProofState.Unknown, () => {
         throw new Error(`Failed with error: ${error}`);
       })
@@ -102,6 +105,11 @@ const handleErrors = ({ status, state, error }: ProofReceipt) => {
   }
 };
 
+/**
+ * Polls `v_getProofReceipt` until the proof is ready. To get status changes pushed instead, use
+ * `v_subscribeProofStatus` on the prover's `/subscribe` endpoint (over WebSocket, or as
+ * server-sent events with `POST`), or pass a `webhook_url` in the call context.
+ */
 export async function waitForProof<
   T extends Abi,
   F extends ContractFunctionName<T>,
@@ -0,0 +1,24 @@
+import { describe, expect, test } from "vitest";
+import { prefixUnselectedSubstrings } from "./prefixUnselectedSubstrings";
+
+describe("prefixUnselectedSubstrings", () => {
+  test("adds 'X-' prefix to all matches except selected ones (indexed from 0)", () => {
+    const str = "abc 123 abc 456 abc 789";
+    expect(prefixUnselectedSubstrings(str, /abc/gi, 3, [0])).toBe(
+      "abc 123 X-abc 456 X-abc 789",
+    );
+    expect(prefixUnselectedSubstrings(str, /abc/gi, 3, [1])).toBe(
+      "X-abc 123 abc 456 X-abc 789",
+    );
+    expect(prefixUnselectedSubstrings(str, /abc/gi, 3, [0, 2])).toBe(
+      "abc 123 X-abc 456 abc 789",
+    );
+  });
+
+  test("does not add prefix to substrings past total substring count", () => {
+    const str = "abc 123 abc 456 abc 789 abc abc";
+    expect(prefixUnselectedSubstrings(str, /abc/gi, 3, [1])).toBe(
+      "X-abc 123 abc 456 X-abc 789 abc abc",
+    );
+  });
+});
@@ -1,12 +1,13 @@
-export function prefixAllButNthSubstring(
+export function prefixUnselectedSubstrings(
   str: string,
   pattern: RegExp,
   substringsCount: number,
-  skippedIndex: number,
+  skippedIndices: number[],
 ) {
   let occurrence = 0;
   return str.replace(pattern, (match) => {
-    return occurrence++ === skippedIndex || occurrence > substringsCount
+    const index = occurrence++;
+    return skippedIndices.includes(index) || index >= substringsCount
       ? match
       : `X-${match}`;
   });
@@ -1,4 +1,4 @@
-export { preverifyEmail } from "./api/email/preverify";
+export { preverifyArcEmail, preverifyEmail } from "./api/email/preverify";
 export { createVlayerClient } from "./api/lib/client";
 export { createExtensionWebProofProvider } from "./api/webProof/providers/extension";
 
@@ -84,6 +84,14 @@ impl EvmBlockHeader for EthBlockHeader {
         &self.state_root
     }
 
+    fn transactions_root(&self) -> &B256 {
+        &self.transactions_root
+    }
+
+    fn receipts_root(&self) -> &B256 {
+        &self.receipts_root
+    }
+
     fn fill_block_env(&self, blk_env: &mut BlockEnv) {
         blk_env.number = U256::from(self.number);
         blk_env.coinbase = self.beneficiary;
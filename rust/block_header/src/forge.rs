@@ -1,4 +1,4 @@
-use alloy_primitives::{B256, BlockNumber, keccak256};
+use alloy_primitives::{B256, BlockNumber, b256, keccak256};
 use alloy_rlp::Encodable;
 use common::Hashable;
 use derive_new::new;
@@ -7,6 +7,10 @@ use serde::{Deserialize, Serialize};
 
 use crate::{EvmBlockHeader, casting_utils::try_downcast};
 
+/// Root of an empty trie. Forge blocks have no transactions nor receipts.
+const EMPTY_ROOT_HASH: B256 =
+    b256!("56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421");
+
 #[derive(Debug, Clone, Serialize, Deserialize, new)]
 pub struct ForgeBlockHeader {
     number: BlockNumber,
@@ -44,6 +48,14 @@ impl EvmBlockHeader for ForgeBlockHeader {
         &self.state_root
     }
 
+    fn transactions_root(&self) -> &B256 {
+        &EMPTY_ROOT_HASH
+    }
+
+    fn receipts_root(&self) -> &B256 {
+        &EMPTY_ROOT_HASH
+    }
+
     fn fill_block_env(&self, _blk_env: &mut BlockEnv) {}
 }
 
@@ -1,6 +1,8 @@
 mod casting_utils;
 mod eth;
 mod forge;
+mod receipt;
+mod transaction;
 
 use std::fmt::Debug;
 
@@ -13,8 +15,10 @@ pub use common::Hashable;
 use dyn_clone::{DynClone, clone_trait_object};
 pub use eth::EthBlockHeader;
 pub use forge::ForgeBlockHeader;
+pub use receipt::{LEGACY_TX_TYPE, Receipt};
 use revm::primitives::BlockEnv;
 use serde::{Deserialize, Serialize};
+pub use transaction::{DEPOSIT_TX_TYPE, Transaction, TransactionError};
 
 /// An EVM abstraction of a block header.
 #[auto_impl(Box, &)]
@@ -27,6 +31,10 @@ pub trait EvmBlockHeader: Hashable + Encodable + AsAny + Debug + DynClone + Send
     fn timestamp(&self) -> u64;
     /// Returns the state root hash.
     fn state_root(&self) -> &B256;
+    /// Returns the transactions root hash.
+    fn transactions_root(&self) -> &B256;
+    /// Returns the receipts root hash.
+    fn receipts_root(&self) -> &B256;
     /// Fills the EVM block environment with the header's data.
     fn fill_block_env(&self, blk_env: &mut BlockEnv);
 }
@@ -202,6 +210,14 @@ mod serialize {
                 unimplemented!()
             }
 
+            fn transactions_root(&self) -> &B256 {
+                unimplemented!()
+            }
+
+            fn receipts_root(&self) -> &B256 {
+                unimplemented!()
+            }
+
             fn fill_block_env(&self, _blk_env: &mut BlockEnv) {
                 unimplemented!()
             }
@@ -0,0 +1,204 @@
+//! Transaction receipts, as committed to by the block's receipts root.
+use alloy_primitives::{Bloom, Log};
+use alloy_rlp::{BufMut, Decodable, EMPTY_LIST_CODE, Encodable, Header};
+use serde::{Deserialize, Serialize};
+
+/// EIP-2718 type of legacy transactions. Their receipts are encoded without a type prefix.
+pub const LEGACY_TX_TYPE: u8 = 0;
+
+/// Consensus fields of a transaction receipt.
+///
+/// The EIP-2718 encoding of a receipt is the value stored in the receipts trie
+/// under the RLP encoded index of the transaction within the block.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Receipt {
+    /// EIP-2718 type of the transaction.
+    pub tx_type: u8,
+    /// Whether the transaction was executed successfully.
+    pub status: bool,
+    /// Gas used in the block up to and including this transaction.
+    pub cumulative_gas_used: u64,
+    /// Bloom filter for the logs emitted by the transaction.
+    pub logs_bloom: Bloom,
+    /// Logs emitted by the transaction.
+    pub logs: Vec<Log>,
+    /// Nonce of an OP Stack deposit transaction.
+    pub deposit_nonce: Option<u64>,
+    /// Receipt version of an OP Stack deposit transaction. Set only after the Canyon hardfork.
+    pub deposit_receipt_version: Option<u64>,
+}
+
+impl Receipt {
+    /// Returns the EIP-2718 encoding of the receipt.
+    pub fn encoded_2718(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        if self.tx_type != LEGACY_TX_TYPE {
+            out.put_u8(self.tx_type);
+        }
+        self.encode_fields(&mut out);
+        out
+    }
+
+    /// Decodes an EIP-2718 encoded receipt.
+    pub fn decode_2718(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        let tx_type = match buf.first() {
+            Some(&tx_type) if tx_type < EMPTY_LIST_CODE => {
+                *buf = &buf[1..];
+                tx_type
+            }
+            Some(_) => LEGACY_TX_TYPE,
+            None => return Err(alloy_rlp::Error::InputTooShort),
+        };
+
+        let header = Header::decode(buf)?;
+        if !header.list {
+            return Err(alloy_rlp::Error::UnexpectedString);
+        }
+        if buf.len() < header.payload_length {
+            return Err(alloy_rlp::Error::InputTooShort);
+        }
+        let (mut payload, rest) = buf.split_at(header.payload_length);
+
+        let receipt = Self {
+            tx_type,
+            status: Decodable::decode(&mut payload)?,
+            cumulative_gas_used: Decodable::decode(&mut payload)?,
+            logs_bloom: Decodable::decode(&mut payload)?,
+            logs: Decodable::decode(&mut payload)?,
+            deposit_nonce: decode_trailing(&mut payload)?,
+            deposit_receipt_version: decode_trailing(&mut payload)?,
+        };
+        if !payload.is_empty() {
+            return Err(alloy_rlp::Error::UnexpectedLength);
+        }
+
+        *buf = rest;
+        Ok(receipt)
+    }
+
+    fn fields_length(&self) -> usize {
+        self.status.length()
+            + self.cumulative_gas_used.length()
+            + self.logs_bloom.length()
+            + self.logs.length()
+            + self.deposit_nonce.map_or(0, |nonce| nonce.length())
+            + self
+                .deposit_receipt_version
+                .map_or(0, |version| version.length())
+    }
+
+    fn encode_fields(&self, out: &mut dyn BufMut) {
+        Header {
+            list: true,
+            payload_length: self.fields_length(),
+        }
+        .encode(out);
+        self.status.encode(out);
+        self.cumulative_gas_used.encode(out);
+        self.logs_bloom.encode(out);
+        self.logs.encode(out);
+        if let Some(nonce) = self.deposit_nonce {
+            nonce.encode(out);
+        }
+        if let Some(version) = self.deposit_receipt_version {
+            version.encode(out);
+        }
+    }
+}
+
+fn decode_trailing<T: Decodable>(payload: &mut &[u8]) -> alloy_rlp::Result<Option<T>> {
+    if payload.is_empty() {
+        return Ok(None);
+    }
+    T::decode(payload).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{Address, Bytes, LogData, b256};
+
+    use super::*;
+
+    fn log() -> Log {
+        Log {
+            address: Address::repeat_byte(0x11),
+            data: LogData::new_unchecked(
+                vec![b256!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef")],
+                Bytes::from_static(&[0x42]),
+            ),
+        }
+    }
+
+    fn receipt(tx_type: u8) -> Receipt {
+        Receipt {
+            tx_type,
+            status: true,
+            cumulative_gas_used: 21_000,
+            logs: vec![log()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn legacy_has_no_type_prefix() {
+        let encoded = receipt(LEGACY_TX_TYPE).encoded_2718();
+
+        assert!(encoded[0] >= EMPTY_LIST_CODE);
+    }
+
+    #[test]
+    fn typed_has_type_prefix() {
+        let encoded = receipt(2).encoded_2718();
+
+        assert_eq!(encoded[0], 2);
+        assert!(encoded[1] >= EMPTY_LIST_CODE);
+    }
+
+    #[test]
+    fn failed_status_is_encoded_as_empty_string() {
+        let receipt = Receipt {
+            status: false,
+            ..Default::default()
+        };
+        let encoded = receipt.encoded_2718();
+
+        assert_eq!(encoded[3], alloy_rlp::EMPTY_STRING_CODE);
+    }
+
+    #[test]
+    fn roundtrip() {
+        for receipt in [receipt(LEGACY_TX_TYPE), receipt(2)] {
+            let encoded = receipt.encoded_2718();
+            let decoded = Receipt::decode_2718(&mut encoded.as_slice()).unwrap();
+
+            assert_eq!(decoded, receipt);
+        }
+    }
+
+    #[test]
+    fn roundtrip_deposit() {
+        let receipt = Receipt {
+            tx_type: 0x7e,
+            deposit_nonce: Some(7),
+            deposit_receipt_version: Some(1),
+            ..receipt(0x7e)
+        };
+        let encoded = receipt.encoded_2718();
+        let decoded = Receipt::decode_2718(&mut encoded.as_slice()).unwrap();
+
+        assert_eq!(decoded, receipt);
+    }
+
+    #[test]
+    fn fails_on_non_list_payload() {
+        assert_eq!(
+            Receipt::decode_2718(&mut [0x02, 0x80].as_slice()),
+            Err(alloy_rlp::Error::UnexpectedString)
+        );
+    }
+
+    #[test]
+    fn fails_on_empty_input() {
+        assert_eq!(Receipt::decode_2718(&mut [].as_slice()), Err(alloy_rlp::Error::InputTooShort));
+    }
+}
@@ -0,0 +1,341 @@
+//! Transactions, as committed to by the block's transactions root.
+use alloy_primitives::{
+    Address, B256, Bytes, ChainId, PrimitiveSignature, SignatureError, U256, keccak256,
+};
+use alloy_rlp::{Decodable, EMPTY_LIST_CODE, EMPTY_STRING_CODE, Header};
+use thiserror::Error;
+
+use crate::LEGACY_TX_TYPE;
+
+/// EIP-2718 type of OP Stack deposit transactions. They are not signed and carry the sender.
+pub const DEPOSIT_TX_TYPE: u8 = 0x7e;
+
+#[derive(Debug, Error)]
+pub enum TransactionError {
+    #[error("RLP error: {0}")]
+    Rlp(#[from] alloy_rlp::Error),
+    #[error("Invalid signature: {0}")]
+    Signature(#[from] SignatureError),
+    #[error("Unsupported transaction type: {0}")]
+    UnsupportedType(u8),
+    #[error("Invalid number of fields for transaction type {0}: {1}")]
+    InvalidFieldCount(u8, usize),
+}
+
+/// Fields of a transaction, decoded from its EIP-2718 encoding.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Transaction {
+    /// Hash of the EIP-2718 encoding of the transaction.
+    pub hash: B256,
+    /// EIP-2718 type of the transaction.
+    pub tx_type: u8,
+    /// Chain ID the transaction was signed for. Pre EIP-155 legacy transactions have none.
+    pub chain_id: Option<ChainId>,
+    /// Nonce of the sender. Deposit transactions have no nonce and use zero.
+    pub nonce: u64,
+    /// Gas limit of the transaction.
+    pub gas_limit: u64,
+    /// Sender of the transaction, recovered from the signature.
+    pub from: Address,
+    /// Recipient of the transaction. `None` for contract creations.
+    pub to: Option<Address>,
+    /// Value transferred with the transaction.
+    pub value: U256,
+    /// Calldata or init code of the transaction.
+    pub input: Bytes,
+}
+
+/// Positions of the common fields within the RLP list of a transaction type.
+struct Layout {
+    field_count: usize,
+    chain_id: Option<usize>,
+    nonce: Option<usize>,
+    gas_limit: usize,
+    to: usize,
+    value: usize,
+    input: usize,
+}
+
+const fn layout(tx_type: u8) -> Option<Layout> {
+    let layout = match tx_type {
+        LEGACY_TX_TYPE => Layout {
+            field_count: 9,
+            chain_id: None,
+            nonce: Some(0),
+            gas_limit: 2,
+            to: 3,
+            value: 4,
+            input: 5,
+        },
+        // EIP-2930
+        0x01 => Layout {
+            field_count: 11,
+            chain_id: Some(0),
+            nonce: Some(1),
+            gas_limit: 3,
+            to: 4,
+            value: 5,
+            input: 6,
+        },
+        // EIP-1559, EIP-4844 and EIP-7702 share the prefix of their fields
+        0x02..=0x04 => Layout {
+            field_count: match tx_type {
+                0x02 => 12,
+                0x03 => 14,
+                _ => 13,
+            },
+            chain_id: Some(0),
+            nonce: Some(1),
+            gas_limit: 4,
+            to: 5,
+            value: 6,
+            input: 7,
+        },
+        DEPOSIT_TX_TYPE => Layout {
+            field_count: 8,
+            chain_id: None,
+            nonce: None,
+            gas_limit: 5,
+            to: 2,
+            value: 4,
+            input: 7,
+        },
+        _ => return None,
+    };
+    Some(layout)
+}
+
+impl Transaction {
+    /// Decodes an EIP-2718 encoded transaction and recovers its sender.
+    pub fn decode_2718(mut buf: &[u8]) -> Result<Self, TransactionError> {
+        let hash = keccak256(buf);
+        let tx_type = match buf.first() {
+            Some(&tx_type) if tx_type < EMPTY_LIST_CODE => {
+                buf = &buf[1..];
+                tx_type
+            }
+            Some(_) => LEGACY_TX_TYPE,
+            None => return Err(alloy_rlp::Error::InputTooShort.into()),
+        };
+        let layout = layout(tx_type).ok_or(TransactionError::UnsupportedType(tx_type))?;
+
+        let fields = split_list(&mut buf)?;
+        if !buf.is_empty() {
+            return Err(alloy_rlp::Error::UnexpectedLength.into());
+        }
+        if fields.len() != layout.field_count {
+            return Err(TransactionError::InvalidFieldCount(tx_type, fields.len()));
+        }
+
+        let (chain_id, from) = match tx_type {
+            LEGACY_TX_TYPE => recover_legacy(&fields)?,
+            DEPOSIT_TX_TYPE => (None, decode(fields[1])?),
+            _ => (
+                layout.chain_id.map(|idx| decode(fields[idx])).transpose()?,
+                recover_typed(tx_type, &fields)?,
+            ),
+        };
+
+        Ok(Self {
+            hash,
+            tx_type,
+            chain_id,
+            nonce: layout
+                .nonce
+                .map(|idx| decode(fields[idx]))
+                .transpose()?
+                .unwrap_or_default(),
+            gas_limit: decode(fields[layout.gas_limit])?,
+            from,
+            to: decode_to(fields[layout.to])?,
+            value: decode(fields[layout.value])?,
+            input: decode(fields[layout.input])?,
+        })
+    }
+}
+
+/// Splits an RLP list into the encodings of its items.
+fn split_list<'a>(buf: &mut &'a [u8]) -> alloy_rlp::Result<Vec<&'a [u8]>> {
+    let header = Header::decode(buf)?;
+    if !header.list {
+        return Err(alloy_rlp::Error::UnexpectedString);
+    }
+    if buf.len() < header.payload_length {
+        return Err(alloy_rlp::Error::InputTooShort);
+    }
+    let (mut payload, rest) = buf.split_at(header.payload_length);
+    *buf = rest;
+
+    let mut items = Vec::new();
+    while !payload.is_empty() {
+        let mut item = payload;
+        let item_header = Header::decode(&mut item)?;
+        let item_length = payload.len() - item.len() + item_header.payload_length;
+        if payload.len() < item_length {
+            return Err(alloy_rlp::Error::InputTooShort);
+        }
+        let (item, rest) = payload.split_at(item_length);
+        items.push(item);
+        payload = rest;
+    }
+    Ok(items)
+}
+
+fn decode<T: Decodable>(mut item: &[u8]) -> alloy_rlp::Result<T> {
+    T::decode(&mut item)
+}
+
+fn decode_to(item: &[u8]) -> alloy_rlp::Result<Option<Address>> {
+    if item == [EMPTY_STRING_CODE] {
+        return Ok(None);
+    }
+    decode(item).map(Some)
+}
+
+fn encode_list(items: &[&[u8]]) -> Vec<u8> {
+    let payload = items.concat();
+    let mut out = Vec::with_capacity(payload.len() + 9);
+    Header {
+        list: true,
+        payload_length: payload.len(),
+    }
+    .encode(&mut out);
+    out.extend(payload);
+    out
+}
+
+fn recover(
+    signing_payload: &[u8],
+    fields: &[&[u8]],
+    y_parity: bool,
+) -> Result<Address, TransactionError> {
+    let [r, s] = [fields[fields.len() - 2], fields[fields.len() - 1]];
+    let signature = PrimitiveSignature::new(decode(r)?, decode(s)?, y_parity);
+    Ok(signature.recover_address_from_prehash(&keccak256(signing_payload))?)
+}
+
+fn recover_legacy(fields: &[&[u8]]) -> Result<(Option<ChainId>, Address), TransactionError> {
+    let v: u64 = decode(fields[6])?;
+    let (chain_id, y_parity) = match v {
+        27 | 28 => (None, v == 28),
+        // EIP-155
+        v if v >= 35 => (Some((v - 35) / 2), (v - 35) % 2 == 1),
+        _ => return Err(SignatureError::InvalidParity(v).into()),
+    };
+
+    let signing_payload = match chain_id {
+        Some(chain_id) => {
+            let chain_id = alloy_rlp::encode(chain_id);
+            let zero: &[u8] = &[EMPTY_STRING_CODE];
+            let mut fields = fields[..6].to_vec();
+            fields.extend([chain_id.as_slice(), zero, zero]);
+            encode_list(&fields)
+        }
+        None => encode_list(&fields[..6]),
+    };
+    let from = recover(&signing_payload, fields, y_parity)?;
+    Ok((chain_id, from))
+}
+
+fn recover_typed(tx_type: u8, fields: &[&[u8]]) -> Result<Address, TransactionError> {
+    let unsigned_fields = &fields[..fields.len() - 3];
+    let y_parity = decode(fields[fields.len() - 3])?;
+    let signing_payload = [vec![tx_type], encode_list(unsigned_fields)].concat();
+    recover(&signing_payload, fields, y_parity)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{address, hex};
+
+    use super::*;
+
+    const SENDER: Address = address!("9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F");
+    const RECIPIENT: Address = address!("3535353535353535353535353535353535353535");
+
+    #[test]
+    fn decodes_eip155_legacy() {
+        // Example from EIP-155
+        let encoded = hex!(
+            "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83"
+        );
+
+        let tx = Transaction::decode_2718(&encoded).unwrap();
+
+        assert_eq!(
+            tx,
+            Transaction {
+                hash: keccak256(encoded),
+                tx_type: LEGACY_TX_TYPE,
+                chain_id: Some(1),
+                nonce: 9,
+                gas_limit: 21_000,
+                from: SENDER,
+                to: Some(RECIPIENT),
+                value: U256::from(1_000_000_000_000_000_000_u64),
+                input: Bytes::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn decodes_eip1559() {
+        let encoded = hex!(
+            "02f86f0107843b9aca0085174876e80082c3509435353535353535353535353535353535353535350184deadbeefc080a09377c312145a5afb911bf9e8c067bcf6094c533603687850df502b61290bbf5ea061c1d2768140d7c6aca263dd5ee007ae3205b9704451cbf929f03a32a31cee0e"
+        );
+
+        let tx = Transaction::decode_2718(&encoded).unwrap();
+
+        assert_eq!(
+            tx,
+            Transaction {
+                hash: keccak256(encoded),
+                tx_type: 2,
+                chain_id: Some(1),
+                nonce: 7,
+                gas_limit: 50_000,
+                from: SENDER,
+                to: Some(RECIPIENT),
+                value: U256::from(1),
+                input: Bytes::from_static(&hex!("deadbeef")),
+            }
+        );
+    }
+
+    #[test]
+    fn decodes_contract_creation() {
+        let encoded = hex!(
+            "01f8530180843b9aca0082ea608080826000c080a0c45ba62c34caa62b9ec83c080e2f490fa8c588bd52187b9a47f40cf45535d645a06e48e3f195ba2df9caa4bd573713d53112f24b1e346100e7fae9bbe8dfa4556f"
+        );
+
+        let tx = Transaction::decode_2718(&encoded).unwrap();
+
+        assert_eq!(tx.from, SENDER);
+        assert_eq!(tx.to, None);
+        assert_eq!(tx.input, Bytes::from_static(&hex!("6000")));
+    }
+
+    #[test]
+    fn fails_on_unsupported_type() {
+        assert!(matches!(
+            Transaction::decode_2718(&[0x05, 0xc0]),
+            Err(TransactionError::UnsupportedType(0x05))
+        ));
+    }
+
+    #[test]
+    fn fails_on_invalid_field_count() {
+        assert!(matches!(
+            Transaction::decode_2718(&[0x02, 0xc1, 0x80]),
+            Err(TransactionError::InvalidFieldCount(2, 1))
+        ));
+    }
+
+    #[test]
+    fn fails_on_empty_input() {
+        assert!(matches!(
+            Transaction::decode_2718(&[]),
+            Err(TransactionError::Rlp(alloy_rlp::Error::InputTooShort))
+        ));
+    }
+}
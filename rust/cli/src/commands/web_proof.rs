@@ -8,7 +8,7 @@ use tracing::debug;
 use web_proof::{
     verifier::verify_and_parse,
     web::Web,
-    web_proof::{BodyRedactionMode, Config, UrlTestMode},
+    web_proof::{BodyRedactionMode, Config, ContentTypes, UrlTestMode},
 };
 use web_prover::{
     Method, NotarizeParams, NotarizeParamsBuilder, NotarizeParamsBuilderError, NotaryConfig,
@@ -117,9 +117,12 @@ pub(crate) fn webproof_debug(args: WebProofDebugArgs) -> anyhow::Result<()> {
         }
     };
     let web_proof = serde_json::from_str(&web_proof_json)?;
-    let config = Config::new(BodyRedactionMode::Disabled, UrlTestMode::Full);
-    let web: Web = verify_and_parse(web_proof, config)?;
-    println!("{web:#?}");
+    let config =
+        Config::new(BodyRedactionMode::Disabled, UrlTestMode::Full, ContentTypes::ALL, vec![]);
+    let webs: Vec<Web> = verify_and_parse(web_proof, config)?;
+    for web in webs {
+        println!("{web:#?}");
+    }
     Ok(())
 }
 
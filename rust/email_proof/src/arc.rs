@@ -0,0 +1,385 @@
+use std::collections::{BTreeMap, HashMap};
+
+use mailparse::{MailHeader, ParsedMail};
+use rsa::RsaPublicKey;
+
+use crate::{dkim::select_signed_headers, errors::Error};
+
+mod canonicalization;
+mod verify_signature;
+
+use canonicalization::{relaxed_body, relaxed_header, simple_body, strip_signature};
+use verify_signature::{body_hash, remove_whitespace, verify_rsa_sha256};
+
+const ARC_AUTHENTICATION_RESULTS_HEADER: &str = "ARC-Authentication-Results";
+const ARC_MESSAGE_SIGNATURE_HEADER: &str = "ARC-Message-Signature";
+const ARC_SEAL_HEADER: &str = "ARC-Seal";
+// https://datatracker.ietf.org/doc/html/rfc8617#section-4.2.1
+const MAX_INSTANCE: usize = 50;
+const SUPPORTED_ALGORITHM: &str = "rsa-sha256";
+const CRLF: &[u8] = b"\r\n";
+
+struct Tags(HashMap<String, String>);
+
+impl Tags {
+    fn parse(value: &str) -> Self {
+        Self(
+            value
+                .split(';')
+                .filter_map(|tag| tag.split_once('='))
+                .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+                .collect(),
+        )
+    }
+
+    fn of(header: &MailHeader) -> Self {
+        Self::parse(&header_value(header))
+    }
+
+    fn get(&self, name: &str) -> Option<&str> {
+        self.0.get(name).map(String::as_str)
+    }
+
+    fn required(&self, header: &str, name: &str) -> Result<&str, Error> {
+        self.get(name)
+            .ok_or_else(|| Error::InvalidArcChain(format!("Missing {name}= tag in {header}")))
+    }
+}
+
+struct ArcSet<'a> {
+    authentication_results: &'a MailHeader<'a>,
+    message_signature: &'a MailHeader<'a>,
+    seal: &'a MailHeader<'a>,
+}
+
+/// ARC sets of an email ordered by their instance number, starting from `i=1`.
+pub struct ArcChain<'a> {
+    sets: Vec<ArcSet<'a>>,
+}
+
+impl<'a> ArcChain<'a> {
+    pub fn parse(email: &'a ParsedMail<'a>) -> Result<Self, Error> {
+        let mut sets: BTreeMap<usize, [Option<&MailHeader>; 3]> = BTreeMap::new();
+        for header in &email.headers {
+            let key = header.get_key_ref();
+            let (position, instance) =
+                if key.eq_ignore_ascii_case(ARC_AUTHENTICATION_RESULTS_HEADER) {
+                    // Only the first tag is structured, the rest are regular Authentication-Results
+                    let value = header_value(header);
+                    let first_tag = value.split(';').next().unwrap_or_default();
+                    (0, instance(&Tags::parse(first_tag), &key)?)
+                } else if key.eq_ignore_ascii_case(ARC_MESSAGE_SIGNATURE_HEADER) {
+                    (1, instance(&Tags::of(header), &key)?)
+                } else if key.eq_ignore_ascii_case(ARC_SEAL_HEADER) {
+                    (2, instance(&Tags::of(header), &key)?)
+                } else {
+                    continue;
+                };
+
+            if sets.entry(instance).or_default()[position]
+                .replace(header)
+                .is_some()
+            {
+                return Err(Error::InvalidArcChain(format!(
+                    "Duplicate {key} header for instance {instance}"
+                )));
+            }
+        }
+
+        if sets.is_empty() {
+            return Err(Error::InvalidArcChain("No ARC headers found".into()));
+        }
+
+        let sets = sets
+            .into_iter()
+            .enumerate()
+            .map(|(index, (instance, headers))| {
+                if instance != index + 1 {
+                    return Err(Error::InvalidArcChain(format!(
+                        "Missing ARC set for instance {}",
+                        index + 1
+                    )));
+                }
+                let [Some(authentication_results), Some(message_signature), Some(seal)] = headers
+                else {
+                    return Err(Error::InvalidArcChain(format!(
+                        "Incomplete ARC set for instance {instance}"
+                    )));
+                };
+                Ok(ArcSet {
+                    authentication_results,
+                    message_signature,
+                    seal,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { sets })
+    }
+
+    /// Names of the headers signed by the most recent ARC-Message-Signature, in the `h=` tag order.
+    pub fn signed_headers(&self) -> Vec<String> {
+        Tags::of(self.latest().message_signature)
+            .get("h")
+            .unwrap_or_default()
+            .split(':')
+            .map(|name| name.trim().to_lowercase())
+            .collect()
+    }
+
+    /// Validates the chain as described in RFC 8617, section 5.2: the chain validation states, the
+    /// most recent ARC-Message-Signature and every ARC-Seal. Returns the sealing domains, starting
+    /// from the first sealer.
+    pub fn verify(
+        &self,
+        email: &ParsedMail,
+        public_key: impl Fn(&str, &str) -> Result<RsaPublicKey, Error>,
+    ) -> Result<Vec<String>, Error> {
+        self.verify_chain_validation_states()?;
+        self.verify_message_signature(email, &public_key)?;
+        (1..=self.sets.len())
+            .rev()
+            .map(|instance| self.verify_seal(instance, &public_key))
+            .collect::<Result<Vec<_>, _>>()
+            .map(|domains| domains.into_iter().rev().collect())
+    }
+
+    fn latest(&self) -> &ArcSet<'a> {
+        self.sets
+            .last()
+            .unwrap_or_else(|| unreachable!("`ArcChain::parse` ensures at least one ARC set"))
+    }
+
+    fn verify_chain_validation_states(&self) -> Result<(), Error> {
+        for (index, set) in self.sets.iter().enumerate() {
+            let tags = Tags::of(set.seal);
+            let cv = tags.required(ARC_SEAL_HEADER, "cv")?;
+            let expected = if index == 0 { "none" } else { "pass" };
+            if !cv.eq_ignore_ascii_case(expected) {
+                return Err(Error::InvalidArcChain(format!(
+                    "Unexpected cv={cv} in ARC-Seal of instance {}",
+                    index + 1
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn verify_message_signature(
+        &self,
+        email: &ParsedMail,
+        public_key: impl Fn(&str, &str) -> Result<RsaPublicKey, Error>,
+    ) -> Result<(), Error> {
+        let context = format!("{ARC_MESSAGE_SIGNATURE_HEADER} i={}", self.sets.len());
+        let value = header_value(self.latest().message_signature);
+        let tags = Tags::parse(&value);
+        verify_algorithm(&tags, ARC_MESSAGE_SIGNATURE_HEADER)?;
+        if tags.get("l").is_some() {
+            return Err(Error::InvalidArcChain(
+                "ARC-Message-Signature contains body length tag (l=)".into(),
+            ));
+        }
+
+        let canonicalization = tags.get("c").unwrap_or("simple/simple");
+        let (header_canonicalization, body_canonicalization) = canonicalization
+            .split_once('/')
+            .unwrap_or((canonicalization, "simple"));
+        if header_canonicalization != "relaxed" {
+            return Err(Error::InvalidArcChain(format!(
+                "Unsupported header canonicalization: {header_canonicalization}"
+            )));
+        }
+        let canonical_body = match body_canonicalization {
+            "relaxed" => relaxed_body(raw_body(email)),
+            "simple" => simple_body(raw_body(email)),
+            other => {
+                return Err(Error::InvalidArcChain(format!(
+                    "Unsupported body canonicalization: {other}"
+                )));
+            }
+        };
+        if body_hash(&canonical_body) != remove_whitespace(tags.required(&context, "bh")?) {
+            return Err(Error::ArcVerification(context, "body hash did not verify".into()));
+        }
+
+        let mut data = Vec::new();
+        for (name, header) in select_signed_headers(email, self.signed_headers()) {
+            data.extend(relaxed_header(&name, header.get_value_raw()));
+            data.extend(CRLF);
+        }
+        data.extend(relaxed_header(
+            ARC_MESSAGE_SIGNATURE_HEADER,
+            strip_signature(&value).as_bytes(),
+        ));
+
+        let key = public_key(tags.required(&context, "s")?, tags.required(&context, "d")?)?;
+        verify_rsa_sha256(&key, &data, tags.required(&context, "b")?)
+            .map_err(|err| Error::ArcVerification(context, err))
+    }
+
+    fn verify_seal(
+        &self,
+        instance: usize,
+        public_key: impl Fn(&str, &str) -> Result<RsaPublicKey, Error>,
+    ) -> Result<String, Error> {
+        let context = format!("{ARC_SEAL_HEADER} i={instance}");
+        let value = header_value(self.sets[instance - 1].seal);
+        let tags = Tags::parse(&value);
+        verify_algorithm(&tags, ARC_SEAL_HEADER)?;
+        if tags.get("h").is_some() {
+            return Err(Error::InvalidArcChain("ARC-Seal must not contain h= tag".into()));
+        }
+
+        // All ARC sets up to this instance, in increasing instance order
+        let mut data = Vec::new();
+        for (index, set) in self.sets[..instance].iter().enumerate() {
+            data.extend(relaxed_header(
+                ARC_AUTHENTICATION_RESULTS_HEADER,
+                set.authentication_results.get_value_raw(),
+            ));
+            data.extend(CRLF);
+            data.extend(relaxed_header(
+                ARC_MESSAGE_SIGNATURE_HEADER,
+                set.message_signature.get_value_raw(),
+            ));
+            data.extend(CRLF);
+            if index + 1 < instance {
+                data.extend(relaxed_header(ARC_SEAL_HEADER, set.seal.get_value_raw()));
+                data.extend(CRLF);
+            }
+        }
+        data.extend(relaxed_header(ARC_SEAL_HEADER, strip_signature(&value).as_bytes()));
+
+        let domain = tags.required(&context, "d")?;
+        let key = public_key(tags.required(&context, "s")?, domain)?;
+        verify_rsa_sha256(&key, &data, tags.required(&context, "b")?)
+            .map_err(|err| Error::ArcVerification(context, err))?;
+
+        Ok(domain.to_string())
+    }
+}
+
+fn instance(tags: &Tags, header: &str) -> Result<usize, Error> {
+    tags.required(header, "i")?
+        .parse()
+        .ok()
+        .filter(|instance| (1..=MAX_INSTANCE).contains(instance))
+        .ok_or_else(|| Error::InvalidArcChain(format!("Invalid instance tag in {header}")))
+}
+
+fn verify_algorithm(tags: &Tags, header: &str) -> Result<(), Error> {
+    let algorithm = tags.required(header, "a")?;
+    if algorithm != SUPPORTED_ALGORITHM {
+        return Err(Error::InvalidArcChain(format!(
+            "Unsupported algorithm in {header}: {algorithm}"
+        )));
+    }
+    Ok(())
+}
+
+fn header_value(header: &MailHeader) -> String {
+    String::from_utf8_lossy(header.get_value_raw()).into_owned()
+}
+
+fn raw_body<'a>(email: &ParsedMail<'a>) -> &'a [u8] {
+    email
+        .raw_bytes
+        .windows(4)
+        .position(|window| window == b"\r\n\r\n")
+        .map_or(&[][..], |end| &email.raw_bytes[end + 4..])
+}
+
+#[cfg(test)]
+mod test {
+    use mailparse::parse_mail;
+
+    use super::*;
+    use crate::test_utils::{build_mime_email, read_email_from_file};
+
+    fn parse_chain(headers: Vec<(&str, &str)>) -> Result<usize, Error> {
+        let email = build_mime_email(headers, "body");
+        let email = parse_mail(email.as_bytes()).unwrap();
+        ArcChain::parse(&email).map(|chain| chain.sets.len())
+    }
+
+    const AAR: &str = "i=1; lists.example.org; dkim=pass";
+    const AMS: &str = "i=1; a=rsa-sha256; d=lists.example.org; s=arc; h=from; bh=; b=";
+    const AS: &str = "i=1; a=rsa-sha256; cv=none; d=lists.example.org; s=arc; b=";
+
+    #[test]
+    fn parses_complete_arc_set() {
+        let headers = vec![
+            ("ARC-Seal", AS),
+            ("ARC-Message-Signature", AMS),
+            ("ARC-Authentication-Results", AAR),
+        ];
+        assert_eq!(parse_chain(headers).unwrap(), 1);
+    }
+
+    #[test]
+    fn parses_sets_of_fixture() {
+        let email = read_email_from_file("./testdata/arc_sealed_email.eml");
+        let email = parse_mail(email.as_bytes()).unwrap();
+        let chain = ArcChain::parse(&email).unwrap();
+
+        assert_eq!(chain.sets.len(), 2);
+        assert_eq!(
+            chain.signed_headers(),
+            ["from", "to", "subject", "date", "message-id", "from", "to", "subject"]
+        );
+    }
+
+    #[test]
+    fn fails_without_arc_headers() {
+        let headers = vec![("From", "alice@example.com")];
+        assert_eq!(
+            parse_chain(headers).unwrap_err(),
+            Error::InvalidArcChain("No ARC headers found".into())
+        );
+    }
+
+    #[test]
+    fn fails_for_incomplete_set() {
+        let headers = vec![("ARC-Seal", AS), ("ARC-Message-Signature", AMS)];
+        assert_eq!(
+            parse_chain(headers).unwrap_err(),
+            Error::InvalidArcChain("Incomplete ARC set for instance 1".into())
+        );
+    }
+
+    #[test]
+    fn fails_for_gap_in_instances() {
+        let headers = vec![
+            ("ARC-Seal", "i=2; a=rsa-sha256; cv=pass; d=example.net; s=seal; b="),
+            ("ARC-Message-Signature", "i=2; a=rsa-sha256; h=from; bh=; b="),
+            ("ARC-Authentication-Results", "i=2; example.net; arc=pass"),
+        ];
+        assert_eq!(
+            parse_chain(headers).unwrap_err(),
+            Error::InvalidArcChain("Missing ARC set for instance 1".into())
+        );
+    }
+
+    #[test]
+    fn fails_for_duplicate_header() {
+        let headers = vec![
+            ("ARC-Seal", AS),
+            ("ARC-Seal", AS),
+            ("ARC-Message-Signature", AMS),
+            ("ARC-Authentication-Results", AAR),
+        ];
+        assert_eq!(
+            parse_chain(headers).unwrap_err(),
+            Error::InvalidArcChain("Duplicate ARC-Seal header for instance 1".into())
+        );
+    }
+
+    #[test]
+    fn fails_for_instance_out_of_range() {
+        let headers = vec![("ARC-Seal", "i=51; a=rsa-sha256; cv=pass; b=")];
+        assert_eq!(
+            parse_chain(headers).unwrap_err(),
+            Error::InvalidArcChain("Invalid instance tag in ARC-Seal".into())
+        );
+    }
+}
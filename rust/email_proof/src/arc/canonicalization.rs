@@ -0,0 +1,156 @@
+//! Relaxed and simple canonicalization as defined in RFC 6376, section 3.4.
+
+const CRLF: &[u8] = b"\r\n";
+
+/// Relaxed header canonicalization, without the trailing CRLF.
+pub(super) fn relaxed_header(name: &str, value: &[u8]) -> Vec<u8> {
+    let mut canonical = name.trim().to_lowercase().into_bytes();
+    canonical.push(b':');
+    canonical.extend(compress_whitespace(&unfold(value)).trim_ascii());
+    canonical
+}
+
+pub(super) fn relaxed_body(body: &[u8]) -> Vec<u8> {
+    let lines: Vec<_> = split_lines(body)
+        .map(|line| compress_whitespace(line).trim_ascii_end().to_vec())
+        .collect();
+    join_lines_without_trailing_empty(&lines)
+}
+
+pub(super) fn simple_body(body: &[u8]) -> Vec<u8> {
+    let lines: Vec<_> = split_lines(body).map(<[u8]>::to_vec).collect();
+    let canonical = join_lines_without_trailing_empty(&lines);
+    // An empty body is canonicalized to a single CRLF
+    if canonical.is_empty() {
+        CRLF.to_vec()
+    } else {
+        canonical
+    }
+}
+
+/// Removes the value of the `b=` tag, keeping the tag itself, as required when hashing the
+/// signature header.
+pub(super) fn strip_signature(value: &str) -> String {
+    value
+        .split(';')
+        .map(|tag| match tag.split_once('=') {
+            Some((name, _)) if name.trim() == "b" => format!("{name}="),
+            _ => tag.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
+fn join_lines_without_trailing_empty(lines: &[Vec<u8>]) -> Vec<u8> {
+    let non_empty = lines
+        .iter()
+        .rposition(|line| !line.is_empty())
+        .map_or(0, |last| last + 1);
+    lines[..non_empty]
+        .iter()
+        .flat_map(|line| [line.as_slice(), CRLF])
+        .flatten()
+        .copied()
+        .collect()
+}
+
+fn unfold(value: &[u8]) -> Vec<u8> {
+    value
+        .iter()
+        .copied()
+        .filter(|byte| *byte != b'\r' && *byte != b'\n')
+        .collect()
+}
+
+fn compress_whitespace(value: &[u8]) -> Vec<u8> {
+    let mut compressed = Vec::with_capacity(value.len());
+    for byte in value.iter().copied() {
+        let byte = if byte == b'\t' { b' ' } else { byte };
+        if byte == b' ' && compressed.last() == Some(&b' ') {
+            continue;
+        }
+        compressed.push(byte);
+    }
+    compressed
+}
+
+// Splits on CRLF. A trailing CRLF doesn't produce an extra empty line.
+fn split_lines(body: &[u8]) -> impl Iterator<Item = &[u8]> {
+    let mut rest = (!body.is_empty()).then(|| body.strip_suffix(CRLF).unwrap_or(body));
+    std::iter::from_fn(move || {
+        let current = rest?;
+        match current.windows(2).position(|window| window == CRLF) {
+            Some(end) => {
+                rest = Some(&current[end + 2..]);
+                Some(&current[..end])
+            }
+            None => {
+                rest = None;
+                Some(current)
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod relaxed_header {
+        use super::*;
+
+        #[test]
+        fn lowercases_name_and_compresses_whitespace() {
+            assert_eq!(
+                relaxed_header("Subject ", b" Is \t dinner\r\n\tready? "),
+                b"subject:Is dinner ready?"
+            );
+        }
+    }
+
+    mod relaxed_body {
+        use super::*;
+
+        #[test]
+        fn compresses_whitespace_and_removes_trailing_empty_lines() {
+            assert_eq!(relaxed_body(b" C \r\nD \t E\r\n\r\n\r\n"), b" C\r\nD E\r\n");
+        }
+
+        #[test]
+        fn keeps_empty_body_empty() {
+            assert_eq!(relaxed_body(b""), b"");
+            assert_eq!(relaxed_body(b"\r\n\r\n"), b"");
+        }
+
+        #[test]
+        fn adds_missing_trailing_crlf() {
+            assert_eq!(relaxed_body(b"body"), b"body\r\n");
+        }
+    }
+
+    mod simple_body {
+        use super::*;
+
+        #[test]
+        fn removes_trailing_empty_lines_only() {
+            assert_eq!(simple_body(b" C \r\nD \t E\r\n\r\n\r\n"), b" C \r\nD \t E\r\n");
+        }
+
+        #[test]
+        fn canonicalizes_empty_body_to_crlf() {
+            assert_eq!(simple_body(b""), b"\r\n");
+        }
+    }
+
+    mod strip_signature {
+        use super::*;
+
+        #[test]
+        fn removes_b_tag_value_only() {
+            assert_eq!(
+                strip_signature("i=1; a=rsa-sha256; bh=abc=; b=de\r\n\tf=="),
+                "i=1; a=rsa-sha256; bh=abc=; b="
+            );
+        }
+    }
+}
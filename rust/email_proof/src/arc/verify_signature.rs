@@ -0,0 +1,23 @@
+use base64::{Engine, engine::general_purpose};
+use rsa::{Pkcs1v15Sign, RsaPublicKey};
+use sha2::{Digest, Sha256};
+
+pub(super) fn body_hash(canonical_body: &[u8]) -> String {
+    general_purpose::STANDARD.encode(Sha256::digest(canonical_body))
+}
+
+pub(super) fn verify_rsa_sha256(
+    key: &RsaPublicKey,
+    data: &[u8],
+    signature: &str,
+) -> Result<(), String> {
+    let signature = general_purpose::STANDARD
+        .decode(remove_whitespace(signature))
+        .map_err(|e| format!("signature decoding error: {e}"))?;
+    key.verify(Pkcs1v15Sign::new::<Sha256>(), &Sha256::digest(data), &signature)
+        .map_err(|_| "signature did not verify".to_string())
+}
+
+pub(super) fn remove_whitespace(value: &str) -> String {
+    value.split_whitespace().collect()
+}
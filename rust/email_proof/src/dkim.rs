@@ -1,4 +1,6 @@
-use cfdkim::{DKIMError, header, validate_header};
+use std::collections::HashMap;
+
+use cfdkim::{DKIMError, DkimPublicKey, header, validate_header};
 use itertools::Itertools;
 use mailparse::{MailHeader, MailHeaderMap, ParsedMail};
 use verifiable_dns::DNSRecord;
@@ -55,27 +57,34 @@ impl DKIMHeader {
         Ok(())
     }
 
-    pub fn verify_required_headers_signed(
-        &self,
-        required_signed_headers: &[&str],
-    ) -> Result<(), Error> {
-        let signed_headers = Self::signed_headers(self);
+    pub fn verify_key_type(&self, key: &DkimPublicKey) -> Result<(), Error> {
+        let algorithm = self.0.get_required_tag("a");
+        let key_type = key.key_type();
+        let signature_key_type = algorithm.split('-').next().unwrap_or_default();
 
-        if let Some(missing) = required_signed_headers
-            .iter()
-            .find(|h| !signed_headers.contains(&h.to_lowercase()))
-        {
-            return Err(Error::MissingRequiredHeaderTag((*missing).to_string()));
+        if !signature_key_type.eq_ignore_ascii_case(key_type) {
+            return Err(Error::KeyTypeMismatch(algorithm, key_type.to_string()));
         }
 
         Ok(())
     }
 
+    pub fn verify_required_headers_signed(
+        &self,
+        required_signed_headers: &[String],
+    ) -> Result<(), Error> {
+        verify_headers_signed(&self.signed_headers(), required_signed_headers)
+    }
+
     fn signing_domain(&self) -> Option<String> {
         self.0.get_tag("d")
     }
 
-    fn signed_headers(&self) -> Vec<String> {
+    fn signs_dns_record(&self, record: &DNSRecord) -> bool {
+        self.verify_dns_consistency(record).is_ok()
+    }
+
+    pub fn signed_headers(&self) -> Vec<String> {
         self.0
             .get_required_tag("h")
             .split(':')
@@ -84,11 +93,61 @@ impl DKIMHeader {
     }
 }
 
-fn normalize_dns_name(name: &str) -> String {
+pub(crate) fn verify_headers_signed(
+    signed_headers: &[String],
+    required_signed_headers: &[String],
+) -> Result<(), Error> {
+    if let Some(missing) = required_signed_headers
+        .iter()
+        .find(|h| !signed_headers.contains(&h.to_lowercase()))
+    {
+        return Err(Error::MissingRequiredHeaderTag(missing.clone()));
+    }
+
+    Ok(())
+}
+
+/// Returns names and values of the email headers listed in a signature's `h=` tag, in the tag
+/// order. Names without a matching header in the email are skipped.
+pub fn signed_header_values(
+    email: &ParsedMail,
+    signed_headers: Vec<String>,
+) -> Vec<(String, String)> {
+    select_signed_headers(email, signed_headers)
+        .into_iter()
+        .map(|(name, header)| (name, header.get_value()))
+        .collect()
+}
+
+pub(crate) fn select_signed_headers<'a>(
+    email: &'a ParsedMail<'a>,
+    signed_headers: Vec<String>,
+) -> Vec<(String, &'a MailHeader<'a>)> {
+    let mut used_instances: HashMap<String, usize> = HashMap::new();
+    signed_headers
+        .into_iter()
+        .filter_map(|name| {
+            let instances = email.headers.get_all_headers(&name);
+            let used = used_instances.entry(name.clone()).or_default();
+            // Multiple instances of a header are signed from the bottom up:
+            // https://datatracker.ietf.org/doc/html/rfc6376#section-5.4.2
+            let header = instances
+                .len()
+                .checked_sub(*used + 1)
+                .map(|index| instances[index]);
+            *used += 1;
+            header.map(|header| (name, header))
+        })
+        .collect()
+}
+
+pub(crate) fn normalize_dns_name(name: &str) -> String {
     name.trim().trim_end_matches('.').to_lowercase()
 }
 
-pub fn get_dkim_header(email: &ParsedMail) -> Result<DKIMHeader, Error> {
+/// Returns the DKIM-Signature headers of the From domain. A domain can sign an email more than
+/// once, e.g. with both RSA and Ed25519 keys or with the old and the new key during a rollover.
+pub fn get_dkim_headers(email: &ParsedMail) -> Result<Vec<DKIMHeader>, Error> {
     let dkim_headers: Vec<_> = email
         .headers
         .get_all_headers(DKIM_SIGNATURE_HEADER)
@@ -105,17 +164,31 @@ pub fn get_dkim_header(email: &ParsedMail) -> Result<DKIMHeader, Error> {
         unreachable!("`extract_address` function ensures that `address` has exactly one '@'")
     });
 
-    let headers_signing_from_domain: Vec<_> =
-        filter_dkim_headers_by_domain(dkim_headers, from_domain);
+    let headers_signing_from_domain = filter_dkim_headers_by_domain(dkim_headers, from_domain);
+    if headers_signing_from_domain.is_empty() {
+        return Err(Error::NoDkimMatchingFromDomain(from_domain.to_string()));
+    }
 
-    let only = headers_signing_from_domain
-        .into_iter()
-        .exactly_one()
-        // It's possible to have multiple DKIM-Signature headers with the
-        // same signing domain but we have decided not to support it.
-        .map_err(|v| Error::InvalidDkimHeaderCount(v.len()))?;
+    Ok(headers_signing_from_domain)
+}
 
-    Ok(only)
+/// Selects the signature made with the key of the DNS record by its selector. A single signature is
+/// returned as is, so that a mismatching record is reported by `verify_dns_consistency`.
+pub fn select_dkim_header<'a>(
+    dkim_headers: &'a [DKIMHeader],
+    dns_record: &DNSRecord,
+) -> Result<&'a DKIMHeader, Error> {
+    if let [only] = dkim_headers {
+        return Ok(only);
+    }
+
+    dkim_headers
+        .iter()
+        .filter(|header| header.signs_dns_record(dns_record))
+        .exactly_one()
+        // Signatures with the same signing domain and selector can't be told apart, as the
+        // signature check accepts any of them, so we have decided not to support them.
+        .map_err(|headers| Error::InvalidDkimHeaderCount(headers.count()))
 }
 
 fn filter_dkim_headers_by_domain(dkim_headers: Vec<DKIMHeader>, domain: &str) -> Vec<DKIMHeader> {
@@ -138,28 +211,41 @@ mod tests {
         DKIMHeader::try_from(&parse_header(raw_data).unwrap().0).unwrap()
     }
 
-    mod get_dkim_header {
+    mod get_dkim_headers {
         use mailparse::parse_mail;
 
         use super::*;
 
         #[test]
-        fn passes_for_single_dkim_header() {
+        fn returns_headers_of_from_domain() {
             let email = parse_mail(
                 b"From: Alice <alice@example.com>\r\n\
-                DKIM-Signature: v=1; a=; c=; d=example.com; s=; t=; h=From; bh=; b=",
+                DKIM-Signature: v=1; a=; c=; d=example.com; s=old; t=; h=From; bh=; b=\r\n\
+                DKIM-Signature: v=1; a=; c=; d=esp.com; s=; t=; h=From; bh=; b=\r\n\
+                DKIM-Signature: v=1; a=; c=; d=example.com; s=new; t=; h=From; bh=; b=",
             )
             .unwrap();
 
-            let result = get_dkim_header(&email);
-            assert!(result.is_ok());
+            let selectors: Vec<_> = get_dkim_headers(&email)
+                .unwrap()
+                .iter()
+                .map(|header| header.0.get_required_tag("s"))
+                .collect();
+            assert_eq!(selectors, ["old", "new"]);
         }
 
         #[test]
-        fn fails_for_not_exactly_one_dkim_headers() {
-            let email = parse_mail(b"From: Alice <alice@example.com>").unwrap();
+        fn fails_for_no_dkim_header_of_from_domain() {
+            let email = parse_mail(
+                b"From: Alice <alice@example.com>\r\n\
+                DKIM-Signature: v=1; a=; c=; d=esp.com; s=; t=; h=From; bh=; b=",
+            )
+            .unwrap();
 
-            assert_eq!(get_dkim_header(&email).unwrap_err(), Error::InvalidDkimHeaderCount(0));
+            assert_eq!(
+                get_dkim_headers(&email).unwrap_err(),
+                Error::NoDkimMatchingFromDomain("example.com".into())
+            );
         }
 
         #[test]
@@ -168,7 +254,72 @@ mod tests {
                 parse_mail(b"DKIM-Signature: v=1; a=; c=; d=example.com; s=; t=; h=From; bh=; b=")
                     .unwrap();
 
-            assert_eq!(get_dkim_header(&email).unwrap_err(), Error::NoFromHeader);
+            assert_eq!(get_dkim_headers(&email).unwrap_err(), Error::NoFromHeader);
+        }
+    }
+
+    mod select_dkim_header {
+        use super::*;
+
+        fn record_with_name(name: &str) -> DNSRecord {
+            DNSRecord {
+                name: name.to_string(),
+                ..Default::default()
+            }
+        }
+
+        fn header(alg: &str, selector: &str) -> DKIMHeader {
+            from_raw_data(
+                format!(
+                    "DKIM-Signature: v=1; a={alg}; d=example.com; s={selector}; h=From; bh=; b="
+                )
+                .as_bytes(),
+            )
+        }
+
+        #[test]
+        fn passes_for_single_dkim_header() {
+            let headers = [header("rsa-sha256", "rsa")];
+
+            let result = select_dkim_header(&headers, &DNSRecord::default());
+            assert!(result.is_ok());
+        }
+
+        #[test]
+        fn selects_dkim_header_by_dns_record_selector() {
+            let headers = [header("ed25519-sha256", "ed"), header("rsa-sha256", "rsa")];
+
+            let ed25519_header =
+                select_dkim_header(&headers, &record_with_name("ed._domainkey.example.com"))
+                    .unwrap();
+            let rsa_header =
+                select_dkim_header(&headers, &record_with_name("rsa._domainkey.example.com"))
+                    .unwrap();
+
+            assert_eq!(ed25519_header.0.get_required_tag("a"), "ed25519-sha256");
+            assert_eq!(rsa_header.0.get_required_tag("a"), "rsa-sha256");
+        }
+
+        #[test]
+        fn fails_for_multiple_dkim_headers_with_same_selector() {
+            let headers = [header("rsa-sha256", "rsa"), header("rsa-sha256", "rsa")];
+
+            assert_eq!(
+                select_dkim_header(&headers, &record_with_name("rsa._domainkey.example.com"))
+                    .unwrap_err(),
+                Error::InvalidDkimHeaderCount(2)
+            );
+        }
+
+        #[test]
+        fn fails_for_no_dkim_header_with_selector() {
+            let headers = [header("ed25519-sha256", "ed"), header("rsa-sha256", "rsa")];
+
+            assert_eq!(
+                select_dkim_header(&headers, &record_with_name("old._domainkey.example.com"))
+                    .unwrap_err(),
+                Error::InvalidDkimHeaderCount(0)
+            );
         }
     }
 
@@ -286,6 +437,78 @@ mod tests {
         }
     }
 
+    mod verify_key_type {
+        use super::*;
+        use crate::dns::extract_public_key;
+
+        const ED25519_RECORD: &str =
+            "v=DKIM1; k=ed25519; p=11qYAYKxCrfVS/7TyWQHOg7hcvPapiMlrwIaaPcHURo=";
+
+        #[test]
+        fn passes_for_matching_key_type() {
+            let header = from_raw_data(
+                b"DKIM-Signature: v=1; a=ed25519-sha256; c=; d=; s=; t=; h=From; bh=; b=",
+            );
+            let key = extract_public_key(ED25519_RECORD).unwrap();
+
+            assert!(header.verify_key_type(&key).is_ok());
+        }
+
+        #[test]
+        fn fails_for_mismatching_key_type() {
+            let header = from_raw_data(
+                b"DKIM-Signature: v=1; a=rsa-sha256; c=; d=; s=; t=; h=From; bh=; b=",
+            );
+            let key = extract_public_key(ED25519_RECORD).unwrap();
+
+            assert_eq!(
+                header.verify_key_type(&key).unwrap_err(),
+                Error::KeyTypeMismatch("rsa-sha256".into(), "ed25519".into())
+            );
+        }
+    }
+
+    mod signed_header_values {
+        use mailparse::parse_mail;
+
+        use super::*;
+
+        #[test]
+        fn returns_signed_headers_in_tag_order() {
+            let header =
+                from_raw_data(b"DKIM-Signature: v=1; a=; c=; d=; s=; t=; h=To:Date; bh=; b=");
+            let email = parse_mail(
+                b"From: alice@example.com\r\nTo: bob@example.com\r\n\
+                Date: Thu, 12 Dec 2024 15:59:07 +0100\r\n\r\nbody",
+            )
+            .unwrap();
+
+            assert_eq!(
+                signed_header_values(&email, header.signed_headers()),
+                vec![
+                    ("to".into(), "bob@example.com".into()),
+                    ("date".into(), "Thu, 12 Dec 2024 15:59:07 +0100".into())
+                ]
+            );
+        }
+
+        #[test]
+        fn takes_repeated_headers_from_the_bottom_and_skips_oversigned_ones() {
+            let header = from_raw_data(
+                b"DKIM-Signature: v=1; a=; c=; d=; s=; t=; h=Received:Received:Received; bh=; b=",
+            );
+            let email = parse_mail(
+                b"Received: first\r\nReceived: second\r\nFrom: alice@example.com\r\n\r\nbody",
+            )
+            .unwrap();
+
+            assert_eq!(
+                signed_header_values(&email, header.signed_headers()),
+                vec![("received".into(), "second".into()), ("received".into(), "first".into())]
+            );
+        }
+    }
+
     mod normalize_dns_name {
         use super::*;
 
@@ -305,7 +528,7 @@ mod tests {
             let header = from_raw_data(
                 b"DKIM-Signature: v=1; a=; c=; d=; s=; t=; h=From:To:Subject; bh=; b=",
             );
-            let required = ["From", "To", "Subject"];
+            let required = ["From", "To", "Subject"].map(String::from);
 
             let result = header.verify_required_headers_signed(&required);
             assert!(result.is_ok());
@@ -315,7 +538,7 @@ mod tests {
         fn fails_when_required_header_is_missing() {
             let header =
                 from_raw_data(b"DKIM-Signature: v=1; a=; c=; d=; s=; t=; h=From:Subject; bh=; b=");
-            let required = ["From", "To", "Subject"];
+            let required = ["From", "To", "Subject"].map(String::from);
 
             assert_eq!(
                 header
@@ -2,9 +2,15 @@ use std::collections::HashMap;
 
 use base64::{Engine, engine::general_purpose};
 use cfdkim::DkimPublicKey;
+use ed25519_dalek::{PUBLIC_KEY_LENGTH, VerifyingKey};
 
 use crate::Error;
 
+enum KeyType {
+    Rsa,
+    Ed25519,
+}
+
 pub fn extract_public_key(record: &str) -> Result<DkimPublicKey, Error> {
     let tags: HashMap<&str, &str> = record
         .split(';')
@@ -12,8 +18,9 @@ pub fn extract_public_key(record: &str) -> Result<DkimPublicKey, Error> {
         .map(|p| p.split_once('=').unwrap_or_default())
         .collect();
 
-    match tags.get("k") {
-        Some(&"rsa") | None => Ok(()),
+    let key_type = match tags.get("k") {
+        Some(&"rsa") | None => Ok(KeyType::Rsa),
+        Some(&"ed25519") => Ok(KeyType::Ed25519),
         Some(v) => Err(Error::InvalidDkimRecord(format!("Invalid k tag value: {v}"))),
     }?;
 
@@ -25,9 +32,30 @@ pub fn extract_public_key(record: &str) -> Result<DkimPublicKey, Error> {
         .decode(public_key)
         .map_err(|e| Error::InvalidDkimRecord(format!("Public key decoding error: {e}")))?;
 
+    match key_type {
+        KeyType::Rsa => parse_rsa_key(&public_key_bytes),
+        KeyType::Ed25519 => parse_ed25519_key(&public_key_bytes),
+    }
+}
+
+fn parse_rsa_key(public_key_bytes: &[u8]) -> Result<DkimPublicKey, Error> {
     Ok(DkimPublicKey::Rsa(
-        rsa::pkcs8::DecodePublicKey::from_public_key_der(&public_key_bytes)
-            .or_else(|_| rsa::pkcs1::DecodeRsaPublicKey::from_pkcs1_der(&public_key_bytes))
+        rsa::pkcs8::DecodePublicKey::from_public_key_der(public_key_bytes)
+            .or_else(|_| rsa::pkcs1::DecodeRsaPublicKey::from_pkcs1_der(public_key_bytes))
+            .map_err(|_| Error::InvalidDkimRecord("Failed to parse public key".into()))?,
+    ))
+}
+
+// RFC 8463 publishes the raw 32-byte key rather than a DER-encoded structure
+fn parse_ed25519_key(public_key_bytes: &[u8]) -> Result<DkimPublicKey, Error> {
+    let public_key_bytes: &[u8; PUBLIC_KEY_LENGTH] = public_key_bytes.try_into().map_err(|_| {
+        Error::InvalidDkimRecord(format!(
+            "Invalid Ed25519 public key length: {}",
+            public_key_bytes.len()
+        ))
+    })?;
+    Ok(DkimPublicKey::Ed25519(
+        VerifyingKey::from_bytes(public_key_bytes)
             .map_err(|_| Error::InvalidDkimRecord("Failed to parse public key".into()))?,
     ))
 }
@@ -77,7 +105,26 @@ mod test {
     }
 
     #[test]
-    fn only_rsa_is_supported() {
+    fn parses_ed25519_dns_record() -> anyhow::Result<()> {
+        let record = "v=DKIM1; k=ed25519; p=11qYAYKxCrfVS/7TyWQHOg7hcvPapiMlrwIaaPcHURo=";
+        let public_key = extract_public_key(record)?;
+        assert_eq!(public_key.key_type(), "ed25519");
+
+        Ok(())
+    }
+
+    #[test]
+    fn fails_for_ed25519_key_of_invalid_length() {
+        let record = "v=DKIM1; k=ed25519; p=11qYAYKxCrfVS/7TyWQHOg7hcvPapiMlrwIaaPcH";
+        let result = extract_public_key(record);
+        assert_eq!(
+            result.err().unwrap().to_string(),
+            "Invalid DKIM public key record: Invalid Ed25519 public key length: 30".to_string()
+        );
+    }
+
+    #[test]
+    fn only_rsa_and_ed25519_are_supported() {
         let record = concat!(
             "v=DKIM1; k=ecdsa ; p=MIIBIjANBgkqhkiG9w0BAQEFAAOC",
             "AQ8AMIIBCgKCAQEAvzwKQIIWzQXv0nihasFTT3+JO23hXCg",
@@ -1,19 +1,31 @@
 use alloy_sol_types::SolValue;
+use attachment::Attachment;
 use extract_address::extract_address;
 use mailparse::{
     DispositionType, MailHeader, MailHeaderMap, MailParseError, ParsedMail, headers::Headers,
 };
-use sol::SolEmail;
+use sol::{SolArcEmail, SolEmail, SolEmailWithAttachment};
 
+pub(crate) mod attachment;
 pub(crate) mod extract_address;
 pub(crate) mod sol;
 
+const PLAIN_TEXT_MIMETYPE: &str = "text/plain";
+const HTML_MIMETYPE: &str = "text/html";
+
 #[derive(Debug, PartialEq)]
 pub struct Email {
     pub from: String,
     pub to: String,
     pub subject: Option<String>,
     pub body: String,
+    pub html_body: Option<String>,
+    /// Lowercase names and values of the headers covered by the DKIM signature
+    pub signed_headers: Vec<(String, String)>,
+    /// Lowercase names of the headers the signature was required to cover
+    pub required_signed_headers: Vec<String>,
+    /// Hash of the signing domain and the signature, unique per signed email
+    pub nullifier: [u8; 32],
 }
 
 impl Email {
@@ -22,6 +34,32 @@ impl Email {
     }
 }
 
+/// Email verified through its ARC chain.
+#[derive(Debug, PartialEq)]
+pub struct ArcEmail {
+    pub email: Email,
+    /// Domains of the ARC-Seal headers, starting from the first sealer
+    pub sealing_domains: Vec<String>,
+}
+
+impl ArcEmail {
+    pub fn abi_encode(self) -> Vec<u8> {
+        SolArcEmail::from(self).abi_encode()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct EmailWithAttachment {
+    pub email: Email,
+    pub attachment: Attachment,
+}
+
+impl EmailWithAttachment {
+    pub fn abi_encode(self) -> Vec<u8> {
+        SolEmailWithAttachment::from(self).abi_encode()
+    }
+}
+
 impl TryFrom<ParsedMail<'_>> for Email {
     type Error = MailParseError;
 
@@ -37,36 +75,39 @@ impl TryFrom<ParsedMail<'_>> for Email {
             .ok_or(MailParseError::Generic("\"To\" header is missing"))?
             .get_value();
         let subject = get_header("Subject").map(MailHeader::get_value);
+        let (body, html_body) = get_bodies(&mail)?;
 
         Ok(Email {
             from: from_email,
-            body: get_body(&mail)?,
+            body,
+            html_body,
             to,
             subject,
+            signed_headers: Vec::new(),
+            required_signed_headers: Vec::new(),
+            nullifier: [0; 32],
         })
     }
 }
 
-fn get_body(mail: &ParsedMail) -> Result<String, MailParseError> {
-    mail.parts()
-        .filter(|part| is_plain_text_mimetype(part))
-        .filter(|part| is_inlined_body_content(part))
-        .map(ParsedMail::get_body)
-        .collect::<Result<_, _>>()
-        .and_then(validate_body_parts)
-        .map(|parts| parts.join(""))
-}
-
-fn validate_body_parts(parts: Vec<String>) -> Result<Vec<String>, MailParseError> {
-    if parts.is_empty() {
-        Err(MailParseError::Generic("Plain text body not found in the email"))
-    } else {
-        Ok(parts)
+fn get_bodies(mail: &ParsedMail) -> Result<(String, Option<String>), MailParseError> {
+    let plain_text_parts = get_body_parts(mail, PLAIN_TEXT_MIMETYPE)?;
+    let html_parts = get_body_parts(mail, HTML_MIMETYPE)?;
+    if plain_text_parts.is_empty() && html_parts.is_empty() {
+        return Err(MailParseError::Generic("Neither plain text nor HTML body found in the email"));
     }
+
+    let html_body = (!html_parts.is_empty()).then(|| html_parts.join(""));
+    Ok((plain_text_parts.join(""), html_body))
 }
 
-fn is_plain_text_mimetype(part: &ParsedMail) -> bool {
-    part.ctype.mimetype == "text/plain"
+// Parts are decoded according to their Content-Transfer-Encoding and charset
+fn get_body_parts(mail: &ParsedMail, mimetype: &str) -> Result<Vec<String>, MailParseError> {
+    mail.parts()
+        .filter(|part| part.ctype.mimetype == mimetype)
+        .filter(|part| is_inlined_body_content(part))
+        .map(ParsedMail::get_body)
+        .collect()
 }
 
 fn is_inlined_body_content(part: &ParsedMail) -> bool {
@@ -85,9 +126,17 @@ mod test {
     use super::*;
     use crate::test_utils::{build_mime_email, parsed_email, read_file};
 
-    mod get_body {
+    mod get_bodies {
         use super::*;
 
+        fn get_body(mail: &ParsedMail) -> Result<String, MailParseError> {
+            get_bodies(mail).map(|(body, _)| body)
+        }
+
+        fn get_html_body(mail: &ParsedMail) -> Result<Option<String>, MailParseError> {
+            get_bodies(mail).map(|(_, html_body)| html_body)
+        }
+
         #[test]
         fn returns_whole_body_for_plain_text_body_content_type() -> anyhow::Result<()> {
             let email =
@@ -108,12 +157,49 @@ mod test {
         }
 
         #[test]
-        fn throws_when_no_plain_text_in_email() -> anyhow::Result<()> {
+        fn returns_empty_body_and_html_body_for_html_only_email() -> anyhow::Result<()> {
             let email =
                 build_mime_email(vec![("Content-Type", "text/html")], "<div>This is html</div>");
+            let mail = mailparse::parse_mail(email.as_bytes())?;
+
+            assert_eq!(get_body(&mail)?, "");
+            assert_eq!(get_html_body(&mail)?.unwrap(), "<div>This is html</div>");
+            Ok(())
+        }
+
+        #[test]
+        fn returns_no_html_body_for_plain_text_email() -> anyhow::Result<()> {
+            let email = build_mime_email(vec![], "This is a plain body");
+            let html_body = get_html_body(&mailparse::parse_mail(email.as_bytes())?)?;
+
+            assert_eq!(html_body, None);
+            Ok(())
+        }
+
+        #[test]
+        fn decodes_html_body_transfer_encoding() -> anyhow::Result<()> {
+            let email = build_mime_email(
+                vec![
+                    ("Content-Type", "text/html; charset=\"UTF-8\""),
+                    ("Content-Transfer-Encoding", "quoted-printable"),
+                ],
+                "<div dir=3D\"ltr\">Za=C5=BC=C3=B3=C5=82=C4=87</div>",
+            );
+            let html_body = get_html_body(&mailparse::parse_mail(email.as_bytes())?)?;
+
+            assert_eq!(html_body.unwrap(), "<div dir=\"ltr\">Zażółć</div>");
+            Ok(())
+        }
+
+        #[test]
+        fn throws_when_no_text_in_email() -> anyhow::Result<()> {
+            let email = build_mime_email(vec![("Content-Type", "image/png")], "iVBORw0KGgo=");
             let body = get_body(&mailparse::parse_mail(email.as_bytes())?);
 
-            assert_eq!(body.err().unwrap().to_string(), "Plain text body not found in the email");
+            assert_eq!(
+                body.err().unwrap().to_string(),
+                "Neither plain text nor HTML body found in the email"
+            );
             Ok(())
         }
 
@@ -145,7 +231,7 @@ ccee4b4aa47f513abce342ce1e2ec2fd9600e31b *flex-confirm-mail.1.11.0.xpi
         }
 
         #[test]
-        fn throws_if_no_plain_text_among_multiparts() -> anyhow::Result<()> {
+        fn concats_html_parts_if_no_plain_text_among_multiparts() -> anyhow::Result<()> {
             let email = build_mime_email(
                 vec![(
                     "Content-Type",
@@ -164,11 +250,16 @@ Content-Type: text/html; charset="UTF-8"
 --0000000000002fe9ab0626ed1e27--"#,
             );
 
-            let body = get_body(&mailparse::parse_mail(email.as_bytes())?);
+            let mail = mailparse::parse_mail(email.as_bytes())?;
 
-            assert_eq!(body.err().unwrap().to_string(), "Plain text body not found in the email");
+            assert_eq!(get_body(&mail)?, "");
+            assert_eq!(
+                get_html_body(&mail)?.unwrap(),
+                "Welcome to vlayer, 0x0E8e5015042BeF1ccF2D449652C7A457a163ECB9\n\n<div dir=\"ltr\">Welcome to vlayer, 0x0E8e5015042BeF1ccF2D449652C7A457a163ECB9</div>\n\n"
+            );
             Ok(())
         }
+
         #[test]
         fn ignores_attachments() -> anyhow::Result<()> {
             let email = build_mime_email(
@@ -293,5 +384,43 @@ ZmlsZSBjb250ZW50Cg==
             assert_eq!(decoded.subject, "".to_string());
             assert_eq!(decoded.body, "body".to_string());
         }
+
+        #[test]
+        fn encodes_html_body_and_signed_headers() {
+            let email = Email {
+                html_body: Some("<p>body</p>".into()),
+                signed_headers: vec![("date".into(), "Thu, 1 Jan 1970 00:00:00 +0000".into())],
+                ..parsed_email(vec![("From", "me@aa.aa"), ("To", "you")], "body").unwrap()
+            };
+            let encoded = email.abi_encode();
+            let decoded = SolEmail::abi_decode(&encoded, true).unwrap();
+            assert_eq!(decoded.htmlBody, "<p>body</p>".to_string());
+            assert_eq!(decoded.signedHeaders.len(), 1);
+            assert_eq!(decoded.signedHeaders[0].name, "date".to_string());
+            assert_eq!(
+                decoded.signedHeaders[0].value,
+                "Thu, 1 Jan 1970 00:00:00 +0000".to_string()
+            );
+        }
+
+        #[test]
+        fn encodes_required_signed_headers() {
+            let email = Email {
+                required_signed_headers: vec!["from".into(), "date".into()],
+                ..parsed_email(vec![("From", "me@aa.aa"), ("To", "you")], "body").unwrap()
+            };
+            let decoded = SolEmail::abi_decode(&email.abi_encode(), true).unwrap();
+            assert_eq!(decoded.requiredSignedHeaders, ["from", "date"]);
+        }
+
+        #[test]
+        fn encodes_nullifier() {
+            let email = Email {
+                nullifier: [7; 32],
+                ..parsed_email(vec![("From", "me@aa.aa"), ("To", "you")], "body").unwrap()
+            };
+            let decoded = SolEmail::abi_decode(&email.abi_encode(), true).unwrap();
+            assert_eq!(decoded.nullifier, [7; 32]);
+        }
     }
 }
@@ -0,0 +1,198 @@
+use mailparse::{DispositionType, ParsedMail};
+
+use crate::errors::Error;
+
+/// Selects an attachment by filename and/or MIME type. At least one of them has to be set.
+#[derive(Debug, Default, PartialEq)]
+pub struct AttachmentQuery {
+    pub filename: Option<String>,
+    pub content_type: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Attachment {
+    pub filename: String,
+    pub content_type: String,
+    /// Content decoded according to its Content-Transfer-Encoding
+    pub content: Vec<u8>,
+}
+
+impl AttachmentQuery {
+    fn matches(&self, part: &ParsedMail, filename: &str) -> bool {
+        let filename_matches = self
+            .filename
+            .as_ref()
+            .is_none_or(|expected| expected == filename);
+        let content_type_matches = self
+            .content_type
+            .as_ref()
+            .is_none_or(|expected| expected.eq_ignore_ascii_case(&part.ctype.mimetype));
+        filename_matches && content_type_matches
+    }
+
+    fn describe(&self) -> String {
+        [
+            self.filename
+                .as_ref()
+                .map(|filename| format!("filename `{filename}`")),
+            self.content_type
+                .as_ref()
+                .map(|content_type| format!("content type `{content_type}`")),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" and ")
+    }
+}
+
+/// Returns the only attachment matching the query. Selecting one of several matching attachments
+/// would make the result depend on their order, so the query has to be unambiguous.
+pub fn find_attachment(mail: &ParsedMail, query: &AttachmentQuery) -> Result<Attachment, Error> {
+    if query.filename.is_none() && query.content_type.is_none() {
+        return Err(Error::InvalidAttachmentQuery);
+    }
+
+    let mut matching = mail
+        .parts()
+        .filter_map(|part| attachment_filename(part).map(|filename| (part, filename)))
+        .filter(|(part, filename)| query.matches(part, filename));
+
+    let Some((part, filename)) = matching.next() else {
+        return Err(Error::AttachmentNotFound(query.describe()));
+    };
+    if matching.next().is_some() {
+        return Err(Error::AmbiguousAttachment(query.describe()));
+    }
+
+    Ok(Attachment {
+        filename,
+        content_type: part.ctype.mimetype.clone(),
+        content: part.get_body_raw()?,
+    })
+}
+
+// Parts with `Content-Disposition: attachment` or with a filename, e.g. inline images. The filename
+// falls back to the legacy `name` parameter of Content-Type.
+fn attachment_filename(part: &ParsedMail) -> Option<String> {
+    let disposition = part.get_content_disposition();
+    let filename = disposition
+        .params
+        .get("filename")
+        .or_else(|| part.ctype.params.get("name"))
+        .cloned();
+    match (disposition.disposition, filename) {
+        (DispositionType::Attachment, filename) => Some(filename.unwrap_or_default()),
+        (_, filename) => filename,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use mailparse::parse_mail;
+
+    use super::*;
+    use crate::test_utils::read_email_from_file;
+
+    const REMAPPINGS: &[u8] =
+        b"@openzeppelin-contracts-5.0.1/=dependencies/@openzeppelin-contracts-5.0.1/\n\
+        openzeppelin-contracts/=dependencies/@openzeppelin-contracts-5.0.1/\n\
+        risc0-ethereum-1.2.0/=dependencies/risc0-ethereum-1.2.0/\n\
+        forge-std-1.9.4/=dependencies/forge-std-1.9.4/\n";
+
+    const MULTIPLE_ATTACHMENTS: &str = "Content-Type: multipart/mixed; boundary=\"boundary\"\r\n\
+        \r\n\
+        --boundary\r\n\
+        Content-Type: text/plain\r\n\
+        \r\n\
+        Body\r\n\
+        --boundary\r\n\
+        Content-Type: text/csv\r\n\
+        Content-Disposition: attachment; filename=\"january.csv\"\r\n\
+        \r\n\
+        date,amount\r\n\
+        --boundary\r\n\
+        Content-Type: text/csv\r\n\
+        Content-Disposition: attachment; filename=\"february.csv\"\r\n\
+        \r\n\
+        date,amount\r\n\
+        --boundary--\r\n";
+
+    fn query(filename: Option<&str>, content_type: Option<&str>) -> AttachmentQuery {
+        AttachmentQuery {
+            filename: filename.map(Into::into),
+            content_type: content_type.map(Into::into),
+        }
+    }
+
+    fn find_in_fixture(query: &AttachmentQuery) -> Result<Attachment, Error> {
+        let email = read_email_from_file("./testdata/email_with_attachment.eml");
+        find_attachment(&parse_mail(email.as_bytes()).unwrap(), query)
+    }
+
+    #[test]
+    fn finds_attachment_by_filename() -> anyhow::Result<()> {
+        assert_eq!(
+            find_in_fixture(&query(Some("remappings.txt"), None))?,
+            Attachment {
+                filename: "remappings.txt".into(),
+                content_type: "text/plain".into(),
+                content: REMAPPINGS.to_vec(),
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn finds_attachment_by_content_type() -> anyhow::Result<()> {
+        let attachment = find_in_fixture(&query(None, Some("TEXT/PLAIN")))?;
+
+        assert_eq!(attachment.filename, "remappings.txt");
+        Ok(())
+    }
+
+    #[test]
+    fn ignores_body_parts() {
+        assert_eq!(
+            find_in_fixture(&query(None, Some("text/html"))).unwrap_err(),
+            Error::AttachmentNotFound("content type `text/html`".into())
+        );
+    }
+
+    #[test]
+    fn fails_for_unknown_filename() {
+        assert_eq!(
+            find_in_fixture(&query(Some("invoice.pdf"), Some("application/pdf"))).unwrap_err(),
+            Error::AttachmentNotFound(
+                "filename `invoice.pdf` and content type `application/pdf`".into()
+            )
+        );
+    }
+
+    #[test]
+    fn fails_for_empty_query() {
+        assert_eq!(
+            find_in_fixture(&AttachmentQuery::default()).unwrap_err(),
+            Error::InvalidAttachmentQuery
+        );
+    }
+
+    #[test]
+    fn fails_for_ambiguous_query() {
+        let email = parse_mail(MULTIPLE_ATTACHMENTS.as_bytes()).unwrap();
+
+        assert_eq!(
+            find_attachment(&email, &query(None, Some("text/csv"))).unwrap_err(),
+            Error::AmbiguousAttachment("content type `text/csv`".into())
+        );
+    }
+
+    #[test]
+    fn selects_one_of_multiple_attachments_by_filename() -> anyhow::Result<()> {
+        let email = parse_mail(MULTIPLE_ATTACHMENTS.as_bytes())?;
+        let attachment = find_attachment(&email, &query(Some("february.csv"), Some("text/csv")))?;
+
+        assert_eq!(attachment.filename, "february.csv");
+        Ok(())
+    }
+}
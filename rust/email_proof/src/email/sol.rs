@@ -1,7 +1,13 @@
 use alloy_sol_types::{Error, SolValue};
-use verifiable_dns::{DNSRecord, PublicKey, RecordType, Signature, VerificationData};
+use sha2::{Digest, Sha256};
+use verifiable_dns::{
+    DNSRecord, DnssecProof, KeyId, PublicKey, RecordType, Signature, SignedRRset, VerificationData,
+};
 
-use crate::email::Email;
+use crate::email::{
+    ArcEmail, Email, EmailWithAttachment,
+    attachment::{Attachment, AttachmentQuery},
+};
 
 mod private {
     use alloy_sol_types::sol;
@@ -10,8 +16,11 @@ mod private {
 }
 
 pub use private::{
-    DnsRecord as SolDnsRecord, UnverifiedEmail, VerificationData as SolVerificationData,
-    VerifiedEmail as SolEmail,
+    AttachmentQuery as SolAttachmentQuery, DnsRecord as SolDnsRecord,
+    DnssecProof as SolDnssecProof, EmailAttachment as SolEmailAttachment,
+    EmailHeader as SolEmailHeader, SignedRRset as SolSignedRRset, UnverifiedArcEmail,
+    UnverifiedEmail, VerificationData as SolVerificationData, VerifiedArcEmail as SolArcEmail,
+    VerifiedEmail as SolEmail, VerifiedEmailWithAttachment as SolEmailWithAttachment,
 };
 
 impl From<Email> for SolEmail {
@@ -21,31 +30,158 @@ impl From<Email> for SolEmail {
             to: email.to,
             subject: email.subject.unwrap_or_default(),
             body: email.body,
+            htmlBody: email.html_body.unwrap_or_default(),
+            signedHeaders: email
+                .signed_headers
+                .into_iter()
+                .map(|(name, value)| SolEmailHeader { name, value })
+                .collect(),
+            requiredSignedHeaders: email.required_signed_headers,
+            nullifier: email.nullifier.into(),
+        }
+    }
+}
+
+impl From<ArcEmail> for SolArcEmail {
+    fn from(arc_email: ArcEmail) -> SolArcEmail {
+        SolArcEmail {
+            email: arc_email.email.into(),
+            sealingDomains: arc_email.sealing_domains,
+        }
+    }
+}
+
+impl From<Attachment> for SolEmailAttachment {
+    fn from(attachment: Attachment) -> SolEmailAttachment {
+        let content_hash: [u8; 32] = Sha256::digest(&attachment.content).into();
+        SolEmailAttachment {
+            filename: attachment.filename,
+            contentType: attachment.content_type,
+            content: attachment.content.into(),
+            contentHash: content_hash.into(),
+        }
+    }
+}
+
+impl From<EmailWithAttachment> for SolEmailWithAttachment {
+    fn from(email_with_attachment: EmailWithAttachment) -> SolEmailWithAttachment {
+        SolEmailWithAttachment {
+            email: email_with_attachment.email.into(),
+            attachment: email_with_attachment.attachment.into(),
+        }
+    }
+}
+
+impl From<SolSignedRRset> for SignedRRset {
+    fn from(rrset: SolSignedRRset) -> SignedRRset {
+        SignedRRset {
+            records: rrset.records.into_iter().map(Into::into).collect(),
+            signatures: rrset.signatures.into_iter().map(Into::into).collect(),
         }
     }
 }
 
+impl From<SolDnssecProof> for DnssecProof {
+    fn from(proof: SolDnssecProof) -> DnssecProof {
+        DnssecProof {
+            dnskeys: proof.dnskeys.into_iter().map(Into::into).collect(),
+            delegations: proof.delegations.into_iter().map(Into::into).collect(),
+            answer: proof.answer.into(),
+        }
+    }
+}
+
+impl From<SolAttachmentQuery> for AttachmentQuery {
+    fn from(query: SolAttachmentQuery) -> AttachmentQuery {
+        AttachmentQuery {
+            filename: Some(query.filename).filter(|filename| !filename.is_empty()),
+            content_type: Some(query.contentType).filter(|content_type| !content_type.is_empty()),
+        }
+    }
+}
+
+type EmailCalldata = (Vec<u8>, Vec<(DNSRecord, VerificationData)>, Vec<String>);
+
 impl UnverifiedEmail {
-    pub(crate) fn parse_calldata(
+    /// Returns the raw email, its DNS records with verification data and the requested names of
+    /// required signed headers
+    pub(crate) fn parse_calldata(calldata: &[u8]) -> Result<EmailCalldata, Error> {
+        UnverifiedEmail::abi_decode(calldata, true)?.into_parts()
+    }
+
+    /// Parses `abi.encode(unverifiedEmail, attachmentQuery)`
+    pub(crate) fn parse_calldata_with_attachment_query(
         calldata: &[u8],
-    ) -> Result<(Vec<u8>, DNSRecord, VerificationData), Error> {
-        let unverified_email = UnverifiedEmail::abi_decode(calldata, true)?;
+    ) -> Result<(EmailCalldata, AttachmentQuery), Error> {
+        let (unverified_email, query) =
+            <(UnverifiedEmail, SolAttachmentQuery)>::abi_decode_params(calldata, true)?;
+        Ok((unverified_email.into_parts()?, query.into()))
+    }
+
+    fn into_parts(self) -> Result<EmailCalldata, Error> {
+        let raw_email = self.email.into_bytes();
+        let dns_records = parse_dns_records(self.dnsRecords, self.verificationData)?;
+        Ok((raw_email, dns_records, self.requiredSignedHeaders))
+    }
+}
+
+impl UnverifiedArcEmail {
+    pub(crate) fn parse_calldata(calldata: &[u8]) -> Result<EmailCalldata, Error> {
+        let unverified_email = UnverifiedArcEmail::abi_decode(calldata, true)?;
         let raw_email = unverified_email.email.into_bytes();
-        let dns_record = DNSRecord {
-            name: unverified_email.dnsRecord.name,
-            record_type: parse_record_type(unverified_email.dnsRecord.recordType)?,
-            data: unverified_email.dnsRecord.data,
-            ttl: unverified_email.dnsRecord.ttl,
-        };
-        let verification_data = VerificationData {
-            valid_until: unverified_email.verificationData.validUntil,
-            signature: Signature(unverified_email.verificationData.signature.into()),
-            pub_key: PublicKey(unverified_email.verificationData.pubKey.into()),
-        };
-        Ok((raw_email, dns_record, verification_data))
+        let dns_records =
+            parse_dns_records(unverified_email.dnsRecords, unverified_email.verificationData)?;
+        Ok((raw_email, dns_records, unverified_email.requiredSignedHeaders))
     }
 }
 
+fn parse_dns_records(
+    dns_records: Vec<SolDnsRecord>,
+    verification_data: Vec<SolVerificationData>,
+) -> Result<Vec<(DNSRecord, VerificationData)>, Error> {
+    if dns_records.len() != verification_data.len() {
+        return Err(Error::custom(format!(
+            "Expected verification data for each of {} DNS records, found {}",
+            dns_records.len(),
+            verification_data.len()
+        )));
+    }
+    dns_records
+        .into_iter()
+        .zip(verification_data)
+        .map(|(dns_record, verification_data)| {
+            Ok((parse_dns_record(dns_record)?, parse_verification_data(verification_data)?))
+        })
+        .collect()
+}
+
+fn parse_dns_record(dns_record: SolDnsRecord) -> Result<DNSRecord, Error> {
+    Ok(DNSRecord {
+        name: dns_record.name,
+        record_type: parse_record_type(dns_record.recordType)?,
+        data: dns_record.data,
+        ttl: dns_record.ttl,
+    })
+}
+
+fn parse_verification_data(
+    verification_data: SolVerificationData,
+) -> Result<VerificationData, Error> {
+    let dnssec_proof = if verification_data.dnssecProof.is_empty() {
+        None
+    } else {
+        Some(SolDnssecProof::abi_decode(&verification_data.dnssecProof, true)?.into())
+    };
+    Ok(VerificationData {
+        valid_until: verification_data.validUntil,
+        signature: Signature(verification_data.signature.into()),
+        pub_key: PublicKey(verification_data.pubKey.into()),
+        // Trust is looked up by key id on-chain, so it has to match the key the record is verified with
+        key_id: Some(KeyId(verification_data.keyId.0)),
+        dnssec_proof,
+    })
+}
+
 fn parse_record_type(record_type: u8) -> Result<RecordType, Error> {
     if record_type == RecordType::TXT as u8 {
         Ok(RecordType::TXT)
@@ -64,53 +200,71 @@ mod test {
 
         use super::*;
 
+        fn dns_record(name: &str, record_type: u8) -> SolDnsRecord {
+            SolDnsRecord {
+                name: name.into(),
+                recordType: record_type,
+                data: "data".into(),
+                ttl: 123,
+            }
+        }
+
+        fn verification_data() -> SolVerificationData {
+            SolVerificationData {
+                validUntil: 456,
+                signature: bytes!("1234"),
+                pubKey: bytes!("5678"),
+                keyId: Default::default(),
+                dnssecProof: Default::default(),
+            }
+        }
+
+        fn unverified_email(dns_records: Vec<SolDnsRecord>) -> UnverifiedEmail {
+            UnverifiedEmail {
+                email: "email".into(),
+                verificationData: dns_records.iter().map(|_| verification_data()).collect(),
+                dnsRecords: dns_records,
+                requiredSignedHeaders: vec![],
+            }
+        }
+
         #[test]
         fn test_parse_calldata() {
-            let input_email = UnverifiedEmail {
-                email: "email".into(),
-                dnsRecord: SolDnsRecord {
-                    name: "name".into(),
-                    recordType: 16,
-                    data: "data".into(),
-                    ttl: 123,
-                },
-                verificationData: SolVerificationData {
-                    validUntil: 456,
-                    signature: bytes!("1234"),
-                    pubKey: bytes!("5678"),
-                },
-            };
+            let input_email = unverified_email(vec![dns_record("name", 16)]);
             let bytecode = UnverifiedEmail::abi_encode(&input_email);
 
-            let (raw_email, dns_records, _) = UnverifiedEmail::parse_calldata(&bytecode).unwrap();
+            let (raw_email, dns_records, required_signed_headers) =
+                UnverifiedEmail::parse_calldata(&bytecode).unwrap();
             assert_eq!(raw_email, "email".as_bytes());
+            let dns_records: Vec<_> = dns_records.into_iter().map(|(record, _)| record).collect();
             assert_eq!(
                 dns_records,
-                DNSRecord {
+                [DNSRecord {
                     name: "name".into(),
                     record_type: RecordType::TXT,
                     data: "data".into(),
                     ttl: 123,
-                }
+                }]
             );
+            assert!(required_signed_headers.is_empty());
+        }
+
+        #[test]
+        fn parses_multiple_dns_records() {
+            let input_email = unverified_email(vec![dns_record("old", 16), dns_record("new", 16)]);
+            let bytecode = UnverifiedEmail::abi_encode(&input_email);
+
+            let (_, dns_records, _) = UnverifiedEmail::parse_calldata(&bytecode).unwrap();
+            let names: Vec<_> = dns_records
+                .iter()
+                .map(|(record, _)| record.name.as_str())
+                .collect();
+            assert_eq!(names, ["old", "new"]);
         }
 
         #[test]
         fn fails_if_record_type_is_not_supported() {
-            let input_email = UnverifiedEmail {
-                email: "email".into(),
-                dnsRecord: SolDnsRecord {
-                    name: "name".into(),
-                    recordType: 5,
-                    data: "data".into(),
-                    ttl: 123,
-                },
-                verificationData: SolVerificationData {
-                    validUntil: 456,
-                    signature: bytes!("1234"),
-                    pubKey: bytes!("5678"),
-                },
-            };
+            let input_email = unverified_email(vec![dns_record("name", 5)]);
             let bytecode = UnverifiedEmail::abi_encode(&input_email);
 
             let result = UnverifiedEmail::parse_calldata(&bytecode);
@@ -120,11 +274,182 @@ mod test {
             );
         }
 
+        #[test]
+        fn fails_if_verification_data_is_missing() {
+            let input_email = UnverifiedEmail {
+                verificationData: vec![],
+                ..unverified_email(vec![dns_record("name", 16)])
+            };
+            let bytecode = UnverifiedEmail::abi_encode(&input_email);
+
+            assert_eq!(
+                UnverifiedEmail::parse_calldata(&bytecode).unwrap_err(),
+                Error::custom("Expected verification data for each of 1 DNS records, found 0")
+            );
+        }
+
+        #[test]
+        fn parses_dnssec_proof() {
+            let dnssec_proof = SolDnssecProof {
+                dnskeys: vec![SolSignedRRset {
+                    records: vec![bytes!("01")],
+                    signatures: vec![bytes!("02")],
+                }],
+                delegations: vec![],
+                answer: SolSignedRRset {
+                    records: vec![bytes!("03")],
+                    signatures: vec![],
+                },
+            };
+            let input_email = UnverifiedEmail {
+                verificationData: vec![SolVerificationData {
+                    dnssecProof: dnssec_proof.abi_encode().into(),
+                    ..verification_data()
+                }],
+                ..unverified_email(vec![dns_record("name", 16)])
+            };
+            let bytecode = UnverifiedEmail::abi_encode(&input_email);
+
+            let (_, dns_records, _) = UnverifiedEmail::parse_calldata(&bytecode).unwrap();
+            assert_eq!(
+                dns_records[0].1.dnssec_proof,
+                Some(DnssecProof {
+                    dnskeys: vec![SignedRRset {
+                        records: vec![vec![1].into()],
+                        signatures: vec![vec![2].into()],
+                    }],
+                    delegations: vec![],
+                    answer: SignedRRset {
+                        records: vec![vec![3].into()],
+                        signatures: vec![],
+                    },
+                })
+            );
+        }
+
+        #[test]
+        fn fails_if_dnssec_proof_is_not_abi_encoded() {
+            let input_email = UnverifiedEmail {
+                verificationData: vec![SolVerificationData {
+                    dnssecProof: bytes!("00"),
+                    ..verification_data()
+                }],
+                ..unverified_email(vec![dns_record("name", 16)])
+            };
+            let bytecode = UnverifiedEmail::abi_encode(&input_email);
+
+            assert_eq!(UnverifiedEmail::parse_calldata(&bytecode).unwrap_err(), Error::Overrun);
+        }
+
         #[test]
         fn test_error_if_parse_calldata_fails() {
             let result = UnverifiedEmail::parse_calldata(&[0x00]);
             assert_eq!(result, Err(Error::Overrun));
         }
+
+        #[test]
+        fn parses_calldata_with_attachment_query() {
+            let input_email = unverified_email(vec![dns_record("name", 16)]);
+            let query = SolAttachmentQuery {
+                filename: "".into(),
+                contentType: "application/pdf".into(),
+            };
+            let bytecode = (input_email, query).abi_encode_params();
+
+            let ((raw_email, ..), query) =
+                UnverifiedEmail::parse_calldata_with_attachment_query(&bytecode).unwrap();
+            assert_eq!(raw_email, "email".as_bytes());
+            assert_eq!(
+                query,
+                AttachmentQuery {
+                    filename: None,
+                    content_type: Some("application/pdf".into()),
+                }
+            );
+        }
+    }
+
+    mod email_attachment {
+        use hex_literal::hex;
+
+        use super::*;
+
+        #[test]
+        fn hashes_content() {
+            let attachment = SolEmailAttachment::from(Attachment {
+                filename: "hello.txt".into(),
+                content_type: "text/plain".into(),
+                content: b"hello".to_vec(),
+            });
+
+            assert_eq!(
+                attachment.contentHash.0,
+                // echo -n hello | sha256sum
+                hex!("2cf24dba5fc0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824")
+            );
+        }
+    }
+
+    mod unverified_arc_email {
+        use alloy_sol_types::private::bytes;
+
+        use super::*;
+
+        fn dns_record(name: &str) -> SolDnsRecord {
+            SolDnsRecord {
+                name: name.into(),
+                recordType: 16,
+                data: "data".into(),
+                ttl: 123,
+            }
+        }
+
+        fn verification_data() -> SolVerificationData {
+            SolVerificationData {
+                validUntil: 456,
+                signature: bytes!("1234"),
+                pubKey: bytes!("5678"),
+                keyId: Default::default(),
+                dnssecProof: Default::default(),
+            }
+        }
+
+        #[test]
+        fn parses_dns_records_with_verification_data() {
+            let input_email = UnverifiedArcEmail {
+                email: "email".into(),
+                dnsRecords: vec![dns_record("first"), dns_record("second")],
+                verificationData: vec![verification_data(), verification_data()],
+                requiredSignedHeaders: vec!["date".into()],
+            };
+            let calldata = UnverifiedArcEmail::abi_encode(&input_email);
+
+            let (raw_email, dns_records, required_signed_headers) =
+                UnverifiedArcEmail::parse_calldata(&calldata).unwrap();
+            assert_eq!(raw_email, "email".as_bytes());
+            assert_eq!(required_signed_headers, ["date"]);
+            let names: Vec<_> = dns_records
+                .iter()
+                .map(|(record, _)| record.name.as_str())
+                .collect();
+            assert_eq!(names, ["first", "second"]);
+        }
+
+        #[test]
+        fn fails_if_verification_data_is_missing() {
+            let input_email = UnverifiedArcEmail {
+                email: "email".into(),
+                dnsRecords: vec![dns_record("first"), dns_record("second")],
+                verificationData: vec![verification_data()],
+                requiredSignedHeaders: vec![],
+            };
+            let calldata = UnverifiedArcEmail::abi_encode(&input_email);
+
+            assert_eq!(
+                UnverifiedArcEmail::parse_calldata(&calldata).unwrap_err(),
+                Error::custom("Expected verification data for each of 2 DNS records, found 1")
+            );
+        }
     }
 
     mod parse_record_type {
@@ -4,6 +4,12 @@ use thiserror::Error;
 #[derive(Error, Debug, Derivative)]
 #[derivative(PartialEq, Eq)]
 pub enum Error {
+    #[error("Multiple attachments match {0}")]
+    AmbiguousAttachment(String),
+    #[error("ARC signature verification failed for {0}: {1}")]
+    ArcVerification(String, String),
+    #[error("No attachment matches {0}")]
+    AttachmentNotFound(String),
     #[error("Could not parse email: {0}")]
     EmailParse(
         #[from]
@@ -16,14 +22,24 @@ pub enum Error {
     DkimVerification(#[from] cfdkim::DKIMError),
     #[error("Domain mismatch: expected {0}, actual {1}")]
     DomainMismatch(String, String),
+    #[error("Invalid ARC chain: {0}")]
+    InvalidArcChain(String),
+    #[error("Attachment query requires a filename or a content type")]
+    InvalidAttachmentQuery,
     #[error("Invalid DKIM public key record: {0}")]
     InvalidDkimRecord(String),
+    #[error("DKIM signature algorithm `{0}` doesn't match public key type `{1}`")]
+    KeyTypeMismatch(String, String),
     #[error("Invalid From header: {0}")]
     InvalidFromHeader(String),
     #[error("Invalid newline separator: lone '\\n' found not preceded by '\\r'. Found byte {0}")]
     LoneNewLine(u8),
     #[error("Missing CRLF-CRLF separator between email headers and body")]
     MissingBodySeparator,
+    #[error("Missing DNS record: {0}")]
+    MissingDnsRecord(String),
+    #[error("No DKIM DNS records provided")]
+    MissingDnsRecords,
     #[error("Missing From header")]
     MissingFromHeader,
     #[error("Missing required header `{0}` in DKIM h= tag")]
@@ -32,7 +48,7 @@ pub enum Error {
     NoDkimMatchingFromDomain(String),
     #[error("No From header found")]
     NoFromHeader,
-    #[error("Expected exactly one DKIM-Signature header, found {0}")]
+    #[error("Expected exactly one DKIM-Signature header for the DNS record, found {0}")]
     InvalidDkimHeaderCount(usize),
     #[error("VDNS signature verification failed: {0}")]
     VdnsSignatureVerification(
@@ -1,41 +1,210 @@
+mod arc;
 mod dkim;
 mod dns;
 mod email;
 mod errors;
 mod from_header;
+mod nullifier;
 #[cfg(test)]
 mod test_utils;
 
-use dkim::{get_dkim_header, verify_signature::verify_signature};
+use arc::ArcChain;
+use cfdkim::DkimPublicKey;
+use dkim::{
+    DKIMHeader, get_dkim_headers, normalize_dns_name, select_dkim_header, signed_header_values,
+    verify_headers_signed, verify_signature::verify_signature,
+};
 use dns::extract_public_key;
-pub use email::sol::{SolDnsRecord, SolVerificationData, UnverifiedEmail};
+use email::attachment::find_attachment;
+pub use email::sol::{SolDnsRecord, SolVerificationData, UnverifiedArcEmail, UnverifiedEmail};
 use mailparse::{ParsedMail, parse_mail};
-use verifiable_dns::DNSRecord;
+use nullifier::nullifier;
+use rsa::RsaPublicKey;
+use verifiable_dns::{DNSRecord, VerificationData};
 
-pub use crate::{email::Email, errors::Error};
+pub use crate::{
+    email::{
+        ArcEmail, Email, EmailWithAttachment,
+        attachment::{Attachment, AttachmentQuery},
+    },
+    errors::Error,
+};
 
-const REQUIRED_SIGNED_HEADERS: [&str; 3] = ["from", "to", "subject"];
+const DEFAULT_REQUIRED_SIGNED_HEADERS: [&str; 3] = ["from", "to", "subject"];
 
 pub fn parse_and_verify(calldata: &[u8]) -> Result<Email, Error> {
-    let (raw_email, dns_record, verification_data) = UnverifiedEmail::parse_calldata(calldata)?;
+    let (raw_email, dns_records, required_signed_headers) =
+        UnverifiedEmail::parse_calldata(calldata)?;
+    let required_signed_headers = required_signed_headers_policy(required_signed_headers);
 
     let email = parse_mail(&raw_email)?;
+    let dkim_header = verify_dkim(&email, &dns_records, &required_signed_headers)?;
+
+    dkim_verified_email(email, &dkim_header, required_signed_headers)
+}
+
+/// Verifies the email like `parse_and_verify` and returns the attachment selected by the query.
+/// DKIM signatures with the `l=` tag are rejected, so the body hash covers every attachment.
+pub fn parse_and_verify_attachment(calldata: &[u8]) -> Result<EmailWithAttachment, Error> {
+    let ((raw_email, dns_records, required_signed_headers), query) =
+        UnverifiedEmail::parse_calldata_with_attachment_query(calldata)?;
+    let required_signed_headers = required_signed_headers_policy(required_signed_headers);
+
+    let email = parse_mail(&raw_email)?;
+    let dkim_header = verify_dkim(&email, &dns_records, &required_signed_headers)?;
+    let attachment = find_attachment(&email, &query)?;
+
+    Ok(EmailWithAttachment {
+        email: dkim_verified_email(email, &dkim_header, required_signed_headers)?,
+        attachment,
+    })
+}
+
+fn dkim_verified_email(
+    email: ParsedMail,
+    dkim_header: &DKIMHeader,
+    required_signed_headers: Vec<String>,
+) -> Result<Email, Error> {
+    let signed_headers = signed_header_values(&email, dkim_header.signed_headers());
+    let email: Email = email.try_into()?;
+    Ok(Email {
+        nullifier: nullifier(&email.from, &signed_headers),
+        signed_headers,
+        required_signed_headers,
+        ..email
+    })
+}
+
+// Tries the DNS records in order, so that an email signed before a key rollover can be proven with
+// both the old and the new key supplied. Returns the first verified DKIM signature, or the error of
+// the last record.
+fn verify_dkim(
+    email: &ParsedMail,
+    dns_records: &[(DNSRecord, VerificationData)],
+    required_signed_headers: &[String],
+) -> Result<DKIMHeader, Error> {
+    let dkim_headers = get_dkim_headers(email)?;
+
+    let mut last_error = Error::MissingDnsRecords;
+    for (dns_record, verification_data) in dns_records {
+        match verify_dkim_with_record(
+            email,
+            &dkim_headers,
+            dns_record,
+            verification_data,
+            required_signed_headers,
+        ) {
+            Ok(dkim_header) => return Ok(dkim_header),
+            Err(err) => last_error = err,
+        }
+    }
+    Err(last_error)
+}
+
+fn verify_dkim_with_record(
+    email: &ParsedMail,
+    dkim_headers: &[DKIMHeader],
+    dns_record: &DNSRecord,
+    verification_data: &VerificationData,
+    required_signed_headers: &[String],
+) -> Result<DKIMHeader, Error> {
     let dkim_public_key = extract_public_key(&dns_record.data)?;
+    let dkim_header = select_dkim_header(dkim_headers, dns_record)?;
+
+    validate_headers(email, dkim_header, dns_record, &dkim_public_key, required_signed_headers)?;
+    dns_record.verify(verification_data)?;
+    verify_signature(email, dkim_public_key)?;
+
+    Ok(dkim_header.clone())
+}
+
+/// Verifies an email through its ARC chain rather than the DKIM signature of the From domain, so
+/// that emails forwarded by mailing lists or forwarding services can be proven. The content is
+/// vouched for by the sealers only, which is why their domains are returned.
+pub fn parse_and_verify_arc(calldata: &[u8]) -> Result<ArcEmail, Error> {
+    let (raw_email, dns_records, required_signed_headers) =
+        UnverifiedArcEmail::parse_calldata(calldata)?;
+    let required_signed_headers = required_signed_headers_policy(required_signed_headers);
+
+    let email = parse_mail(&raw_email)?;
+    verify_no_fake_separator(parse_headers_bytes(email.raw_bytes)?)?;
+    for (dns_record, verification_data) in &dns_records {
+        dns_record.verify(verification_data)?;
+    }
+
+    let arc_chain = ArcChain::parse(&email)?;
+    let signed_headers = arc_chain.signed_headers();
+    verify_headers_signed(&signed_headers, &required_signed_headers)?;
+    let sealing_domains = arc_chain
+        .verify(&email, |selector, domain| find_rsa_public_key(&dns_records, selector, domain))?;
+
+    let signed_headers = signed_header_values(&email, signed_headers);
+    let email: Email = email.try_into()?;
+    Ok(ArcEmail {
+        email: Email {
+            nullifier: nullifier(&email.from, &signed_headers),
+            signed_headers,
+            required_signed_headers,
+            ..email
+        },
+        sealing_domains,
+    })
+}
+
+/// Lowercases and deduplicates the requested header names, falling back to the default set if none
+/// were requested. `From` is always required, as the sender domain is checked against it.
+fn required_signed_headers_policy(requested: Vec<String>) -> Vec<String> {
+    let requested: Vec<String> = if requested.is_empty() {
+        DEFAULT_REQUIRED_SIGNED_HEADERS.map(String::from).into()
+    } else {
+        requested
+            .into_iter()
+            .map(|name| name.trim().to_lowercase())
+            .collect()
+    };
 
-    validate_headers(&email, &dns_record)?;
-    dns_record.verify(&verification_data)?;
-    verify_signature(&email, dkim_public_key)?;
+    let mut policy = vec!["from".to_string()];
+    for name in requested {
+        if !policy.contains(&name) {
+            policy.push(name);
+        }
+    }
+    policy
+}
 
-    Ok(email.try_into()?)
+fn find_rsa_public_key<T>(
+    dns_records: &[(DNSRecord, T)],
+    selector: &str,
+    domain: &str,
+) -> Result<RsaPublicKey, Error> {
+    let name = normalize_dns_name(&format!("{selector}._domainkey.{domain}"));
+    let (dns_record, _) = dns_records
+        .iter()
+        .find(|(dns_record, _)| normalize_dns_name(&dns_record.name) == name)
+        .ok_or_else(|| Error::MissingDnsRecord(name.clone()))?;
+
+    match extract_public_key(&dns_record.data)? {
+        DkimPublicKey::Rsa(public_key) => Ok(public_key),
+        public_key => Err(Error::InvalidDkimRecord(format!(
+            "Expected rsa key for {name}, found {}",
+            public_key.key_type()
+        ))),
+    }
 }
 
-fn validate_headers(email: &ParsedMail, dns_record: &DNSRecord) -> Result<(), Error> {
+fn validate_headers(
+    email: &ParsedMail,
+    dkim_header: &DKIMHeader,
+    dns_record: &DNSRecord,
+    dkim_public_key: &DkimPublicKey,
+    required_signed_headers: &[String],
+) -> Result<(), Error> {
     let raw_headers = parse_headers_bytes(email.raw_bytes)?;
-    let dkim_header = get_dkim_header(email)?;
 
     verify_no_fake_separator(raw_headers)?;
     dkim_header.verify_dns_consistency(dns_record)?;
-    dkim_header.verify_required_headers_signed(&REQUIRED_SIGNED_HEADERS)?;
+    dkim_header.verify_key_type(dkim_public_key)?;
+    dkim_header.verify_required_headers_signed(required_signed_headers)?;
     dkim_header.verify_body_length_tag()?;
 
     Ok(())
@@ -65,10 +234,11 @@ fn verify_no_fake_separator(raw_headers: &[u8]) -> Result<(), Error> {
 #[cfg(test)]
 mod test {
     use alloy_sol_types::{SolValue, private::bytes};
+    use hex_literal::hex;
     use lazy_static::lazy_static;
     use test_utils::read_email_from_file;
     use verifiable_dns::{
-        DNSRecord, RecordType,
+        DNSRecord, PublicKey, RecordType,
         verifiable_dns::{sign_record::sign_record, signer::Signer},
     };
 
@@ -89,7 +259,9 @@ mod test {
         SolVerificationData {
             validUntil: verification_data_signed.valid_until,
             signature: verification_data_signed.signature.0.clone().into(),
+            keyId: verification_data_signed.pub_key.key_id().0.into(),
             pubKey: verification_data_signed.pub_key.0.into(),
+            dnssecProof: Default::default(),
         }
     }
 
@@ -100,12 +272,24 @@ mod test {
     ) -> Vec<u8> {
         UnverifiedEmail {
             email: email.into(),
-            dnsRecord: dns_record.clone(),
-            verificationData: verification_data.clone(),
+            dnsRecords: vec![dns_record.clone()],
+            verificationData: vec![verification_data.clone()],
+            requiredSignedHeaders: vec![],
         }
         .abi_encode()
     }
 
+    fn default_required_signed_headers() -> Vec<String> {
+        DEFAULT_REQUIRED_SIGNED_HEADERS.map(String::from).into()
+    }
+
+    fn signed_headers(headers: &[(&str, &str)]) -> Vec<(String, String)> {
+        headers
+            .iter()
+            .map(|(name, value)| ((*name).to_string(), (*value).to_string()))
+            .collect()
+    }
+
     lazy_static! {
         static ref DNS_FIXTURE: SolDnsRecord = SolDnsRecord {
             name: "google._domainkey.vlayer.xyz".into(),
@@ -134,6 +318,20 @@ mod test {
                 to: "Ivan Rukhavets <ivanruch@gmail.com>".into(),
                 subject: Some("Is dinner ready?".into(),),
                 body: "Foo bar\r\n\r\n".into(),
+                html_body: Some("<div dir=\"ltr\">Foo bar</div>\r\n\r\n".into()),
+                signed_headers: signed_headers(&[
+                    ("to", "Ivan Rukhavets <ivanruch@gmail.com>"),
+                    ("subject", "Is dinner ready?"),
+                    (
+                        "message-id",
+                        "<CAM-4p2UYUgouPwaP_aCddBiRr-ba4p_mJnORuGUHb5XULpe6TQ@mail.gmail.com>"
+                    ),
+                    ("date", "Thu, 12 Dec 2024 15:59:07 +0100"),
+                    ("from", "Ivan Rukhavets <ivan@vlayer.xyz>"),
+                    ("mime-version", "1.0"),
+                ]),
+                required_signed_headers: default_required_signed_headers(),
+                nullifier: hex!("935f64e4d3546c59802bb58e279d3c24ec4102336e32b82a25bc43375e870178"),
             }
         );
         Ok(())
@@ -151,11 +349,386 @@ mod test {
                 to: "Ivan Rukhavets <ivan@vlayer.xyz>".into(),
                 subject: Some("Email with attachment".into(),),
                 body: "Hello,\r\ntake a look at the following remappings.\r\n\r\nBest Regards,\r\nPiotr\r\n\r\n".into(),
+                html_body: Some("<div dir=\"ltr\">Hello,<div>take a look at the following remappings.</div><div><br></div><div>Best Regards,</div><div>Piotr</div></div>\r\n\r\n".into()),
+                signed_headers: signed_headers(&[
+                    ("to", "Ivan Rukhavets <ivan@vlayer.xyz>"),
+                    ("subject", "Email with attachment"),
+                    (
+                        "message-id",
+                        "<CAMnqj52Xgt-3KCbry6GPpz-9ayUwbrY2xuapBFAUB1CO+Qn0VQ@mail.gmail.com>"
+                    ),
+                    ("date", "Thu, 13 Feb 2025 14:06:23 +0100"),
+                    ("from", "Piotr Żelazko <piotr@vlayer.xyz>"),
+                    ("mime-version", "1.0"),
+                ]),
+                required_signed_headers: default_required_signed_headers(),
+                nullifier: hex!("7236a2ccd7af43ccc0e539f786ef8c71b3fb3b750b3dd6393ba94f86fa07ef2b"),
             }
         );
         Ok(())
     }
 
+    mod required_signed_headers {
+        use super::*;
+
+        fn verify_requiring(required_signed_headers: &[&str]) -> Result<Email, Error> {
+            let calldata = UnverifiedEmail {
+                email: signed_email_fixture(),
+                dnsRecords: vec![DNS_FIXTURE.clone()],
+                verificationData: vec![VERIFICATION_DATA.clone()],
+                requiredSignedHeaders: required_signed_headers
+                    .iter()
+                    .map(|&name| name.into())
+                    .collect(),
+            }
+            .abi_encode();
+            parse_and_verify(&calldata)
+        }
+
+        #[test]
+        fn passes_when_requested_headers_are_signed() -> anyhow::Result<()> {
+            let email = verify_requiring(&["Date", "message-id"])?;
+
+            assert_eq!(email.required_signed_headers, ["from", "date", "message-id"]);
+            Ok(())
+        }
+
+        #[test]
+        fn fails_when_requested_header_is_not_signed() {
+            assert_eq!(
+                verify_requiring(&["date", "cc"]).unwrap_err(),
+                Error::MissingRequiredHeaderTag("cc".into())
+            );
+        }
+
+        #[test]
+        fn applies_default_policy_when_none_requested() -> anyhow::Result<()> {
+            let email = verify_requiring(&[])?;
+
+            assert_eq!(email.required_signed_headers, ["from", "to", "subject"]);
+            Ok(())
+        }
+
+        #[test]
+        fn policy_always_requires_from_once() {
+            let requested = ["subject", " FROM", "subject"].map(String::from).into();
+
+            assert_eq!(required_signed_headers_policy(requested), ["from", "subject"]);
+        }
+    }
+
+    mod attachment {
+        use super::*;
+        use crate::email::sol::SolAttachmentQuery;
+
+        fn attachment_calldata(email: &str, filename: &str, content_type: &str) -> Vec<u8> {
+            let unverified_email = UnverifiedEmail {
+                email: email.into(),
+                dnsRecords: vec![DNS_FIXTURE.clone()],
+                verificationData: vec![VERIFICATION_DATA.clone()],
+                requiredSignedHeaders: vec![],
+            };
+            let query = SolAttachmentQuery {
+                filename: filename.into(),
+                contentType: content_type.into(),
+            };
+            (unverified_email, query).abi_encode_params()
+        }
+
+        #[test]
+        fn returns_attachment_of_valid_email() -> anyhow::Result<()> {
+            let email = read_email_from_file("./testdata/email_with_attachment.eml");
+            let calldata = attachment_calldata(&email, "remappings.txt", "text/plain");
+
+            let EmailWithAttachment { email, attachment } = parse_and_verify_attachment(&calldata)?;
+            assert_eq!(email.from, "piotr@vlayer.xyz");
+            assert_eq!(attachment.filename, "remappings.txt");
+            assert_eq!(attachment.content_type, "text/plain");
+            assert!(
+                attachment
+                    .content
+                    .starts_with(b"@openzeppelin-contracts-5.0.1/=")
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn fails_for_modified_attachment() {
+            let email = read_email_from_file("./testdata/email_with_attachment.eml")
+                .replace("cy9mb3JnZS1zdGQtMS45LjQvCg==", "cy9mb3JnZS1zdGQtMS45LjUvCg==");
+            let calldata = attachment_calldata(&email, "remappings.txt", "");
+
+            assert!(matches!(
+                parse_and_verify_attachment(&calldata).unwrap_err(),
+                Error::DkimVerification(_)
+            ));
+        }
+
+        #[test]
+        fn fails_for_missing_attachment() {
+            let calldata = attachment_calldata(&signed_email_fixture(), "", "application/pdf");
+
+            assert_eq!(
+                parse_and_verify_attachment(&calldata).unwrap_err(),
+                Error::AttachmentNotFound("content type `application/pdf`".into())
+            );
+        }
+    }
+
+    mod dual_signed_email {
+        use super::*;
+
+        // Example message and keys from RFC 8463, Appendix A
+        lazy_static! {
+            static ref ED25519_DNS_FIXTURE: SolDnsRecord = SolDnsRecord {
+                name: "brisbane._domainkey.football.example.com".into(),
+                recordType: 16,
+                data: "v=DKIM1; k=ed25519; p=11qYAYKxCrfVS/7TyWQHOg7hcvPapiMlrwIaaPcHURo=".into(),
+                ttl: 0,
+            };
+            static ref RSA_DNS_FIXTURE: SolDnsRecord = SolDnsRecord {
+                name: "test._domainkey.football.example.com".into(),
+                recordType: 16,
+                data: "v=DKIM1; k=rsa; p=MIGfMA0GCSqGSIb3DQEBAQUAA4GNADCBiQKBgQDkHlOQoBTzWRiGs5V6NpP3idY6Wk08a5qhdR6wy5bdOKb2jLQiY/J16JYi0Qvx/byYzCNb3W91y3FutACDfzwQ/BC/e/8uBsCR+yz1Lxj+PL6lHvqMKrM3rG4hstT5QjvHO9PzoxZyVYLzBfO2EeC3Ip3G+2kryOTIKT+l/K4w3QIDAQAB".into(),
+                ttl: 0,
+            };
+        }
+
+        // Both signatures sign the same Message-ID
+        const NULLIFIER: [u8; 32] =
+            hex!("c9cd12045ca09bc3d0a2d071d4b81157c5372ef4c24c83e17f60fd217adf1b58");
+
+        fn email() -> Email {
+            Email {
+                from: "joe@football.example.com".into(),
+                to: "Suzie Q <suzie@shopping.example.net>".into(),
+                subject: Some("Is dinner ready?".into()),
+                body: "Hi.\r\n\r\nWe lost the game.  Are you hungry yet?\r\n\r\nJoe.\r\n".into(),
+                html_body: None,
+                signed_headers: signed_headers(&[
+                    ("from", "Joe SixPack <joe@football.example.com>"),
+                    ("to", "Suzie Q <suzie@shopping.example.net>"),
+                    ("subject", "Is dinner ready?"),
+                    ("date", "Fri, 11 Jul 2003 21:00:37 -0700 (PDT)"),
+                    ("message-id", "<20030712040037.46341.5F8J@football.example.com>"),
+                ]),
+                required_signed_headers: default_required_signed_headers(),
+                nullifier: NULLIFIER,
+            }
+        }
+
+        fn verify_with(dns_record: &SolDnsRecord) -> Result<Email, Error> {
+            let email = read_email_from_file("./testdata/dual_signed_email.eml");
+            let calldata = calldata(&email, dns_record, &sign_dns_fixture(dns_record));
+            parse_and_verify(&calldata)
+        }
+
+        #[test]
+        fn passes_for_ed25519_signature() -> anyhow::Result<()> {
+            assert_eq!(verify_with(&ED25519_DNS_FIXTURE)?, email());
+            Ok(())
+        }
+
+        #[test]
+        fn passes_for_rsa_signature() -> anyhow::Result<()> {
+            assert_eq!(verify_with(&RSA_DNS_FIXTURE)?, email());
+            Ok(())
+        }
+
+        #[test]
+        fn gives_same_nullifier_for_both_signatures() -> anyhow::Result<()> {
+            assert_eq!(
+                verify_with(&ED25519_DNS_FIXTURE)?.nullifier,
+                verify_with(&RSA_DNS_FIXTURE)?.nullifier
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn fails_for_key_of_other_signature() {
+            let dns_record = SolDnsRecord {
+                data: ED25519_DNS_FIXTURE.data.clone(),
+                ..RSA_DNS_FIXTURE.clone()
+            };
+
+            assert_eq!(
+                verify_with(&dns_record).unwrap_err(),
+                Error::KeyTypeMismatch("rsa-sha256".into(), "ed25519".into())
+            );
+        }
+    }
+
+    mod multiple_dns_records {
+        use super::*;
+
+        fn calldata_with_records(email: &str, dns_records: &[&SolDnsRecord]) -> Vec<u8> {
+            UnverifiedEmail {
+                email: email.into(),
+                dnsRecords: dns_records.iter().map(|&record| record.clone()).collect(),
+                verificationData: dns_records
+                    .iter()
+                    .map(|record| sign_dns_fixture(record))
+                    .collect(),
+                requiredSignedHeaders: vec![],
+            }
+            .abi_encode()
+        }
+
+        lazy_static! {
+            static ref ROTATED_DNS_FIXTURE: SolDnsRecord = SolDnsRecord {
+                name: "google2._domainkey.vlayer.xyz".into(),
+                ..DNS_FIXTURE.clone()
+            };
+        }
+
+        #[test]
+        fn passes_if_any_record_verifies() -> anyhow::Result<()> {
+            let calldata = calldata_with_records(
+                &signed_email_fixture(),
+                &[&ROTATED_DNS_FIXTURE, &DNS_FIXTURE],
+            );
+
+            assert_eq!(parse_and_verify(&calldata)?.from, "ivan@vlayer.xyz");
+            Ok(())
+        }
+
+        #[test]
+        fn fails_if_no_record_verifies() {
+            let calldata = calldata_with_records(
+                &signed_email_fixture(),
+                &[&ROTATED_DNS_FIXTURE, &SPOOFED_DOMAIN_DNS_FIXTURE],
+            );
+
+            assert_eq!(
+                parse_and_verify(&calldata).unwrap_err(),
+                Error::DomainMismatch(
+                    "google._domainkey.vlayer.xyz".into(),
+                    "google._domainkey.spoofed-vlayer.xyz".into()
+                )
+            );
+        }
+
+        #[test]
+        fn fails_for_no_records() {
+            let calldata = calldata_with_records(&signed_email_fixture(), &[]);
+
+            assert_eq!(parse_and_verify(&calldata).unwrap_err(), Error::MissingDnsRecords);
+        }
+    }
+
+    mod arc_sealed_email {
+        use super::*;
+
+        lazy_static! {
+            static ref LISTS_DNS_FIXTURE: SolDnsRecord = SolDnsRecord {
+                name: "arc._domainkey.lists.example.org".into(),
+                recordType: 16,
+                data: "v=DKIM1; k=rsa; p=MIGfMA0GCSqGSIb3DQEBAQUAA4GNADCBiQKBgQDgzYMQrCvQRZw3/EU1S1MhcXng7VLnH9mdPujYbfcaBm3SXGwsYZc5o72f3JJkYmED7P9nwrUe9Jod9m6+Fq576SCjY9+31M6zaBlmn82yi736SpBmuZF4unKHM1zKIhkIhR433JtHxe652OGPYh9X2cA3osIZI1QX1zzIppbQsQIDAQAB".into(),
+                ttl: 0,
+            };
+            static ref FORWARDER_DNS_FIXTURE: SolDnsRecord = SolDnsRecord {
+                name: "seal._domainkey.forwarder.example.net".into(),
+                recordType: 16,
+                data: "v=DKIM1; k=rsa; p=MIGfMA0GCSqGSIb3DQEBAQUAA4GNADCBiQKBgQDt8FPAV7JAKfr2FBiaXpL05r0bwwzFkDLZwg76yUgZ/Flnws08dPg/6y5F71JY821JukpoYGMbITtv7AXxBaDRALcL+OGR2R6GUIlXaD68f2hOHNJmJ1T6mI0k4Oz0YAoRypWEvT+cm/ybcfod8vjoKGz2eumfOXZJVroxHzGCxwIDAQAB".into(),
+                ttl: 0,
+            };
+        }
+
+        fn arc_calldata(email: &str, dns_records: &[&SolDnsRecord]) -> Vec<u8> {
+            UnverifiedArcEmail {
+                email: email.into(),
+                dnsRecords: dns_records.iter().map(|&record| record.clone()).collect(),
+                verificationData: dns_records
+                    .iter()
+                    .map(|record| sign_dns_fixture(record))
+                    .collect(),
+                requiredSignedHeaders: vec![],
+            }
+            .abi_encode()
+        }
+
+        fn arc_sealed_email() -> String {
+            read_email_from_file("./testdata/arc_sealed_email.eml")
+        }
+
+        #[test]
+        fn passes_for_valid_chain() -> anyhow::Result<()> {
+            let calldata =
+                arc_calldata(&arc_sealed_email(), &[&LISTS_DNS_FIXTURE, &FORWARDER_DNS_FIXTURE]);
+
+            assert_eq!(
+                parse_and_verify_arc(&calldata)?,
+                ArcEmail {
+                    email: Email {
+                        from: "alice@example.com".into(),
+                        to: "Receipts <receipts@lists.example.org>".into(),
+                        subject: Some("[receipts] Your receipt #42".into()),
+                        body: "Thank you for your order #42.\r\n\r\n--\r\nSent via the receipts mailing list\r\n".into(),
+                        html_body: None,
+                        signed_headers: signed_headers(&[
+                            ("from", "Alice <alice@example.com>"),
+                            ("to", "Receipts <receipts@lists.example.org>"),
+                            ("subject", "[receipts] Your receipt #42"),
+                            ("date", "Thu, 13 Feb 2025 14:06:23 +0100"),
+                            ("message-id", "<receipt-42@example.com>"),
+                        ]),
+                        required_signed_headers: default_required_signed_headers(),
+                        nullifier: hex!(
+                            "6f93a106dc3044f9f6a9b2b38dfae7be34d66bf57864576cac2f8ed46f171a19"
+                        ),
+                    },
+                    sealing_domains: vec!["lists.example.org".into(), "forwarder.example.net".into()],
+                }
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn fails_for_modified_body() {
+            let email = arc_sealed_email().replace("order #42", "order #43");
+            let calldata = arc_calldata(&email, &[&LISTS_DNS_FIXTURE, &FORWARDER_DNS_FIXTURE]);
+
+            assert_eq!(
+                parse_and_verify_arc(&calldata).unwrap_err(),
+                Error::ArcVerification(
+                    "ARC-Message-Signature i=2".into(),
+                    "body hash did not verify".into()
+                )
+            );
+        }
+
+        #[test]
+        fn fails_for_modified_arc_set() {
+            let email = arc_sealed_email().replace("dkim=pass header.d", "dkim=none header.d");
+            let calldata = arc_calldata(&email, &[&LISTS_DNS_FIXTURE, &FORWARDER_DNS_FIXTURE]);
+
+            assert_eq!(
+                parse_and_verify_arc(&calldata).unwrap_err(),
+                Error::ArcVerification("ARC-Seal i=2".into(), "signature did not verify".into())
+            );
+        }
+
+        #[test]
+        fn fails_for_missing_dns_record() {
+            let calldata = arc_calldata(&arc_sealed_email(), &[&FORWARDER_DNS_FIXTURE]);
+
+            assert_eq!(
+                parse_and_verify_arc(&calldata).unwrap_err(),
+                Error::MissingDnsRecord("arc._domainkey.lists.example.org".into())
+            );
+        }
+
+        #[test]
+        fn fails_for_email_without_arc_headers() {
+            let calldata = arc_calldata(&signed_email_fixture(), &[&DNS_FIXTURE]);
+
+            assert_eq!(
+                parse_and_verify_arc(&calldata).unwrap_err(),
+                Error::InvalidArcChain("No ARC headers found".into())
+            );
+        }
+    }
+
     #[test]
     fn fails_for_missing_dns_record() {
         let email = signed_email_fixture();
@@ -175,6 +748,7 @@ mod test {
 
     mod verifiable_dns_integration {
         use super::*;
+        use crate::email::sol::{SolDnssecProof, SolSignedRRset};
 
         #[test]
         fn fails_for_invalid_vdns_signature() {
@@ -197,7 +771,9 @@ mod test {
             let verification_data = SolVerificationData {
                 signature: Default::default(),
                 pubKey: Default::default(),
+                keyId: PublicKey(Default::default()).key_id().0.into(),
                 validUntil: 0,
+                dnssecProof: Default::default(),
             };
             let calldata = calldata(&email, &DNS_FIXTURE, &verification_data);
 
@@ -206,6 +782,47 @@ mod test {
                 "VDNS signature verification failed: Public key decoding error: ASN.1 error: ASN.1 DER message is incomplete: expected 1, actual 0 at DER byte 0".to_string()
             );
         }
+
+        #[test]
+        fn fails_for_mismatched_key_id() {
+            let email = signed_email_fixture();
+            let verification_data = SolVerificationData {
+                keyId: [0xde; 32].into(),
+                ..VERIFICATION_DATA.clone()
+            };
+            let calldata = calldata(&email, &DNS_FIXTURE, &verification_data);
+
+            assert_eq!(
+                parse_and_verify(&calldata).unwrap_err().to_string(),
+                format!(
+                    "VDNS signature verification failed: Key id {} doesn't match the public key",
+                    "de".repeat(32)
+                )
+            );
+        }
+
+        #[test]
+        fn verifies_dnssec_proof_instead_of_vdns_signature() {
+            let email = signed_email_fixture();
+            let dnssec_proof = SolDnssecProof {
+                dnskeys: vec![],
+                delegations: vec![],
+                answer: SolSignedRRset {
+                    records: vec![],
+                    signatures: vec![],
+                },
+            };
+            let verification_data = SolVerificationData {
+                dnssecProof: dnssec_proof.abi_encode().into(),
+                ..VERIFICATION_DATA.clone()
+            };
+            let calldata = calldata(&email, &DNS_FIXTURE, &verification_data);
+
+            assert_eq!(
+                parse_and_verify(&calldata).unwrap_err().to_string(),
+                "VDNS signature verification failed: DNSSEC verification error: Invalid chain of trust: missing root DNSKEY RRset".to_string()
+            );
+        }
     }
 
     mod verify_no_lone_separator {
@@ -0,0 +1,69 @@
+use alloy_sol_types::{SolValue, private::keccak256};
+
+const MESSAGE_ID_HEADER: &str = "message-id";
+
+/// Identifier of an email that contracts can store to accept every email once. Computed as
+/// `keccak256(abi.encode(domain, messageId))` from the lowercase domain of the From address and the
+/// signed `Message-ID` header. It doesn't depend on the verified signature, so it's the same for
+/// every signature of the sender domain and for every path the email was forwarded through.
+/// Zero if `Message-ID` isn't signed. `signed_headers` are the values of the signed headers.
+pub fn nullifier(from: &str, signed_headers: &[(String, String)]) -> [u8; 32] {
+    let message_id = signed_headers
+        .iter()
+        .find(|(name, _)| name == MESSAGE_ID_HEADER);
+    let (Some((_, domain)), Some((_, message_id))) = (from.rsplit_once('@'), message_id) else {
+        return [0; 32];
+    };
+    message_nullifier(domain, message_id)
+}
+
+fn message_nullifier(domain: &str, message_id: &str) -> [u8; 32] {
+    let encoded = (domain.to_lowercase(), message_id.trim()).abi_encode_params();
+    keccak256(encoded).0
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use super::*;
+
+    fn signed_headers(message_id: &str) -> Vec<(String, String)> {
+        vec![
+            ("from".into(), "alice@example.com".into()),
+            ("message-id".into(), message_id.into()),
+        ]
+    }
+
+    #[test]
+    fn matches_solidity_encoding() {
+        assert_eq!(
+            nullifier("alice@Example.com", &signed_headers(" <id@example.com>")),
+            // Same as keccak256(abi.encode(domain, messageId)) in Solidity
+            hex!("a73d5d050ee4dc5f15734a6eb5306e86b84d1d280d03a2b019a34bb49ea4a1a2")
+        );
+    }
+
+    #[test]
+    fn ignores_local_part_of_sender() {
+        assert_eq!(
+            nullifier("alice@example.com", &signed_headers("<id@example.com>")),
+            nullifier("bob@example.com", &signed_headers("<id@example.com>"))
+        );
+    }
+
+    #[test]
+    fn differs_per_domain() {
+        assert_ne!(
+            nullifier("alice@example.com", &signed_headers("<id@example.com>")),
+            nullifier("alice@example.org", &signed_headers("<id@example.com>"))
+        );
+    }
+
+    #[test]
+    fn is_zero_when_message_id_is_not_signed() {
+        let signed_headers = [("from".to_string(), "alice@example.com".to_string())];
+
+        assert_eq!(nullifier("alice@example.com", &signed_headers), [0; 32]);
+    }
+}
@@ -42,6 +42,10 @@ impl ReadTx for InMemoryReadTx<'_> {
         let prefixed_key = add_table_prefix(table, key);
         Ok(self.store.get(prefixed_key.as_slice()).cloned())
     }
+
+    fn entries(&self, table: &str) -> DbResult<Vec<(Box<[u8]>, Box<[u8]>)>> {
+        Ok(table_entries(self.store, table))
+    }
 }
 
 impl ReadTx for InMemoryReadWriteTx<'_> {
@@ -49,6 +53,20 @@ impl ReadTx for InMemoryReadWriteTx<'_> {
         let prefixed_key = add_table_prefix(table, key);
         Ok(self.store.get(prefixed_key.as_slice()).cloned())
     }
+
+    fn entries(&self, table: &str) -> DbResult<Vec<(Box<[u8]>, Box<[u8]>)>> {
+        Ok(table_entries(self.store, table))
+    }
+}
+
+fn table_entries(store: &KeyValueMap, table: &str) -> Vec<(Box<[u8]>, Box<[u8]>)> {
+    store
+        .iter()
+        .filter_map(|(key, value)| {
+            key.strip_prefix(table.as_bytes())
+                .map(|key| (key.into(), value.clone()))
+        })
+        .collect()
 }
 
 fn add_table_prefix(table: impl AsRef<str>, key: impl AsRef<[u8]>) -> Vec<u8> {
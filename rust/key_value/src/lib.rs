@@ -20,6 +20,8 @@ assert_obj_safe!(Database);
 #[auto_impl(Box)]
 pub trait ReadTx {
     fn get(&self, table: &str, key: &[u8]) -> DbResult<Option<Box<[u8]>>>;
+    /// Returns all `(key, value)` pairs stored in `table`.
+    fn entries(&self, table: &str) -> DbResult<Vec<(Box<[u8]>, Box<[u8]>)>>;
 }
 
 assert_obj_safe!(ReadTx);
@@ -73,6 +73,20 @@ impl<TK: TransactionKind> ReadTx for MdbxTx<'_, TK> {
             .map_err(DbError::custom)?
             .map(Vec::into_boxed_slice))
     }
+
+    fn entries(&self, table: &str) -> DbResult<Vec<(Box<[u8]>, Box<[u8]>)>> {
+        let table = self.get_table(table)?;
+        let mut cursor = self.tx.cursor(&table).map_err(DbError::custom)?;
+        let mut entries = Vec::new();
+        let mut entry = cursor
+            .first::<Vec<u8>, Vec<u8>>()
+            .map_err(DbError::custom)?;
+        while let Some((key, value)) = entry {
+            entries.push((key.into_boxed_slice(), value.into_boxed_slice()));
+            entry = cursor.next::<Vec<u8>, Vec<u8>>().map_err(DbError::custom)?;
+        }
+        Ok(entries)
+    }
 }
 
 impl WriteTx for MdbxTx<'_, RW> {
@@ -63,6 +63,27 @@ fn db_flow() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn entries() -> Result<()> {
+    temp_db!(db);
+
+    crate_and_insert(&mut db, [0], [1])?;
+    insert(&mut db, [2], [3])?;
+    let entries = db.begin_ro()?.entries(TABLE)?;
+    assert_eq!(entries, vec![([0].into(), [1].into()), ([2].into(), [3].into())]);
+
+    Ok(())
+}
+
+#[test]
+fn entries_no_table() {
+    temp_db!(db);
+    assert_eq!(
+        db.begin_ro().unwrap().entries(TABLE).unwrap_err(),
+        DbError::non_existing_table(TABLE)
+    );
+}
+
 #[test]
 fn get_no_table() {
     temp_db!(db);
@@ -7,9 +7,11 @@ use std::{
 };
 
 use alloy_primitives::{Address, BlockNumber, Bytes, StorageKey, StorageValue, TxNumber, U256};
-use block_header::EvmBlockHeader;
+use block_header::{EvmBlockHeader, Receipt};
 use ethers_core::types::BlockNumber as BlockTag;
-use json::{AccountQuery, BlockQuery, JsonCache, ProofQuery, StorageQuery};
+use json::{
+    AccountQuery, BlockQuery, JsonCache, ProofQuery, ReceiptsQuery, StorageQuery, TransactionsQuery,
+};
 
 use super::{BlockingProvider, EIP1186Proof, Result};
 use crate::never::NeverProvider;
@@ -165,6 +167,31 @@ impl BlockingProvider for CachedProvider {
         }
     }
 
+    fn get_block_receipts(&self, block: BlockNumber) -> Result<Vec<Receipt>> {
+        let mut cache = self.cache.write().expect("poisoned RwLock");
+        match cache.receipts.entry(ReceiptsQuery { block_no: block }) {
+            Entry::Occupied(entry) => Ok(entry.get().clone()),
+            Entry::Vacant(entry) => {
+                let receipts = self.inner.get_block_receipts(block)?;
+                Ok(entry.insert(receipts).clone())
+            }
+        }
+    }
+
+    fn get_block_transactions(&self, block: BlockNumber) -> Result<Vec<Bytes>> {
+        let mut cache = self.cache.write().expect("poisoned RwLock");
+        match cache
+            .transactions
+            .entry(TransactionsQuery { block_no: block })
+        {
+            Entry::Occupied(entry) => Ok(entry.get().clone()),
+            Entry::Vacant(entry) => {
+                let transactions = self.inner.get_block_transactions(block)?;
+                Ok(entry.insert(transactions).clone())
+            }
+        }
+    }
+
     fn get_latest_block_number(&self) -> Result<BlockNumber> {
         todo!()
     }
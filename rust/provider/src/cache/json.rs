@@ -7,7 +7,7 @@ use std::{
 
 use alloy_primitives::{Address, BlockNumber, Bytes, StorageKey, StorageValue, TxNumber, U256};
 use anyhow::Context;
-use block_header::EvmBlockHeader;
+use block_header::{EvmBlockHeader, Receipt};
 use ethers_core::types::BlockNumber as BlockTag;
 use serde::{Deserialize, Serialize};
 
@@ -54,6 +54,16 @@ pub(super) struct ProofQuery {
     pub(super) storage_keys: BTreeSet<StorageKey>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub(super) struct ReceiptsQuery {
+    pub(super) block_no: BlockNumber,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub(super) struct TransactionsQuery {
+    pub(super) block_no: BlockNumber,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
 pub(super) struct StorageQuery {
     pub(super) block_no: BlockNumber,
@@ -79,6 +89,11 @@ pub(crate) struct JsonCache {
     pub(super) code: HashMap<AccountQuery, Bytes>,
     #[serde(with = "ordered_map")]
     pub(super) storage: HashMap<StorageQuery, StorageValue>,
+    // Defaulted, so that cache files recorded before receipts were supported still load
+    #[serde(default, with = "ordered_map")]
+    pub(super) receipts: HashMap<ReceiptsQuery, Vec<Receipt>>,
+    #[serde(default, with = "ordered_map")]
+    pub(super) transactions: HashMap<TransactionsQuery, Vec<Bytes>>,
 }
 
 impl PartialEq for JsonCache {
@@ -98,6 +113,8 @@ impl JsonCache {
             balance: HashMap::new(),
             code: HashMap::new(),
             storage: HashMap::new(),
+            receipts: HashMap::new(),
+            transactions: HashMap::new(),
         }
     }
 
@@ -1,5 +1,5 @@
 use alloy_primitives::{Address, BlockNumber, Bytes, StorageKey, StorageValue, TxNumber, U256};
-use block_header::EvmBlockHeader;
+use block_header::{EvmBlockHeader, Receipt};
 use ethers_core::types::BlockNumber as BlockTag;
 
 use super::{BlockingProvider, EIP1186Proof, Result};
@@ -33,6 +33,14 @@ impl BlockingProvider for DefaultProvider {
         Ok(Default::default())
     }
 
+    fn get_block_receipts(&self, _: BlockNumber) -> Result<Vec<Receipt>> {
+        Ok(Default::default())
+    }
+
+    fn get_block_transactions(&self, _: BlockNumber) -> Result<Vec<Bytes>> {
+        Ok(Default::default())
+    }
+
     fn get_latest_block_number(&self) -> Result<BlockNumber> {
         Ok(Default::default())
     }
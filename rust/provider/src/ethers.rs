@@ -1,16 +1,20 @@
 use core::future::Future;
 
-use alloy_primitives::{B256, BlockNumber, U256};
+use alloy_primitives::{B256, BlockNumber, Bytes, Log, LogData, U256};
 use anyhow::{Context, anyhow};
-use block_header::{EthBlockHeader, EvmBlockHeader};
+use block_header::{EthBlockHeader, EvmBlockHeader, LEGACY_TX_TYPE, Receipt};
 use derive_new::new;
-use ethers_core::types::{Block, BlockNumber as BlockTag};
+use ethers_core::types::{Block, BlockNumber as BlockTag, TransactionReceipt, U64};
 use ethers_providers::{JsonRpcClient, Middleware};
+use futures::{StreamExt, TryStreamExt, stream};
 use tokio::runtime::Handle;
 use tracing::instrument;
 
 use super::{BlockingProvider, EIP1186Proof, Result};
 
+/// Maximum number of requests sent concurrently when fetching all transactions of a block.
+const MAX_CONCURRENT_REQUESTS: usize = 32;
+
 /// A provider that fetches data from an Ethereum node using the ethers crate.
 #[derive(Debug, new)]
 pub struct EthersProvider<T: JsonRpcClient> {
@@ -117,6 +121,37 @@ impl<T: JsonRpcClient> BlockingProvider for EthersProvider<T> {
         })
     }
 
+    #[instrument(skip(self))]
+    fn get_block_receipts(&self, block: BlockNumber) -> Result<Vec<Receipt>> {
+        let receipts = block_on(self.client.get_block_receipts(block))?;
+        receipts.into_iter().map(to_receipt).collect()
+    }
+
+    // There is no standard method returning all raw transactions of a block, so they are
+    // requested one by one, with up to `MAX_CONCURRENT_REQUESTS` requests in flight.
+    #[instrument(skip(self))]
+    fn get_block_transactions(&self, block: BlockNumber) -> Result<Vec<Bytes>> {
+        let block = BlockTag::from(block);
+        let tx_count: Option<U64> = block_on(
+            self.client
+                .request("eth_getBlockTransactionCountByNumber", [block]),
+        )?;
+        let tx_count = tx_count.context("block not found")?.as_u64();
+        let transactions = stream::iter(0..tx_count)
+            .map(|index| {
+                self.client.request::<_, ethers_core::types::Bytes>(
+                    "eth_getRawTransactionByBlockNumberAndIndex",
+                    (block, U64::from(index)),
+                )
+            })
+            .buffered(MAX_CONCURRENT_REQUESTS)
+            .try_collect::<Vec<_>>();
+        Ok(block_on(transactions)?
+            .into_iter()
+            .map(from_ethers_bytes)
+            .collect())
+    }
+
     fn get_latest_block_number(&self) -> Result<BlockNumber> {
         Ok(block_on(self.client.get_block_number())?.as_u64())
     }
@@ -168,6 +203,43 @@ pub fn to_eth_block_header<T>(block: Block<T>) -> Result<EthBlockHeader> {
     })
 }
 
+pub fn to_receipt(receipt: TransactionReceipt) -> Result<Receipt> {
+    let tx_type = receipt
+        .transaction_type
+        .map_or(Ok(LEGACY_TX_TYPE), |tx_type| u8::try_from(tx_type.as_u64()))
+        .context("transaction type")?;
+    // Pre-Byzantium receipts commit to an intermediate state root instead of the status
+    let status = receipt.status.context("status")?.as_u64() == 1;
+    let deposit_field = |key| {
+        receipt
+            .other
+            .get_deserialized::<ethers_core::types::U64>(key)
+            .transpose()
+            .map(|value| value.map(|value| value.as_u64()))
+            .context(key)
+    };
+
+    Ok(Receipt {
+        tx_type,
+        status,
+        cumulative_gas_used: receipt.cumulative_gas_used.as_u64(),
+        logs_bloom: alloy_primitives::Bloom::from_slice(receipt.logs_bloom.as_bytes()),
+        deposit_nonce: deposit_field("depositNonce")?,
+        deposit_receipt_version: deposit_field("depositReceiptVersion")?,
+        logs: receipt
+            .logs
+            .into_iter()
+            .map(|log| Log {
+                address: log.address.0.into(),
+                data: LogData::new_unchecked(
+                    log.topics.into_iter().map(from_ethers_h256).collect(),
+                    from_ethers_bytes(log.data),
+                ),
+            })
+            .collect(),
+    })
+}
+
 pub fn from_ethers_bytes(v: ethers_core::types::Bytes) -> alloy_primitives::Bytes {
     v.0.into()
 }
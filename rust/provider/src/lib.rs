@@ -12,7 +12,7 @@ use std::fmt::Debug;
 
 pub use alloy_primitives::{Address, BlockNumber, Bytes, StorageKey, StorageValue, TxNumber, U256};
 use auto_impl::auto_impl;
-pub use block_header::EvmBlockHeader;
+pub use block_header::{EvmBlockHeader, Receipt};
 pub use cache::CachedProvider;
 pub use ethers::*;
 pub use ethers_core::types::BlockNumber as BlockTag;
@@ -56,5 +56,8 @@ pub trait BlockingProvider: Debug + Send + Sync {
         block: BlockNumber,
     ) -> Result<StorageValue>;
     fn get_transaction_count(&self, address: Address, block: BlockNumber) -> Result<TxNumber>;
+    fn get_block_receipts(&self, block: BlockNumber) -> Result<Vec<Receipt>>;
+    /// Returns the EIP-2718 encodings of the transactions included in the block.
+    fn get_block_transactions(&self, block: BlockNumber) -> Result<Vec<Bytes>>;
     fn get_latest_block_number(&self) -> Result<BlockNumber>;
 }
@@ -1,7 +1,7 @@
 use std::marker::PhantomData;
 
 use alloy_primitives::{Address, BlockNumber, Bytes, StorageKey, StorageValue, TxNumber, U256};
-use block_header::EvmBlockHeader;
+use block_header::{EvmBlockHeader, Receipt};
 use ethers_core::types::BlockNumber as BlockTag;
 
 use super::{BlockingProvider, EIP1186Proof, Result};
@@ -36,6 +36,14 @@ impl BlockingProvider for NeverProvider {
         panic!("Unexpected provider call")
     }
 
+    fn get_block_receipts(&self, _: BlockNumber) -> Result<Vec<Receipt>> {
+        panic!("Unexpected provider call")
+    }
+
+    fn get_block_transactions(&self, _: BlockNumber) -> Result<Vec<Bytes>> {
+        panic!("Unexpected provider call")
+    }
+
     fn get_latest_block_number(&self) -> Result<BlockNumber> {
         panic!("Unexpected provider call")
     }
@@ -1,7 +1,7 @@
 use std::{collections::HashMap, sync::RwLock};
 
 use alloy_primitives::{Address, BlockNumber, Bytes, StorageKey, StorageValue, TxNumber, U256};
-use block_header::EvmBlockHeader;
+use block_header::{EvmBlockHeader, Receipt};
 use ethers_core::types::BlockNumber as BlockTag;
 use serde::{Deserialize, Serialize};
 
@@ -16,6 +16,8 @@ pub struct State {
     pub nonce: HashMap<BlockNumber, HashMap<Address, u64>>,
     pub proof: HashMap<BlockNumber, HashMap<Address, u64>>,
     pub storage: HashMap<BlockNumber, HashMap<Address, HashMap<StorageKey, u64>>>,
+    pub receipts: HashMap<BlockNumber, u64>,
+    pub transactions: HashMap<BlockNumber, u64>,
 }
 
 fn flatten<K>(map: &HashMap<K, u64>) -> impl Iterator<Item = &u64> {
@@ -40,6 +42,8 @@ impl State {
             .chain(flatten2(&self.nonce))
             .chain(flatten2(&self.proof))
             .chain(flatten3(&self.storage))
+            .chain(flatten(&self.receipts))
+            .chain(flatten(&self.transactions))
             .sum::<u64>()
             + self.latest_block
     }
@@ -116,6 +120,16 @@ impl BlockingProvider for Provider {
         self.inner.get_transaction_count(address, block)
     }
 
+    fn get_block_receipts(&self, block: BlockNumber) -> Result<Vec<Receipt>> {
+        inc!(self.state, receipts, block);
+        self.inner.get_block_receipts(block)
+    }
+
+    fn get_block_transactions(&self, block: BlockNumber) -> Result<Vec<Bytes>> {
+        inc!(self.state, transactions, block);
+        self.inner.get_block_transactions(block)
+    }
+
     #[allow(clippy::expect_used)]
     fn get_latest_block_number(&self) -> Result<BlockNumber> {
         self.state.write().expect("poisoned lock").latest_block += 1;
@@ -141,6 +155,8 @@ mod tests {
         provider.get_proof(Default::default(), Default::default(), Default::default())?;
         provider.get_storage_at(Default::default(), Default::default(), Default::default())?;
         provider.get_transaction_count(Default::default(), Default::default())?;
+        provider.get_block_receipts(Default::default())?;
+        provider.get_block_transactions(Default::default())?;
 
         let expected_state = State {
             latest_block: 1,
@@ -150,6 +166,8 @@ mod tests {
             nonce: m! { 0 => m! { Address::ZERO => 1 } },
             proof: m! { 0 => m! { Address::ZERO => 1 } },
             storage: m! { 0 => m! { Address::ZERO => m! { StorageKey::ZERO => 1 } } },
+            receipts: m! { 0 => 1 },
+            transactions: m! { 0 => 1 },
         };
         assert_eq!(provider.state(), expected_state);
 
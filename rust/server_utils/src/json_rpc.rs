@@ -1,9 +1,16 @@
+use std::convert::Infallible;
+
 use axum::{
     body::Bytes,
+    extract::ws::{Message, WebSocket},
     http::{header::CONTENT_TYPE, status::StatusCode},
-    response::IntoResponse,
+    response::{
+        IntoResponse, Response,
+        sse::{Event, KeepAlive, Sse},
+    },
 };
 use derive_new::new;
+use futures::{StreamExt, stream};
 use jsonrpsee::{
     ConnectionId, Extensions, MethodCallback, MethodResponse, RpcModule,
     types::{
@@ -14,6 +21,9 @@ use jsonrpsee::{
 use mime::APPLICATION_JSON;
 use tracing::{error, info};
 
+/// Number of subscription notifications buffered before the subscription waits for the client
+const SUBSCRIPTION_BUFFER_SIZE: usize = 16;
+
 #[derive(new, Clone)]
 pub struct Router<T: Send + Sync + Clone + 'static>(RpcModule<T>);
 
@@ -39,6 +49,93 @@ where
         self.handle(body, extensions).await
     }
 
+    /// Handles a subscription request. The response (subscription id or error) and then
+    /// all the notifications are streamed to the client as server-sent events.
+    pub async fn subscribe_with_params<Params>(mut self, body: Bytes, params: Params) -> Response
+    where
+        Params: Clone + Send + Sync + 'static,
+    {
+        self.0.extensions_mut().insert(params);
+        let request = String::from_utf8_lossy(&body);
+        let (response, notifications) = match self
+            .0
+            .raw_json_request(&request, SUBSCRIPTION_BUFFER_SIZE)
+            .await
+        {
+            Ok(response_and_notifications) => response_and_notifications,
+            Err(err) => {
+                let response = MethodResponse::error(Id::Null, Error::InvalidRequest(err));
+                log_response(&response);
+                return (
+                    StatusCode::OK,
+                    [(CONTENT_TYPE, APPLICATION_JSON.to_string())],
+                    response.to_result(),
+                )
+                    .into_response();
+            }
+        };
+        info!(result = response.as_str(), "JsonRpc subscription request");
+        let notifications = stream::unfold(notifications, |mut notifications| async move {
+            let notification = notifications.recv().await?;
+            Some((notification, notifications))
+        });
+        let events = stream::once(async move { response })
+            .chain(notifications)
+            .map(|message| Ok::<_, Infallible>(Event::default().data(message)));
+        Sse::new(events)
+            .keep_alive(KeepAlive::default())
+            .into_response()
+    }
+
+    /// Handles a subscription over WebSocket. The client sends a single subscription request,
+    /// after which the response and all the notifications are sent as text messages. The socket
+    /// is closed when the subscription ends, and closing it from the client unsubscribes.
+    pub async fn subscribe_over_websocket<Params>(mut self, mut socket: WebSocket, params: Params)
+    where
+        Params: Clone + Send + Sync + 'static,
+    {
+        self.0.extensions_mut().insert(params);
+        let Some(Ok(Message::Text(request))) = socket.recv().await else {
+            return;
+        };
+        let (response, mut notifications) = match self
+            .0
+            .raw_json_request(request.as_str(), SUBSCRIPTION_BUFFER_SIZE)
+            .await
+        {
+            Ok(response_and_notifications) => response_and_notifications,
+            Err(err) => {
+                let response = MethodResponse::error(Id::Null, Error::InvalidRequest(err));
+                log_response(&response);
+                socket.send(Message::text(response.to_result())).await.ok();
+                return;
+            }
+        };
+        info!(result = response.as_str(), "JsonRpc subscription request");
+        if socket.send(Message::text(response)).await.is_err() {
+            return;
+        }
+        loop {
+            tokio::select! {
+                notification = notifications.recv() => {
+                    let Some(notification) = notification else {
+                        break;
+                    };
+                    if socket.send(Message::text(notification)).await.is_err() {
+                        return;
+                    }
+                }
+                // Nothing else is expected from the client, so any other message is ignored
+                message = socket.recv() => {
+                    if matches!(message, None | Some(Err(_) | Ok(Message::Close(_)))) {
+                        return;
+                    }
+                }
+            }
+        }
+        socket.send(Message::Close(None)).await.ok();
+    }
+
     async fn handle(self, body: Bytes, extensions: Extensions) -> impl IntoResponse {
         let response = match serde_json::from_slice::<Request>(&body) {
             Ok(request) => self.handle_inner(request, extensions).await,
@@ -59,7 +156,10 @@ where
         match self.0.method(request.method_name()) {
             Some(method) => match method {
                 MethodCallback::Async(cb) => cb(id, params, conn_id, usize::MAX, extensions).await,
-                _ => todo!("implement other method types in handler"),
+                MethodCallback::Subscription(..) | MethodCallback::Unsubscription(..) => {
+                    MethodResponse::error(id, Error::Subscription(request.method_name().into()))
+                }
+                MethodCallback::Sync(..) => todo!("implement other method types in handler"),
             },
             None => MethodResponse::error(id, Error::MethodNotFound(request.method_name().into())),
         }
@@ -70,6 +170,8 @@ where
 enum Error {
     #[error("Method `{0}` not found")]
     MethodNotFound(String),
+    #[error("Method `{0}` is a subscription and has to be called with a subscription request")]
+    Subscription(String),
     #[error("{0}")]
     InvalidRequest(#[from] serde_json::error::Error),
 }
@@ -77,7 +179,7 @@ enum Error {
 impl From<Error> for ErrorObjectOwned {
     fn from(error: Error) -> Self {
         match error {
-            Error::MethodNotFound(..) => ErrorObjectOwned::owned::<()>(
+            Error::MethodNotFound(..) | Error::Subscription(..) => ErrorObjectOwned::owned::<()>(
                 jrpcerror::METHOD_NOT_FOUND_CODE,
                 error.to_string(),
                 None,
@@ -1,7 +1,12 @@
 #![allow(clippy::disallowed_types)]
-use std::fmt;
+use std::{convert::Infallible, fmt};
 
-use revm::{Database, DatabaseRef, db::WrapDatabaseRef};
+use alloy_primitives::Bytes;
+use block_header::Receipt;
+use revm::{
+    Database, DatabaseRef,
+    db::{CacheDB, EmptyDB, WrapDatabaseRef},
+};
 
 pub trait RevmDBError:
     std::error::Error + fmt::Debug + fmt::Display + Send + Sync + 'static + Into<anyhow::Error>
@@ -41,3 +46,33 @@ where
 {
     type Error = E;
 }
+
+/// Database that can also provide the transactions included in its block and their receipts
+pub trait BlockDatabaseRef: DatabaseRef {
+    /// Get the EIP-2718 encoded transaction at the given index within the block. It is returned
+    /// undecoded, so that finding a transaction by hash doesn't recover the sender of each one.
+    fn raw_transaction_ref(&self, tx_index: u64) -> Result<Option<Bytes>, Self::Error>;
+
+    /// Get receipt of the transaction at the given index within the block.
+    fn receipt_ref(&self, tx_index: u64) -> Result<Option<Receipt>, Self::Error>;
+}
+
+impl<ExtDB: BlockDatabaseRef> BlockDatabaseRef for CacheDB<ExtDB> {
+    fn raw_transaction_ref(&self, tx_index: u64) -> Result<Option<Bytes>, Self::Error> {
+        self.db.raw_transaction_ref(tx_index)
+    }
+
+    fn receipt_ref(&self, tx_index: u64) -> Result<Option<Receipt>, Self::Error> {
+        self.db.receipt_ref(tx_index)
+    }
+}
+
+impl BlockDatabaseRef for EmptyDB {
+    fn raw_transaction_ref(&self, _tx_index: u64) -> Result<Option<Bytes>, Infallible> {
+        Ok(None)
+    }
+
+    fn receipt_ref(&self, _tx_index: u64) -> Result<Option<Receipt>, Infallible> {
+        Ok(None)
+    }
+}
@@ -1,7 +1,8 @@
 use std::sync::{Arc, RwLock};
 
 use alloy_primitives::{Address, B256, BlockNumber, Bytes, U256};
-use block_header::EvmBlockHeader;
+use block_header::{EvmBlockHeader, Receipt};
+use call_common::BlockDatabaseRef;
 use mpt::{KeccakMerkleTrie as MerkleTrie, ParseNodeError};
 use provider::{BlockingProvider, EIP1186Proof};
 use revm::{
@@ -17,6 +18,8 @@ struct State {
     accounts: HashMap<Address, HashSet<U256>>,
     contracts: HashMap<B256, Bytes>,
     block_hash_numbers: HashSet<u64>,
+    transactions_accessed: bool,
+    receipts_accessed: bool,
 }
 
 #[derive(Debug)]
@@ -58,6 +61,23 @@ impl DatabaseRef for ProofDb {
     }
 }
 
+#[allow(clippy::expect_used)]
+impl BlockDatabaseRef for ProofDb {
+    fn raw_transaction_ref(&self, tx_index: u64) -> Result<Option<Bytes>, Self::Error> {
+        let transaction = self.db.raw_transaction_ref(tx_index)?;
+        let mut state = self.state.write().expect("poisoned lock");
+        state.transactions_accessed = true;
+        Ok(transaction)
+    }
+
+    fn receipt_ref(&self, tx_index: u64) -> Result<Option<Receipt>, Self::Error> {
+        let receipt = self.db.receipt_ref(tx_index)?;
+        let mut state = self.state.write().expect("poisoned lock");
+        state.receipts_accessed = true;
+        Ok(receipt)
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum Error {
     #[error("Invalid state proof: {0}")]
@@ -121,6 +141,42 @@ impl ProofDb {
         Ok(proofs)
     }
 
+    /// Returns the trie of all the block transactions, if any of them were accessed.
+    pub fn transactions_trie(&self) -> Result<Option<MerkleTrie>, Error> {
+        let state = self.state.read().expect("poisoned lock");
+        if !state.transactions_accessed {
+            return Ok(None);
+        }
+        let transactions = self.db.block_transactions()?;
+        Ok(Some(Self::transactions_trie_from(&transactions)))
+    }
+
+    fn transactions_trie_from(transactions: &[Bytes]) -> MerkleTrie {
+        transactions
+            .iter()
+            .enumerate()
+            .map(|(tx_index, tx)| (alloy_rlp::encode(tx_index), tx.to_vec()))
+            .collect()
+    }
+
+    /// Returns the trie of all the block receipts, if any of them were accessed.
+    pub fn receipts_trie(&self) -> Result<Option<MerkleTrie>, Error> {
+        let state = self.state.read().expect("poisoned lock");
+        if !state.receipts_accessed {
+            return Ok(None);
+        }
+        let receipts = self.db.block_receipts()?;
+        Ok(Some(Self::receipts_trie_from(&receipts)))
+    }
+
+    fn receipts_trie_from(receipts: &[Receipt]) -> MerkleTrie {
+        receipts
+            .iter()
+            .enumerate()
+            .map(|(tx_index, receipt)| (alloy_rlp::encode(tx_index), receipt.encoded_2718()))
+            .collect()
+    }
+
     pub fn state_trie(proofs: &[EIP1186Proof]) -> Result<MerkleTrie, Error> {
         let state_nodes = proofs.iter().flat_map(|p| p.account_proof.iter());
         let state_trie =
@@ -5,7 +5,9 @@ use std::{
     sync::{Arc, RwLock},
 };
 
-use alloy_primitives::{Address, B256, U256};
+use alloy_primitives::{Address, B256, Bytes, U256};
+use block_header::Receipt;
+use call_common::BlockDatabaseRef;
 use provider::BlockingProvider;
 #[allow(clippy::disallowed_types)]
 use revm::{
@@ -30,6 +32,8 @@ pub struct ProviderDb {
     pub provider: Arc<dyn BlockingProvider>,
     pub block_number: u64,
     code_hashes: RwLock<HashMap<B256, Address>>,
+    transactions: RwLock<Option<Arc<[Bytes]>>>,
+    receipts: RwLock<Option<Arc<[Receipt]>>>,
 }
 
 impl ProviderDb {
@@ -39,8 +43,39 @@ impl ProviderDb {
             provider,
             block_number,
             code_hashes: RwLock::new(HashMap::new()),
+            transactions: RwLock::new(None),
+            receipts: RwLock::new(None),
         }
     }
+
+    /// Returns the EIP-2718 encoded transactions of the block. They are fetched on first use and
+    /// reused afterwards, as scanning the block by hash reads each of them.
+    #[allow(clippy::expect_used)]
+    pub fn block_transactions(&self) -> Result<Arc<[Bytes]>> {
+        let mut transactions = self.transactions.write().expect("poisoned lock");
+        if let Some(transactions) = transactions.as_ref() {
+            return Ok(Arc::clone(transactions));
+        }
+        let fetched: Arc<[Bytes]> = self
+            .provider
+            .get_block_transactions(self.block_number)?
+            .into();
+        *transactions = Some(Arc::clone(&fetched));
+        Ok(fetched)
+    }
+
+    /// Returns the receipts of the block. Like [Self::block_transactions], they are fetched on
+    /// first use, as each of them is fetched together with all the others.
+    #[allow(clippy::expect_used)]
+    pub fn block_receipts(&self) -> Result<Arc<[Receipt]>> {
+        let mut receipts = self.receipts.write().expect("poisoned lock");
+        if let Some(receipts) = receipts.as_ref() {
+            return Ok(Arc::clone(receipts));
+        }
+        let fetched: Arc<[Receipt]> = self.provider.get_block_receipts(self.block_number)?.into();
+        *receipts = Some(Arc::clone(&fetched));
+        Ok(fetched)
+    }
 }
 
 impl DatabaseRef for ProviderDb {
@@ -103,4 +138,22 @@ impl DatabaseRef for ProviderDb {
     }
 }
 
+impl BlockDatabaseRef for ProviderDb {
+    fn raw_transaction_ref(&self, tx_index: u64) -> Result<Option<Bytes>> {
+        let transactions = self.block_transactions()?;
+        Ok(usize::try_from(tx_index)
+            .ok()
+            .and_then(|tx_index| transactions.get(tx_index))
+            .cloned())
+    }
+
+    fn receipt_ref(&self, tx_index: u64) -> Result<Option<Receipt>> {
+        let receipts = self.block_receipts()?;
+        Ok(usize::try_from(tx_index)
+            .ok()
+            .and_then(|tx_index| receipts.get(tx_index))
+            .cloned())
+    }
+}
+
 pub use self::Error as ProviderDbError;
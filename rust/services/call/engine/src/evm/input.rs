@@ -20,6 +20,8 @@ pub struct EvmInput {
     pub storage_tries: Vec<MerkleTrie>,
     pub contracts: Vec<Bytes>,
     pub ancestors: Vec<Box<dyn EvmBlockHeader>>,
+    pub transactions_trie: MerkleTrie,
+    pub receipts_trie: MerkleTrie,
 }
 
 impl EvmInput {
@@ -31,6 +33,8 @@ impl EvmInput {
         debug!("total storage size: {}", total_storage_size);
         debug!("contracts: {}", self.contracts.len());
         debug!("blocks: {}", self.ancestors.len());
+        debug!("transactions size: {}", self.transactions_trie.size());
+        debug!("receipts size: {}", self.receipts_trie.size());
     }
 }
 
@@ -45,6 +49,8 @@ impl EvmInput {
 
     pub fn assert_coherency(&self) {
         self.assert_state_root_coherency();
+        self.assert_transactions_root_coherency();
+        self.assert_receipts_root_coherency();
         self.assert_ancestors_coherency();
     }
 
@@ -53,6 +59,20 @@ impl EvmInput {
         assert_eq!(self.header.state_root(), &state_root, "State root mismatch");
     }
 
+    fn assert_transactions_root_coherency(&self) {
+        let transactions_root = self.transactions_trie.hash_slow();
+        assert_eq!(
+            self.header.transactions_root(),
+            &transactions_root,
+            "Transactions root mismatch"
+        );
+    }
+
+    fn assert_receipts_root_coherency(&self) {
+        let receipts_root = self.receipts_trie.hash_slow();
+        assert_eq!(self.header.receipts_root(), &receipts_root, "Receipts root mismatch");
+    }
+
     fn assert_ancestors_coherency(&self) {
         let mut previous_header = &self.header;
         for ancestor in &self.ancestors {
@@ -160,6 +180,68 @@ mod test {
         }
     }
 
+    mod assert_transactions_root_coherency {
+        use alloy_primitives::B256;
+
+        use super::*;
+
+        #[test]
+        fn success() {
+            let input = EvmInput {
+                header: Box::new(EthBlockHeader {
+                    transactions_root: EMPTY_ROOT_HASH,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            };
+            input.assert_transactions_root_coherency();
+        }
+
+        #[test]
+        #[should_panic(expected = "Transactions root mismatch")]
+        fn mismatch() {
+            let input = EvmInput {
+                header: Box::new(EthBlockHeader {
+                    transactions_root: B256::ZERO,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            };
+            input.assert_transactions_root_coherency();
+        }
+    }
+
+    mod assert_receipts_root_coherency {
+        use alloy_primitives::B256;
+
+        use super::*;
+
+        #[test]
+        fn success() {
+            let input = EvmInput {
+                header: Box::new(EthBlockHeader {
+                    receipts_root: EMPTY_ROOT_HASH,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            };
+            input.assert_receipts_root_coherency();
+        }
+
+        #[test]
+        #[should_panic(expected = "Receipts root mismatch")]
+        fn mismatch() {
+            let input = EvmInput {
+                header: Box::new(EthBlockHeader {
+                    receipts_root: B256::ZERO,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            };
+            input.assert_receipts_root_coherency();
+        }
+    }
+
     mod assert_ancestors_coherency {
         use common::Hashable;
 
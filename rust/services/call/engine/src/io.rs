@@ -26,6 +26,9 @@ pub struct Input {
     pub call: Call,
     pub op_output_cache: OpOutputCache,
     pub is_vlayer_test: bool,
+    /// PEM-encoded public keys of the notaries trusted to sign web proofs. `None` accepts any
+    /// notary. Committed in [`CallAssumptions`] as a hash.
+    pub trusted_notary_keys: Option<Vec<String>>,
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone, RlpEncodable)]
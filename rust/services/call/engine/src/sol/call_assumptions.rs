@@ -3,8 +3,8 @@ mod private {
     alloy_sol_types::sol!(#![sol(all_derives)] "../../../../contracts/vlayer/src/CallAssumptions.sol");
 }
 
-use alloy_primitives::{Address, ChainId, U256};
-use alloy_sol_types::SolType;
+use alloy_primitives::{Address, B256, ChainId, U256, keccak256};
+use alloy_sol_types::{SolType, SolValue};
 use block_header::EvmBlockHeader;
 /// Solidity struct representing the committed block used for validation.
 pub use private::CallAssumptions;
@@ -18,6 +18,7 @@ impl CallAssumptions {
         header: &dyn EvmBlockHeader,
         to: Address,
         selector: CallSelector,
+        trusted_notary_keys: Option<&[String]>,
     ) -> Self {
         Self {
             proverContractAddress: to,
@@ -25,6 +26,7 @@ impl CallAssumptions {
             settleChainId: U256::from(chain_id),
             settleBlockHash: header.hash_slow(),
             settleBlockNumber: U256::from(header.number()),
+            trustedNotaryKeysHash: trusted_notary_keys_hash(trusted_notary_keys),
         }
     }
 
@@ -33,3 +35,9 @@ impl CallAssumptions {
         Self::ENCODED_SIZE.expect("CallAssumptions compiletime size does not exist")
     }
 }
+
+/// Mirrors `CallAssumptionsLib.trustedNotaryKeysHash`. Zero when web proofs are not restricted to
+/// any notaries.
+fn trusted_notary_keys_hash(trusted_notary_keys: Option<&[String]>) -> B256 {
+    trusted_notary_keys.map_or(B256::ZERO, |keys| keccak256(keys.abi_encode()))
+}
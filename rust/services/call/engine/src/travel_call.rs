@@ -1,6 +1,6 @@
 use std::panic;
 
-use call_common::{ExecutionLocation, RevmDB, WrappedRevmDBError};
+use call_common::{BlockDatabaseRef, ExecutionLocation, RevmDB, WrappedRevmDBError};
 use derive_new::new;
 use evm::build_evm;
 use inspector::TxResultWithMetadata;
@@ -17,6 +17,8 @@ mod args;
 mod error;
 mod evm;
 mod inspector;
+mod receipts;
+mod transactions;
 
 pub use args::Args;
 pub use error::Error;
@@ -27,9 +29,18 @@ pub struct Executor<'envs, D: RevmDB> {
     envs: &'envs CachedEvmEnv<D>,
     start: ExecutionLocation,
     is_vlayer_test: bool,
+    #[new(default)]
+    trusted_notary_keys: Option<Vec<String>>,
 }
 
-impl<'envs, D: RevmDB> Executor<'envs, D> {
+impl<'envs, D: RevmDB + BlockDatabaseRef> Executor<'envs, D> {
+    /// Rejects web proofs from notaries other than the given ones. See [`crate::io::Input`].
+    #[must_use]
+    pub fn with_trusted_notary_keys(mut self, trusted_notary_keys: Option<Vec<String>>) -> Self {
+        self.trusted_notary_keys = trusted_notary_keys;
+        self
+    }
+
     pub fn call(
         self,
         tx: &Call,
@@ -56,7 +67,13 @@ impl<'envs, D: RevmDB> Executor<'envs, D> {
             self.is_vlayer_test,
             self.is_on_historic_block(location),
         );
-        let mut evm = build_evm(&env, tx, inspector, self.is_vlayer_test);
+        let mut evm = build_evm(
+            &env,
+            tx,
+            inspector,
+            self.is_vlayer_test,
+            self.trusted_notary_keys.as_deref(),
+        );
         // Can panic because EVM is unable to propagate errors on intercepted calls
         let ResultAndState { result, .. } = evm.transact_preverified()?;
         debug!("EVM call result: {result:?}");
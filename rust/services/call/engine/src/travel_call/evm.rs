@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use call_common::RevmDB;
+use call_common::{BlockDatabaseRef, RevmDB};
 use call_precompiles::precompiles as generate_precompiles;
 use revm::{
     Evm, Handler, db::WrapDatabaseRef, inspector_handle_register, precompile::PrecompileWithAddress,
@@ -9,16 +9,17 @@ use revm::{
 use super::inspector::Inspector;
 use crate::{Call, evm::env::EvmEnv};
 
-pub fn build_evm<'inspector, 'envs, D: RevmDB>(
+pub fn build_evm<'inspector, 'envs, D: RevmDB + BlockDatabaseRef>(
     env: &'envs EvmEnv<D>,
     tx: &Call,
     inspector: Inspector<'inspector, D>,
     is_vlayer_test: bool,
+    trusted_notary_keys: Option<&[String]>,
 ) -> Evm<'inspector, Inspector<'inspector, D>, WrapDatabaseRef<&'envs D>> {
     let precompiles_handle_register = move |handler: &mut Handler<_, _, _>| {
         let mut precompiles = handler.pre_execution.load_precompiles();
         precompiles.extend(
-            generate_precompiles(is_vlayer_test)
+            generate_precompiles(is_vlayer_test, trusted_notary_keys)
                 .into_iter()
                 .map(PrecompileWithAddress::from),
         );
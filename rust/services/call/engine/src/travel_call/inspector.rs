@@ -1,7 +1,8 @@
 use alloy_dyn_abi::{DynSolType, DynSolValue};
-use alloy_primitives::{Address, ChainId, address};
+use alloy_primitives::{Address, B256, Bytes, ChainId, address, keccak256};
+use block_header::Transaction;
 use call_common::{
-    ExecutionLocation, RevmDB, WrappedRevmDBError,
+    BlockDatabaseRef, ExecutionLocation, RevmDB, WrappedRevmDBError,
     metadata::{Metadata, PrecompileResult},
 };
 use call_precompiles::{is_time_dependent, precompile_by_address};
@@ -15,22 +16,50 @@ use tracing::{debug, info, warn};
 
 use crate::{
     io::Call,
-    travel_call::{args::Args, error::Error},
-    utils::evm_call::{create_encoded_return_outcome, execution_result_to_call_outcome},
+    travel_call::{
+        args::Args,
+        error::Error,
+        receipts::{RECEIPTS_ADDR, SolReceipt, parse_get_receipt},
+        transactions::{
+            SolTransaction, TRANSACTIONS_ADDR, TransactionQuery, parse_transactions_call,
+        },
+    },
+    utils::evm_call::{
+        create_encoded_return_outcome, create_revert_outcome, execution_result_to_call_outcome,
+    },
 };
 
 /// This is calculated as:
 /// `address(bytes20(uint160(uint256(keccak256('vlayer.traveler')))))`
 pub const CONTRACT_ADDR: Address = address!("76dC9aa45aa006A0F63942d8F9f21Bd4537972A3");
 
-fn parse_web_proof_url(abi_data: &[u8]) -> Option<String> {
-    let array_type = DynSolType::FixedArray(Box::new(DynSolType::String), 4);
-    let decoded = array_type.abi_decode(abi_data).ok()?;
+// Layout of a single exchange returned by the web proof precompile, see `web_proof::web::Web`
+fn web_proof_exchange_type() -> DynSolType {
+    DynSolType::Tuple(vec![
+        DynSolType::String,
+        DynSolType::String,
+        DynSolType::String,
+        DynSolType::String,
+        DynSolType::Uint(16),
+        DynSolType::String,
+        DynSolType::Array(Box::new(DynSolType::Tuple(vec![
+            DynSolType::String,
+            DynSolType::String,
+        ]))),
+        DynSolType::Uint(64),
+    ])
+}
 
-    if let DynSolValue::FixedArray(values) = decoded {
-        if let Some(DynSolValue::String(url)) = values.first() {
-            if url.starts_with("http") && !url.is_empty() {
-                return Some(url.clone());
+fn parse_web_proof_url(abi_data: &[u8]) -> Option<String> {
+    let exchanges_type = DynSolType::Array(Box::new(web_proof_exchange_type()));
+    let decoded = exchanges_type.abi_decode(abi_data).ok()?;
+
+    if let DynSolValue::Array(exchanges) = decoded {
+        if let Some(DynSolValue::Tuple(fields)) = exchanges.first() {
+            if let Some(DynSolValue::String(url)) = fields.first() {
+                if url.starts_with("http") && !url.is_empty() {
+                    return Some(url.clone());
+                }
             }
         }
     }
@@ -38,6 +67,69 @@ fn parse_web_proof_url(abi_data: &[u8]) -> Option<String> {
     None
 }
 
+// Scans the block transactions in order, as the block has no index by hash. Only the matching
+// transaction gets decoded, so the senders of the others are never recovered.
+fn find_transaction_by_hash<D: BlockDatabaseRef>(
+    db: &D,
+    tx_hash: B256,
+) -> Result<Option<Bytes>, D::Error> {
+    for tx_index in 0.. {
+        match db.raw_transaction_ref(tx_index)? {
+            Some(encoded) if keccak256(&encoded) == tx_hash => return Ok(Some(encoded)),
+            Some(_) => {}
+            None => break,
+        }
+    }
+    Ok(None)
+}
+
+fn on_transactions_call<D: RevmDB + BlockDatabaseRef>(db: &D, inputs: &CallInputs) -> CallOutcome {
+    info!("Call to transactions contract");
+    let (transaction, not_found) = match parse_transactions_call(inputs) {
+        TransactionQuery::Index(Some(tx_index)) => {
+            (db.raw_transaction_ref(tx_index), format!("No transaction at index {tx_index}"))
+        }
+        TransactionQuery::Index(None) => {
+            return create_revert_outcome("Transaction index overflows u64", inputs.gas_limit);
+        }
+        TransactionQuery::Hash(tx_hash) => (
+            find_transaction_by_hash(db, tx_hash),
+            format!("No transaction with hash {tx_hash}"),
+        ),
+    };
+    match transaction.map(|encoded| encoded.as_deref().map(Transaction::decode_2718)) {
+        Ok(Some(Ok(transaction))) => {
+            create_encoded_return_outcome(&SolTransaction::from(transaction), inputs)
+        }
+        Ok(Some(Err(err))) => {
+            warn!("Invalid transaction: {err}");
+            create_revert_outcome(&format!("Invalid transaction: {err}"), inputs.gas_limit)
+        }
+        Ok(None) => create_revert_outcome(&not_found, inputs.gas_limit),
+        Err(err) => {
+            warn!("Failed to get transaction: {err}");
+            create_revert_outcome(&format!("Failed to get transaction: {err}"), inputs.gas_limit)
+        }
+    }
+}
+
+fn on_receipts_call<D: RevmDB + BlockDatabaseRef>(db: &D, inputs: &CallInputs) -> CallOutcome {
+    info!("Call to receipts contract");
+    let Some(tx_index) = parse_get_receipt(inputs) else {
+        return create_revert_outcome("Transaction index overflows u64", inputs.gas_limit);
+    };
+    match db.receipt_ref(tx_index) {
+        Ok(Some(receipt)) => create_encoded_return_outcome(&SolReceipt::from(receipt), inputs),
+        Ok(None) => {
+            create_revert_outcome(&format!("No transaction at index {tx_index}"), inputs.gas_limit)
+        }
+        Err(err) => {
+            warn!("Failed to get receipt at index {tx_index}: {err}");
+            create_revert_outcome(&format!("Failed to get receipt: {err}"), inputs.gas_limit)
+        }
+    }
+}
+
 pub type TxResultWithMetadata = (ExecutionResult, Box<[Metadata]>);
 type TransactionCallback<'a, D> =
     dyn Fn(&Call, ExecutionLocation) -> Result<TxResultWithMetadata, Error<D>> + 'a;
@@ -138,12 +230,12 @@ impl<'a, D: RevmDB> Inspector<'a, D> {
 
 impl<D> IInspector<WrapDatabaseRef<&D>> for Inspector<'_, D>
 where
-    D: RevmDB,
+    D: RevmDB + BlockDatabaseRef,
 {
     #[allow(clippy::panic)]
     fn call(
         &mut self,
-        _context: &mut EvmContext<WrapDatabaseRef<&D>>,
+        context: &mut EvmContext<WrapDatabaseRef<&D>>,
         inputs: &mut CallInputs,
     ) -> Option<CallOutcome> {
         info!(caller = ?inputs.caller, callee = ?inputs.bytecode_address, "Call");
@@ -163,6 +255,13 @@ where
 
         match inputs.bytecode_address {
             CONTRACT_ADDR => self.on_travel_call(inputs),
+            // After setBlock/setChain the call is forwarded to the EVM at the new location
+            TRANSACTIONS_ADDR if self.location.is_none() => {
+                Some(on_transactions_call(context.db.0, inputs))
+            }
+            RECEIPTS_ADDR if self.location.is_none() => {
+                Some(on_receipts_call(context.db.0, inputs))
+            }
             _ => self.on_call(inputs),
         }
     }
@@ -258,7 +357,7 @@ mod test {
 
     use std::convert::Infallible;
 
-    use alloy_primitives::{Address, BlockNumber, Bytes, U256, address};
+    use alloy_primitives::{Address, BlockNumber, Bytes, U256, address, hex};
     use call_precompiles::{precompile::Tag, precompile_by_tag};
     use lazy_static::lazy_static;
     use revm::{
@@ -275,6 +374,9 @@ mod test {
     const MAINNET_ID: ChainId = 1;
     const SEPOLIA_ID: ChainId = 11_155_111;
     const MAINNET_BLOCK: BlockNumber = 20_000_000;
+    const GET_RECEIPT_SELECTOR: [u8; 4] = hex!("b63e6ac3");
+    const GET_TRANSACTION_SELECTOR: [u8; 4] = hex!("33ea3dc8");
+    const GET_TRANSACTION_BY_HASH_SELECTOR: [u8; 4] = hex!("ee3d7ad3");
     const SEPOLIA_BLOCK: BlockNumber = 6_000_000;
 
     lazy_static! {
@@ -432,6 +534,90 @@ mod test {
         inspector.on_call(&call_inputs);
     }
 
+    #[test]
+    fn call_receipts_reverts_for_missing_transaction() {
+        let mock_db = InMemoryDB::default();
+        let mut evm_context = EvmContext::new(WrapDatabaseRef::from(&mock_db));
+        let tx_index = U256::from(0).to_be_bytes::<32>();
+        let input = [GET_RECEIPT_SELECTOR.as_slice(), &tx_index].concat();
+        let mut call_inputs = create_mock_call_inputs(RECEIPTS_ADDR, input);
+
+        let mut inspector = Inspector::new(MAINNET_ID, TRANSACTION_CALLBACK, true, false);
+        let outcome = inspector.call(&mut evm_context, &mut call_inputs).unwrap();
+
+        assert_eq!(outcome.result.result, InstructionResult::Revert);
+    }
+
+    #[test]
+    fn call_transactions_reverts_for_missing_transaction() {
+        let mock_db = InMemoryDB::default();
+        let mut evm_context = EvmContext::new(WrapDatabaseRef::from(&mock_db));
+        let tx_index = U256::from(0).to_be_bytes::<32>();
+        let input = [GET_TRANSACTION_SELECTOR.as_slice(), &tx_index].concat();
+        let mut call_inputs = create_mock_call_inputs(TRANSACTIONS_ADDR, input);
+
+        let mut inspector = Inspector::new(MAINNET_ID, TRANSACTION_CALLBACK, true, false);
+        let outcome = inspector.call(&mut evm_context, &mut call_inputs).unwrap();
+
+        assert_eq!(outcome.result.result, InstructionResult::Revert);
+    }
+
+    #[test]
+    fn call_transactions_reverts_for_out_of_range_index() {
+        let mock_db = InMemoryDB::default();
+        let mut evm_context = EvmContext::new(WrapDatabaseRef::from(&mock_db));
+        let tx_index = U256::MAX.to_be_bytes::<32>();
+        let input = [GET_TRANSACTION_SELECTOR.as_slice(), &tx_index].concat();
+        let mut call_inputs = create_mock_call_inputs(TRANSACTIONS_ADDR, input);
+
+        let mut inspector = Inspector::new(MAINNET_ID, TRANSACTION_CALLBACK, true, false);
+        let outcome = inspector.call(&mut evm_context, &mut call_inputs).unwrap();
+
+        assert_eq!(outcome.result.result, InstructionResult::Revert);
+    }
+
+    #[test]
+    fn call_transactions_by_hash_reverts_for_missing_transaction() {
+        let mock_db = InMemoryDB::default();
+        let mut evm_context = EvmContext::new(WrapDatabaseRef::from(&mock_db));
+        let input =
+            [GET_TRANSACTION_BY_HASH_SELECTOR.as_slice(), B256::repeat_byte(1).as_slice()].concat();
+        let mut call_inputs = create_mock_call_inputs(TRANSACTIONS_ADDR, input);
+
+        let mut inspector = Inspector::new(MAINNET_ID, TRANSACTION_CALLBACK, true, false);
+        let outcome = inspector.call(&mut evm_context, &mut call_inputs).unwrap();
+
+        assert_eq!(outcome.result.result, InstructionResult::Revert);
+    }
+
+    #[test]
+    fn parses_url_of_first_web_proof_exchange() {
+        let exchange = |url: &str| {
+            DynSolValue::Tuple(vec![
+                url.into(),
+                "api.x.com".into(),
+                "{}".into(),
+                "key".into(),
+                200_u16.into(),
+                "GET".into(),
+                DynSolValue::Array(vec![]),
+                1_751_371_200_u64.into(),
+            ])
+        };
+        let output = DynSolValue::Array(vec![
+            exchange("https://api.x.com/login"),
+            exchange("https://api.x.com/account"),
+        ])
+        .abi_encode();
+
+        assert_eq!(parse_web_proof_url(&output).unwrap(), "https://api.x.com/login");
+    }
+
+    #[test]
+    fn does_not_parse_url_from_unexpected_output() {
+        assert!(parse_web_proof_url(&DynSolValue::String("url".into()).abi_encode()).is_none());
+    }
+
     #[test]
     #[should_panic(expected = "Precompile `WebProof` is not allowed for travel calls")]
     fn panics_for_precompile_not_allowed_in_travel_call() {
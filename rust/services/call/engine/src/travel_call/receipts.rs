@@ -0,0 +1,116 @@
+// Keep everything in the vlayer library private except the receipt types.
+mod private {
+    alloy_sol_types::sol!("../../../../contracts/vlayer/src/Receipts.sol");
+}
+
+use alloy_primitives::{Address, address};
+use alloy_sol_types::SolCall;
+use revm::interpreter::CallInputs;
+
+use self::private::IReceipts::getReceiptCall;
+pub use self::private::{Log as SolLog, Receipt as SolReceipt};
+
+/// This is calculated as:
+/// `address(bytes20(uint160(uint256(keccak256('vlayer.receipts')))))`
+pub const RECEIPTS_ADDR: Address = address!("f19DFBEd081a379f8A71536215B9ec789ce8b02a");
+
+/// Returns the index of the transaction whose receipt is requested,
+/// or `None` if it does not fit in `u64`.
+/// Panics if the call is not a valid `getReceipt` call.
+pub fn parse_get_receipt(inputs: &CallInputs) -> Option<u64> {
+    let getReceiptCall { txIndex } =
+        getReceiptCall::abi_decode(&inputs.input, true).expect("Invalid args for getReceipt");
+    txIndex.try_into().ok()
+}
+
+impl From<block_header::Receipt> for SolReceipt {
+    fn from(receipt: block_header::Receipt) -> Self {
+        Self {
+            txType: receipt.tx_type,
+            status: receipt.status,
+            cumulativeGasUsed: receipt.cumulative_gas_used,
+            logs: receipt
+                .logs
+                .into_iter()
+                .map(|log| SolLog {
+                    emitter: log.address,
+                    topics: log.data.topics().to_vec(),
+                    data: log.data.data,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{B256, Bytes, Log, LogData, U256};
+    use revm::interpreter::{CallScheme, CallValue};
+
+    use super::*;
+
+    fn call_inputs(input: Vec<u8>) -> CallInputs {
+        CallInputs {
+            input: input.into(),
+            gas_limit: 0,
+            bytecode_address: RECEIPTS_ADDR,
+            target_address: RECEIPTS_ADDR,
+            caller: Address::ZERO,
+            value: CallValue::Transfer(U256::ZERO),
+            scheme: CallScheme::StaticCall,
+            is_eof: false,
+            is_static: true,
+            return_memory_offset: 0..0,
+        }
+    }
+
+    #[test]
+    fn parses_tx_index() {
+        let input = getReceiptCall {
+            txIndex: U256::from(7),
+        }
+        .abi_encode();
+
+        assert_eq!(parse_get_receipt(&call_inputs(input)), Some(7));
+    }
+
+    #[test]
+    fn rejects_tx_index_overflowing_u64() {
+        let input = getReceiptCall {
+            txIndex: U256::from(u64::MAX) + U256::from(1),
+        }
+        .abi_encode();
+
+        assert_eq!(parse_get_receipt(&call_inputs(input)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid args for getReceipt")]
+    fn panics_on_invalid_selector() {
+        parse_get_receipt(&call_inputs(vec![0; 36]));
+    }
+
+    #[test]
+    fn converts_logs() {
+        let topic = B256::repeat_byte(1);
+        let receipt = block_header::Receipt {
+            tx_type: 2,
+            status: true,
+            cumulative_gas_used: 21_000,
+            logs: vec![Log {
+                address: Address::repeat_byte(2),
+                data: LogData::new_unchecked(vec![topic], Bytes::from_static(&[3])),
+            }],
+            ..Default::default()
+        };
+
+        let sol_receipt = SolReceipt::from(receipt);
+
+        assert_eq!(sol_receipt.txType, 2);
+        assert!(sol_receipt.status);
+        assert_eq!(sol_receipt.cumulativeGasUsed, 21_000);
+        assert_eq!(sol_receipt.logs[0].emitter, Address::repeat_byte(2));
+        assert_eq!(sol_receipt.logs[0].topics, vec![topic]);
+        assert_eq!(sol_receipt.logs[0].data, Bytes::from_static(&[3]));
+    }
+}
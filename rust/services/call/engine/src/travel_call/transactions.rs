@@ -0,0 +1,134 @@
+// Keep everything in the vlayer library private except the transaction type.
+mod private {
+    alloy_sol_types::sol!("../../../../contracts/vlayer/src/Transactions.sol");
+}
+
+use alloy_primitives::{Address, B256, address};
+use alloy_sol_types::SolInterface;
+use revm::interpreter::CallInputs;
+
+use self::private::ITransactions::{
+    ITransactionsCalls, getTransactionByHashCall, getTransactionCall,
+};
+pub use self::private::Transaction as SolTransaction;
+
+/// This is calculated as:
+/// `address(bytes20(uint160(uint256(keccak256('vlayer.transactions')))))`
+pub const TRANSACTIONS_ADDR: Address = address!("32942406F6143bb15844Bb3206780a9A7a13C912");
+
+/// Transaction requested from the transactions contract.
+#[derive(Debug, PartialEq, Eq)]
+pub enum TransactionQuery {
+    /// Transaction at the given index within the block, `None` if the index does not fit in `u64`.
+    Index(Option<u64>),
+    /// Transaction with the given hash.
+    Hash(B256),
+}
+
+/// Returns the query of a call to the transactions contract.
+/// Panics if the call is not a valid `getTransaction` or `getTransactionByHash` call.
+pub fn parse_transactions_call(inputs: &CallInputs) -> TransactionQuery {
+    match ITransactionsCalls::abi_decode(&inputs.input, true)
+        .expect("Invalid args for transactions contract")
+    {
+        ITransactionsCalls::getTransaction(getTransactionCall { txIndex }) => {
+            TransactionQuery::Index(txIndex.try_into().ok())
+        }
+        ITransactionsCalls::getTransactionByHash(getTransactionByHashCall { txHash }) => {
+            TransactionQuery::Hash(txHash)
+        }
+    }
+}
+
+impl From<block_header::Transaction> for SolTransaction {
+    fn from(tx: block_header::Transaction) -> Self {
+        Self {
+            txType: tx.tx_type,
+            chainId: tx.chain_id.unwrap_or_default(),
+            nonce: tx.nonce,
+            gasLimit: tx.gas_limit,
+            from: tx.from,
+            to: tx.to.unwrap_or_default(),
+            value: tx.value,
+            data: tx.input,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{Bytes, U256};
+    use alloy_sol_types::SolCall;
+    use revm::interpreter::{CallScheme, CallValue};
+
+    use super::*;
+
+    fn call_inputs(input: Vec<u8>) -> CallInputs {
+        CallInputs {
+            input: input.into(),
+            gas_limit: 0,
+            bytecode_address: TRANSACTIONS_ADDR,
+            target_address: TRANSACTIONS_ADDR,
+            caller: Address::ZERO,
+            value: CallValue::Transfer(U256::ZERO),
+            scheme: CallScheme::StaticCall,
+            is_eof: false,
+            is_static: true,
+            return_memory_offset: 0..0,
+        }
+    }
+
+    #[test]
+    fn parses_tx_index() {
+        let input = getTransactionCall {
+            txIndex: U256::from(3),
+        }
+        .abi_encode();
+
+        assert_eq!(parse_transactions_call(&call_inputs(input)), TransactionQuery::Index(Some(3)));
+    }
+
+    #[test]
+    fn rejects_tx_index_overflowing_u64() {
+        let input = getTransactionCall {
+            txIndex: U256::from(u64::MAX) + U256::from(1),
+        }
+        .abi_encode();
+
+        assert_eq!(parse_transactions_call(&call_inputs(input)), TransactionQuery::Index(None));
+    }
+
+    #[test]
+    fn parses_tx_hash() {
+        let tx_hash = B256::repeat_byte(1);
+        let input = getTransactionByHashCall { txHash: tx_hash }.abi_encode();
+
+        assert_eq!(parse_transactions_call(&call_inputs(input)), TransactionQuery::Hash(tx_hash));
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid args for transactions contract")]
+    fn panics_on_invalid_selector() {
+        parse_transactions_call(&call_inputs(vec![0; 36]));
+    }
+
+    #[test]
+    fn converts_contract_creation() {
+        let tx = block_header::Transaction {
+            tx_type: 2,
+            chain_id: Some(1),
+            from: Address::repeat_byte(1),
+            to: None,
+            input: Bytes::from_static(&[0x60, 0x00]),
+            ..Default::default()
+        };
+
+        let sol_tx = SolTransaction::from(tx);
+
+        assert_eq!(sol_tx.txType, 2);
+        assert_eq!(sol_tx.chainId, 1);
+        assert_eq!(sol_tx.from, Address::repeat_byte(1));
+        assert_eq!(sol_tx.to, Address::ZERO);
+        assert_eq!(sol_tx.data, Bytes::from_static(&[0x60, 0x00]));
+    }
+}
@@ -2,7 +2,7 @@ use std::{collections::HashMap, sync::Arc};
 
 use alloy_primitives::{Address, B256, Bytes, TxNumber, U256, b256, keccak256};
 use alloy_rlp_derive::{RlpDecodable, RlpEncodable};
-use block_header::Hashable;
+use block_header::{Hashable, Receipt};
 use mpt::KeccakMerkleTrie as MerkleTrie;
 
 /// Hash of an empty byte array, i.e. `keccak256([])`.
@@ -36,14 +36,17 @@ impl Default for StateAccount {
 
 /// A simple read-only EVM database.
 ///
-/// It is backed by a single [MerkleTrie] for the accounts and one [MerkleTrie] each for the
-/// accounts' storages. It panics when data is queried that is not contained in the tries.
+/// It is backed by a single [MerkleTrie] for the accounts, one [MerkleTrie] each for the
+/// accounts' storages and a [MerkleTrie] each for the block transactions and receipts. It panics
+/// when data is queried that is not contained in the tries.
 #[derive(Default, Debug)]
 pub struct StateDb {
     state_trie: MerkleTrie,
     storage_tries: HashMap<B256, Arc<MerkleTrie>>,
     contracts: HashMap<B256, Bytes>,
     block_hashes: HashMap<u64, B256>,
+    transactions_trie: MerkleTrie,
+    receipts_trie: MerkleTrie,
 }
 
 impl StateDb {
@@ -53,6 +56,8 @@ impl StateDb {
         storage_tries: impl IntoIterator<Item = MerkleTrie>,
         contracts: impl IntoIterator<Item = Bytes>,
         block_hashes: HashMap<u64, B256>,
+        transactions_trie: MerkleTrie,
+        receipts_trie: MerkleTrie,
     ) -> Self {
         let contracts = contracts
             .into_iter()
@@ -67,6 +72,8 @@ impl StateDb {
             contracts,
             storage_tries,
             block_hashes,
+            transactions_trie,
+            receipts_trie,
         }
     }
 
@@ -93,6 +100,19 @@ impl StateDb {
         *hash
     }
 
+    pub fn raw_transaction(&self, tx_index: u64) -> Option<Bytes> {
+        self.transactions_trie
+            .get(alloy_rlp::encode(tx_index))
+            .map(Bytes::copy_from_slice)
+    }
+
+    #[allow(clippy::expect_used)]
+    pub fn receipt(&self, tx_index: u64) -> Option<Receipt> {
+        self.receipts_trie
+            .get(alloy_rlp::encode(tx_index))
+            .map(|mut encoded| Receipt::decode_2718(&mut encoded).expect("invalid receipt"))
+    }
+
     pub fn storage_trie(&self, root: &B256) -> Option<&Arc<MerkleTrie>> {
         self.storage_tries.get(root)
     }
@@ -4,7 +4,9 @@ use std::{
     sync::{Arc, RwLock},
 };
 
-use alloy_primitives::{Address, B256, U256, keccak256};
+use alloy_primitives::{Address, B256, Bytes, U256, keccak256};
+use block_header::Receipt;
+use call_common::BlockDatabaseRef;
 use call_engine::evm::input::EvmInput;
 use mpt::KeccakMerkleTrie as MerkleTrie;
 #[allow(clippy::disallowed_types)]
@@ -97,11 +99,29 @@ impl DatabaseRef for WrapStateDb {
     }
 }
 
+impl BlockDatabaseRef for WrapStateDb {
+    /// Get the EIP-2718 encoded transaction at the given index within the block.
+    fn raw_transaction_ref(&self, tx_index: u64) -> Result<Option<Bytes>, Self::Error> {
+        Ok(self.inner.raw_transaction(tx_index))
+    }
+
+    /// Get receipt of the transaction at the given index within the block.
+    fn receipt_ref(&self, tx_index: u64) -> Result<Option<Receipt>, Self::Error> {
+        Ok(self.inner.receipt(tx_index))
+    }
+}
+
 impl From<EvmInput> for WrapStateDb {
     fn from(input: EvmInput) -> Self {
         let block_hashes = input.block_hashes();
-        let state_db =
-            StateDb::new(input.state_trie, input.storage_tries, input.contracts, block_hashes);
+        let state_db = StateDb::new(
+            input.state_trie,
+            input.storage_tries,
+            input.contracts,
+            block_hashes,
+            input.transactions_trie,
+            input.receipts_trie,
+        );
 
         WrapStateDb::new(state_db)
     }
@@ -174,3 +194,92 @@ mod storage_ref {
         assert_eq!(value, U256::from(42));
     }
 }
+
+#[cfg(test)]
+mod receipt_ref {
+    use block_header::LEGACY_TX_TYPE;
+
+    use super::*;
+
+    fn db_with_receipts(receipts_trie: MerkleTrie) -> WrapStateDb {
+        let state_db = StateDb::new(
+            MerkleTrie::default(),
+            [],
+            [],
+            HashMap::new(),
+            MerkleTrie::default(),
+            receipts_trie,
+        );
+        WrapStateDb::new(state_db)
+    }
+
+    #[test]
+    fn success() {
+        let receipt = Receipt {
+            tx_type: LEGACY_TX_TYPE,
+            status: true,
+            cumulative_gas_used: 21_000,
+            ..Default::default()
+        };
+        let receipts_trie = [(alloy_rlp::encode(0_u64), receipt.encoded_2718())]
+            .into_iter()
+            .collect();
+        let db = db_with_receipts(receipts_trie);
+
+        assert_eq!(db.receipt_ref(0).unwrap(), Some(receipt));
+        assert_eq!(db.receipt_ref(1).unwrap(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid receipt")]
+    fn panics_when_receipt_invalid() {
+        let receipts_trie = [(alloy_rlp::encode(0_u64), vec![0x80])]
+            .into_iter()
+            .collect();
+        let db = db_with_receipts(receipts_trie);
+
+        db.receipt_ref(0).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod raw_transaction_ref {
+    use super::*;
+
+    fn db_with_transactions(transactions_trie: MerkleTrie) -> WrapStateDb {
+        let state_db = StateDb::new(
+            MerkleTrie::default(),
+            [],
+            [],
+            HashMap::new(),
+            transactions_trie,
+            MerkleTrie::default(),
+        );
+        WrapStateDb::new(state_db)
+    }
+
+    #[test]
+    fn success() {
+        // Example from EIP-155
+        let encoded = alloy_primitives::hex!(
+            "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83"
+        );
+        let transactions_trie = [(alloy_rlp::encode(0_u64), encoded.to_vec())]
+            .into_iter()
+            .collect();
+        let db = db_with_transactions(transactions_trie);
+
+        assert_eq!(db.raw_transaction_ref(0).unwrap(), Some(Bytes::from(encoded)));
+        assert_eq!(db.raw_transaction_ref(1).unwrap(), None);
+    }
+
+    #[test]
+    fn does_not_decode_transaction() {
+        let transactions_trie = [(alloy_rlp::encode(0_u64), vec![0x80])]
+            .into_iter()
+            .collect();
+        let db = db_with_transactions(transactions_trie);
+
+        assert_eq!(db.raw_transaction_ref(0).unwrap(), Some(Bytes::from_static(&[0x80])));
+    }
+}
@@ -34,6 +34,7 @@ pub async fn main(
         call,
         op_output_cache,
         is_vlayer_test,
+        trusted_notary_keys,
     }: Input,
     chain_guest_ids: impl IntoIterator<Item = Digest>,
 ) -> GuestOutput {
@@ -51,6 +52,7 @@ pub async fn main(
 
     let evm_call_result =
         TravelCallExecutor::new(&cached_envs, start_execution_location, is_vlayer_test)
+            .with_trusted_notary_keys(trusted_notary_keys.clone())
             .call(&call)
             .expect("travel call execution failed")
             .output;
@@ -64,6 +66,7 @@ pub async fn main(
         start_env.header(),
         call.to,
         call.selector(),
+        trusted_notary_keys.as_deref(),
     );
 
     GuestOutput::new(call_assumptions, evm_call_result)
@@ -30,6 +30,8 @@ fn mock_header(state_root: B256) -> Box<dyn EvmBlockHeader> {
     let header = EthBlockHeader {
         number: BLOCK_NUM,
         state_root,
+        transactions_root: mpt::EMPTY_ROOT_HASH,
+        receipts_root: mpt::EMPTY_ROOT_HASH,
         ..Default::default()
     };
     Box::new(header)
@@ -42,6 +44,8 @@ fn mock_evm_input(header: Box<dyn EvmBlockHeader>, state_trie: MerkleTrie) -> Ev
         storage_tries: Default::default(),
         contracts: Default::default(),
         ancestors: Default::default(),
+        transactions_trie: Default::default(),
+        receipts_trie: Default::default(),
     }
 }
 
@@ -55,6 +55,7 @@ pub struct Host {
     travel_call_verifier: HostTravelCallVerifier,
     guest_elf: GuestElf,
     is_vlayer_test: bool,
+    trusted_notary_keys: Option<Vec<String>>,
 }
 
 impl Host {
@@ -120,6 +121,7 @@ impl Host {
             travel_call_verifier,
             guest_elf: config.call_guest_elf,
             is_vlayer_test: config.is_vlayer_test,
+            trusted_notary_keys: config.trusted_notary_keys,
         })
     }
 
@@ -145,6 +147,7 @@ impl Host {
             gas_used,
             metadata,
         } = TravelCallExecutor::new(&self.envs, self.start_execution_location, self.is_vlayer_test)
+            .with_trusted_notary_keys(self.trusted_notary_keys.clone())
             .call(&call)?;
 
         self.travel_call_verifier
@@ -179,6 +182,7 @@ impl Host {
             call,
             op_output_cache,
             is_vlayer_test: self.is_vlayer_test,
+            trusted_notary_keys: self.trusted_notary_keys,
         })
     }
 
@@ -17,6 +17,7 @@
 //!            ╚>`WithStartChainId`
 //!               ║ `with_prover_contract_addr` - calculate start execution location,
 //!               ║ (ensuring that the prover contract is deployed on that location)
+//!               ║ or `with_start_block_number` - use a previously calculated location
 //!               ║
 //!               ╚>`WithStartExecLocation`
 //!                  ║ `build`
@@ -196,6 +197,24 @@ impl WithStartChainId {
             op_client_factory,
         })
     }
+
+    /// Start the execution at a known block, skipping the checks done by
+    /// `with_prover_contract_addr`. Used to resume calls whose start location was
+    /// already calculated, so that they keep their original call hash.
+    pub fn with_start_block_number(self, start_block_number: BlockNumber) -> WithStartExecLocation {
+        let WithStartChainId {
+            start_chain_id,
+            chain_client,
+            providers,
+            op_client_factory,
+        } = self;
+        WithStartExecLocation {
+            chain_client: Some(chain_client),
+            start_exec_location: (start_chain_id, start_block_number).into(),
+            providers,
+            op_client_factory,
+        }
+    }
 }
 
 fn compute_start_block_number(
@@ -298,6 +317,12 @@ mod tests {
             assert_eq!(res.start_exec_location, (CHAIN_ID, LATEST_RPC_BLOCK).into());
         }
 
+        #[test]
+        fn start_block_number() {
+            let res = builder(&[]).with_start_block_number(LATEST_RPC_BLOCK);
+            assert_eq!(res.start_exec_location, (CHAIN_ID, LATEST_RPC_BLOCK).into());
+        }
+
         #[tokio::test(flavor = "multi_thread")]
         async fn prover_contract_indexed() {
             let builder = builder(&[b"01", b"01"]);
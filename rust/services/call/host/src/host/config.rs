@@ -7,6 +7,9 @@ pub struct Config {
     pub call_guest_elf: GuestElf,
     pub chain_guest_ids: Box<[Digest]>,
     pub is_vlayer_test: bool,
+    /// PEM-encoded public keys of the notaries trusted to sign web proofs. `None` accepts any
+    /// notary.
+    pub trusted_notary_keys: Option<Vec<String>>,
 }
 
 impl Default for Config {
@@ -16,6 +19,7 @@ impl Default for Config {
             call_guest_elf: GuestElf::default(),
             chain_guest_ids: vec![].into_boxed_slice(),
             is_vlayer_test: false,
+            trusted_notary_keys: None,
         }
     }
 }
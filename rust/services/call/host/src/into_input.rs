@@ -18,6 +18,10 @@ pub enum Error {
     ProofDB(#[from] ProofDbError),
     #[error("State root mismatch")]
     StateRootMismatch,
+    #[error("Transactions root mismatch")]
+    TransactionsRootMismatch,
+    #[error("Receipts root mismatch")]
+    ReceiptsRootMismatch,
     #[error("Can't unwrap EvmEnv Arc as it still has {0} strong references")]
     OutstandingStrongReferencesOnArcUnwrap(usize),
 }
@@ -34,12 +38,30 @@ fn into_input(db: &ProofDb, header: Box<dyn EvmBlockHeader>) -> Result<EvmInput>
         return Err(Error::StateRootMismatch);
     }
 
+    // Transactions and receipts are only included if they were accessed during execution
+    let transactions_trie = match db.transactions_trie()? {
+        Some(transactions_trie) if header.transactions_root() != &transactions_trie.hash_slow() => {
+            return Err(Error::TransactionsRootMismatch);
+        }
+        Some(transactions_trie) => transactions_trie,
+        None => MerkleTrie(Node::Digest(*header.transactions_root())),
+    };
+    let receipts_trie = match db.receipts_trie()? {
+        Some(receipts_trie) if header.receipts_root() != &receipts_trie.hash_slow() => {
+            return Err(Error::ReceiptsRootMismatch);
+        }
+        Some(receipts_trie) => receipts_trie,
+        None => MerkleTrie(Node::Digest(*header.receipts_root())),
+    };
+
     let evm_input = EvmInput {
         header,
         state_trie,
         storage_tries,
         contracts: db.contracts(),
         ancestors: db.fetch_ancestors()?,
+        transactions_trie,
+        receipts_trie,
     };
     evm_input.print_sizes();
 
@@ -81,4 +103,30 @@ mod test {
         let input = into_input(&db, Box::new(header)).unwrap();
         assert_eq!(input.state_trie, MerkleTrie(Node::Digest(state_root)));
     }
+
+    #[test]
+    fn into_input_no_transactions_accessed() {
+        let provider = provider::never::NeverProvider(PhantomData);
+        let db = ProofDb::new(Arc::new(provider), 0);
+        let transactions_root = B256::with_last_byte(1);
+        let header = EthBlockHeader {
+            transactions_root,
+            ..Default::default()
+        };
+        let input = into_input(&db, Box::new(header)).unwrap();
+        assert_eq!(input.transactions_trie, MerkleTrie(Node::Digest(transactions_root)));
+    }
+
+    #[test]
+    fn into_input_no_receipts_accessed() {
+        let provider = provider::never::NeverProvider(PhantomData);
+        let db = ProofDb::new(Arc::new(provider), 0);
+        let receipts_root = B256::with_last_byte(1);
+        let header = EthBlockHeader {
+            receipts_root,
+            ..Default::default()
+        };
+        let input = into_input(&db, Box::new(header)).unwrap();
+        assert_eq!(input.receipts_trie, MerkleTrie(Node::Digest(receipts_root)));
+    }
 }
@@ -9,3 +9,15 @@ pub fn verify(input: &Bytes) -> Result<Bytes> {
         .map(|x| x.abi_encode().into())
         .map_err(map_to_fatal)
 }
+
+pub fn verify_attachment(input: &Bytes) -> Result<Bytes> {
+    email_proof::parse_and_verify_attachment(input)
+        .map(|x| x.abi_encode().into())
+        .map_err(map_to_fatal)
+}
+
+pub fn verify_arc(input: &Bytes) -> Result<Bytes> {
+    email_proof::parse_and_verify_arc(input)
+        .map(|x| x.abi_encode().into())
+        .map_err(map_to_fatal)
+}
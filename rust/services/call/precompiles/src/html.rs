@@ -0,0 +1,81 @@
+use alloy_primitives::Bytes;
+use alloy_sol_types::{SolType, SolValue, sol_data};
+use scraper::{Html, Selector};
+
+use crate::helpers::{Result, map_to_fatal};
+
+type InputType = sol_data::FixedArray<sol_data::String, 2>;
+
+pub fn get_text(input: &Bytes) -> Result<Bytes> {
+    let [html, selector] = InputType::abi_decode(input, true).map_err(map_to_fatal)?;
+    select_text(&html, &selector)
+        .map(|text| text.abi_encode().into())
+        .map_err(map_to_fatal)
+}
+
+fn select_text(html: &str, selector: &str) -> std::result::Result<String, String> {
+    let selector =
+        Selector::parse(selector).map_err(|err| format!("Invalid CSS selector: {err}"))?;
+    let document = Html::parse_document(html);
+    document
+        .select(&selector)
+        .next()
+        .map(|element| element.text().collect::<String>().trim().to_string())
+        .ok_or_else(|| "No element matches the selector".to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use revm::precompile::PrecompileErrors;
+
+    use super::*;
+
+    const HTML: &str = r#"
+        <html>
+            <body>
+                <h1 id="title">  Account summary </h1>
+                <div class="balance">Balance: <b>42</b> USD</div>
+                <ul><li>first</li><li>second</li></ul>
+            </body>
+        </html>
+    "#;
+
+    fn run(selector: &str) -> Result<String> {
+        let input = [HTML, selector].abi_encode();
+        let result = get_text(&Bytes::from(input))?;
+        Ok(String::abi_decode(&result, true).unwrap())
+    }
+
+    #[test]
+    fn selects_by_id() {
+        assert_eq!(run("#title").unwrap(), "Account summary");
+    }
+
+    #[test]
+    fn concatenates_nested_text() {
+        assert_eq!(run("div.balance").unwrap(), "Balance: 42 USD");
+    }
+
+    #[test]
+    fn returns_first_match() {
+        assert_eq!(run("ul > li").unwrap(), "first");
+    }
+
+    #[test]
+    fn no_match() {
+        let err = run("table").unwrap_err();
+        assert!(matches!(
+            err,
+            PrecompileErrors::Fatal { msg } if msg == "No element matches the selector"
+        ));
+    }
+
+    #[test]
+    fn invalid_selector() {
+        let err = run("div[").unwrap_err();
+        assert!(matches!(
+            err,
+            PrecompileErrors::Fatal { msg } if msg.starts_with("Invalid CSS selector")
+        ));
+    }
+}
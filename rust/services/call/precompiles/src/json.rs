@@ -1,13 +1,16 @@
-use alloy_primitives::Bytes;
+use alloy_primitives::{Bytes, U256};
 use alloy_sol_types::sol_data;
 use args::decode_args;
+pub use batch::get_batch;
 pub use get_float_as_int::get_float_as_int;
 use jmespath::Variable;
 use path::get_value_by_path;
+use revm::precompile::PrecompileErrors;
 
 use crate::helpers::{Result, abi_encode, map_to_fatal};
 
 mod args;
+mod batch;
 mod get_float_as_int;
 mod path;
 
@@ -15,30 +18,117 @@ type InputType = sol_data::FixedArray<sol_data::String, 2>;
 
 pub fn get_string(input: &Bytes) -> Result<Bytes> {
     let (value, path) = get_value(input)?;
-    value
-        .as_string()
-        .map(abi_encode)
-        .ok_or(map_to_fatal(format!("Expected type 'String' at {path}, but found {value:?}")))
+    to_string(&value, &path).map(abi_encode)
 }
 
-#[allow(clippy::unwrap_used)]
 pub fn get_int(input: &Bytes) -> Result<Bytes> {
     let (value, path) = get_value(input)?;
-    let numeric_value = match value {
-        Variable::Number(ref num) => num.as_i64(),
-        _ => None,
-    };
-    numeric_value
-        .map(abi_encode)
-        .ok_or(map_to_fatal(format!("Expected type 'Number' at {path}, but found {value:?}")))
+    to_int(&value, &path).map(abi_encode)
 }
 
 pub fn get_bool(input: &Bytes) -> Result<Bytes> {
     let (value, path) = get_value(input)?;
+    to_bool(&value, &path).map(abi_encode)
+}
+
+pub fn get_array_length(input: &Bytes) -> Result<Bytes> {
+    let (value, path) = get_value(input)?;
+    to_array_length(&value, &path).map(|length| abi_encode(U256::from(length)))
+}
+
+/// Keys of the object at the path, in lexicographical order.
+pub fn get_keys(input: &Bytes) -> Result<Bytes> {
+    let (value, path) = get_value(input)?;
+    to_keys(&value, &path).map(abi_encode)
+}
+
+pub fn get_string_array(input: &Bytes) -> Result<Bytes> {
+    let (value, path) = get_value(input)?;
+    to_string_array(&value, &path).map(abi_encode)
+}
+
+pub fn get_int_array(input: &Bytes) -> Result<Bytes> {
+    let (value, path) = get_value(input)?;
+    to_int_array(&value, &path).map(abi_encode)
+}
+
+pub fn get_bool_array(input: &Bytes) -> Result<Bytes> {
+    let (value, path) = get_value(input)?;
+    to_bool_array(&value, &path).map(abi_encode)
+}
+
+fn to_string(value: &Variable, path: &str) -> Result<String> {
+    value
+        .as_string()
+        .cloned()
+        .ok_or_else(|| type_mismatch("String", path, value))
+}
+
+fn to_int(value: &Variable, path: &str) -> Result<i64> {
+    as_i64(value).ok_or_else(|| type_mismatch("Number", path, value))
+}
+
+fn to_bool(value: &Variable, path: &str) -> Result<bool> {
     value
         .as_boolean()
-        .map(abi_encode)
-        .ok_or(map_to_fatal(format!("Expected type 'Bool' at {path}, but found {value:?}")))
+        .ok_or_else(|| type_mismatch("Bool", path, value))
+}
+
+fn to_array_length(value: &Variable, path: &str) -> Result<usize> {
+    value
+        .as_array()
+        .map(Vec::len)
+        .ok_or_else(|| type_mismatch("Array", path, value))
+}
+
+fn to_keys(value: &Variable, path: &str) -> Result<Vec<String>> {
+    value
+        .as_object()
+        .map(|object| object.keys().cloned().collect())
+        .ok_or_else(|| type_mismatch("Object", path, value))
+}
+
+fn to_string_array(value: &Variable, path: &str) -> Result<Vec<String>> {
+    to_array(value, path, "String", |element| element.as_string().cloned())
+}
+
+fn to_int_array(value: &Variable, path: &str) -> Result<Vec<i64>> {
+    to_array(value, path, "Number", as_i64)
+}
+
+fn to_bool_array(value: &Variable, path: &str) -> Result<Vec<bool>> {
+    to_array(value, path, "Bool", Variable::as_boolean)
+}
+
+/// Extracts every element of the array, failing on the first one of a different type.
+fn to_array<T>(
+    value: &Variable,
+    path: &str,
+    element_type: &str,
+    extract: impl Fn(&Variable) -> Option<T>,
+) -> Result<Vec<T>> {
+    let array = value
+        .as_array()
+        .ok_or_else(|| type_mismatch("Array", path, value))?;
+    array
+        .iter()
+        .enumerate()
+        .map(|(index, element)| {
+            extract(element.as_ref())
+                .ok_or_else(|| type_mismatch(element_type, &format!("{path}[{index}]"), element))
+        })
+        .collect()
+}
+
+fn as_i64(value: &Variable) -> Option<i64> {
+    match value {
+        Variable::Number(num) => num.as_i64(),
+        _ => None,
+    }
+}
+
+fn type_mismatch(expected: &str, path: &str, found: &Variable) -> PrecompileErrors {
+    map_to_fatal(format!("Expected type '{expected}' at {path}, but found {found:?}"))
 }
 
 fn get_value(input: &Bytes) -> Result<(Variable, String)> {
@@ -231,6 +321,132 @@ mod tests {
         }
     }
 
+    mod array_tests {
+        use super::*;
+
+        #[test]
+        fn success_array_length() {
+            let input = abi_encode(&[TEST_JSON, "root.nested_level.field_array_of_objects"]);
+
+            let output = get_array_length(&input).unwrap();
+
+            let length = sol_data::Uint::<256>::abi_decode(&output, false).unwrap();
+            assert_eq!(length, U256::from(2));
+        }
+
+        #[test]
+        fn success_empty_array_length() {
+            let input = abi_encode(&[r#"{"empty": []}"#, "empty"]);
+
+            let output = get_array_length(&input).unwrap();
+
+            let length = sol_data::Uint::<256>::abi_decode(&output, false).unwrap();
+            assert_eq!(length, U256::ZERO);
+        }
+
+        #[test]
+        fn fail_array_length_object() {
+            let input = abi_encode(&[TEST_JSON, "root.nested_level.field_object"]);
+
+            assert_eq!(
+                get_array_length(&input),
+                Err(Fatal {
+                    msg: "Expected type 'Array' at root.nested_level.field_object, but found Object({})".into()
+                })
+            );
+        }
+
+        #[test]
+        fn success_keys() {
+            let input = abi_encode(&[r#"{"b": 1, "a": {"c": 2}}"#, "@"]);
+
+            let output = get_keys(&input).unwrap();
+
+            let keys = sol_data::Array::<sol_data::String>::abi_decode(&output, false).unwrap();
+            assert_eq!(keys, vec!["a".to_string(), "b".to_string()]);
+        }
+
+        #[test]
+        fn fail_keys_array() {
+            let input = abi_encode(&[TEST_JSON, "root.nested_level.field_array_of_numbers"]);
+
+            assert_eq!(
+                get_keys(&input),
+                Err(Fatal {
+                    msg: "Expected type 'Object' at root.nested_level.field_array_of_numbers, but found Array([Number(Number(1)), Number(Number(2)), Number(Number(3))])".into()
+                })
+            );
+        }
+
+        #[test]
+        fn success_string_array() {
+            let input = abi_encode(&[TEST_JSON, "root.nested_level.field_array"]);
+
+            let output = get_string_array(&input).unwrap();
+
+            let values = sol_data::Array::<sol_data::String>::abi_decode(&output, false).unwrap();
+            assert_eq!(values, vec!["val1".to_string(), "val2".to_string()]);
+        }
+
+        #[test]
+        fn success_string_array_from_projection() {
+            let input = abi_encode(&[TEST_JSON, "root.nested_level.field_array_of_objects[*].key"]);
+
+            let output = get_string_array(&input).unwrap();
+
+            let values = sol_data::Array::<sol_data::String>::abi_decode(&output, false).unwrap();
+            assert_eq!(values, vec!["val01".to_string(), "val02".to_string()]);
+        }
+
+        #[test]
+        fn success_int_array() {
+            let input = abi_encode(&[r#"{"numbers": [1, -2, 3]}"#, "numbers"]);
+
+            let output = get_int_array(&input).unwrap();
+
+            let values = sol_data::Array::<sol_data::Int<256>>::abi_decode(&output, false).unwrap();
+            let expected: Vec<alloy_primitives::I256> = ["1", "-2", "3"]
+                .iter()
+                .map(|v| v.parse().unwrap())
+                .collect();
+            assert_eq!(values, expected);
+        }
+
+        #[test]
+        fn success_bool_array() {
+            let input = abi_encode(&[TEST_JSON, "root.nested_level.field_array_of_booleans"]);
+
+            let output = get_bool_array(&input).unwrap();
+
+            let values = sol_data::Array::<sol_data::Bool>::abi_decode(&output, false).unwrap();
+            assert_eq!(values, vec![false, false, true]);
+        }
+
+        #[test]
+        fn fail_mixed_array() {
+            let input = abi_encode(&[r#"{"mixed": [1, "two"]}"#, "mixed"]);
+
+            assert_eq!(
+                get_int_array(&input),
+                Err(Fatal {
+                    msg: "Expected type 'Number' at mixed[1], but found String(\"two\")".into()
+                })
+            );
+        }
+
+        #[test]
+        fn fail_array_string() {
+            let input = abi_encode(&[TEST_JSON, "root.nested_level.field_string"]);
+
+            assert_eq!(
+                get_bool_array(&input),
+                Err(Fatal {
+                    msg: "Expected type 'Array' at root.nested_level.field_string, but found String(\"field_string_value\")".into()
+                })
+            );
+        }
+    }
+
     #[test]
     fn fail_empty_json_body() {
         let abi_encoded_body_and_json_path = abi_encode(&["{}", "field"]);
@@ -16,9 +16,12 @@ fn abi_decode_args(input: &Bytes) -> Result<(String, String)> {
 }
 
 fn deserialize_args(body: &str, path: String) -> Result<(Value, String)> {
-    let body = serde_json::from_str(body)
-        .map_err(|err| map_to_fatal(format!("Error converting string body to json: {err}")))?;
-    Ok((body, path))
+    Ok((parse_json(body)?, path))
+}
+
+pub(crate) fn parse_json(body: &str) -> Result<Value> {
+    serde_json::from_str(body)
+        .map_err(|err| map_to_fatal(format!("Error converting string body to json: {err}")))
 }
 
 #[cfg(test)]
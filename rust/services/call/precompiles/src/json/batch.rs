@@ -0,0 +1,225 @@
+use alloy_dyn_abi::DynSolValue;
+use alloy_primitives::{Bytes, I256, U256};
+use alloy_sol_types::sol;
+use jmespath::{Rcvar, Variable};
+
+use super::{
+    args::parse_json, get_float_as_int::to_float_as_int, path::search_by_path, to_array_length,
+    to_bool, to_bool_array, to_int, to_int_array, to_keys, to_string, to_string_array,
+};
+use crate::helpers::{Result, map_to_fatal};
+
+sol! {
+    struct JsonQuery {
+        string path;
+        uint8 valueType;
+        // Only used for `FloatAsInt` queries
+        uint8 precision;
+    }
+
+    struct BatchInput {
+        string json;
+        JsonQuery[] queries;
+    }
+}
+
+/// Mirrors the `JsonValueType` enum from `WebProof.sol`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ValueType {
+    String,
+    Int,
+    Bool,
+    FloatAsInt,
+    ArrayLength,
+    Keys,
+    StringArray,
+    IntArray,
+    BoolArray,
+}
+
+impl TryFrom<u8> for ValueType {
+    type Error = u8;
+
+    fn try_from(value: u8) -> std::result::Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::String,
+            1 => Self::Int,
+            2 => Self::Bool,
+            3 => Self::FloatAsInt,
+            4 => Self::ArrayLength,
+            5 => Self::Keys,
+            6 => Self::StringArray,
+            7 => Self::IntArray,
+            8 => Self::BoolArray,
+            _ => return Err(value),
+        })
+    }
+}
+
+/// Parses the JSON and converts it for JMESPath once, then evaluates all the queries against it.
+/// Results are ABI-encoded as a tuple, in the order of the queries.
+pub fn get_batch(input: &Bytes) -> Result<Bytes> {
+    let BatchInput { json, queries } =
+        <BatchInput as alloy_sol_types::SolType>::abi_decode(input, true).map_err(map_to_fatal)?;
+    let body = Rcvar::new(Variable::from(parse_json(&json)?));
+
+    let results = queries
+        .iter()
+        .map(|query| run_query(&body, query))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(DynSolValue::Tuple(results).abi_encode_params().into())
+}
+
+fn run_query(body: &Rcvar, query: &JsonQuery) -> Result<DynSolValue> {
+    let JsonQuery {
+        path,
+        valueType,
+        precision,
+    } = query;
+    let value_type = ValueType::try_from(*valueType)
+        .map_err(|value_type| map_to_fatal(format!("Unsupported JSON value type: {value_type}")))?;
+    let value = search_by_path(body, path).map_err(map_to_fatal)?;
+
+    let strings = |strings: Vec<String>| {
+        DynSolValue::Array(strings.into_iter().map(DynSolValue::String).collect())
+    };
+    let result = match value_type {
+        ValueType::String => DynSolValue::String(to_string(&value, path)?),
+        ValueType::Int => int(to_int(&value, path)?),
+        ValueType::Bool => DynSolValue::Bool(to_bool(&value, path)?),
+        ValueType::FloatAsInt => int(to_float_as_int(&value, path, *precision)?),
+        ValueType::ArrayLength => {
+            DynSolValue::Uint(U256::from(to_array_length(&value, path)?), 256)
+        }
+        ValueType::Keys => strings(to_keys(&value, path)?),
+        ValueType::StringArray => strings(to_string_array(&value, path)?),
+        ValueType::IntArray => {
+            DynSolValue::Array(to_int_array(&value, path)?.into_iter().map(int).collect())
+        }
+        ValueType::BoolArray => DynSolValue::Array(
+            to_bool_array(&value, path)?
+                .into_iter()
+                .map(DynSolValue::Bool)
+                .collect(),
+        ),
+    };
+    Ok(result)
+}
+
+fn int(value: i64) -> DynSolValue {
+    DynSolValue::Int(I256::unchecked_from(value), 256)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_sol_types::{SolType, SolValue, sol_data};
+    use revm::precompile::PrecompileErrors::Fatal;
+
+    use super::*;
+
+    const TEST_JSON: &str =
+        r#"{"name": "vlayer", "stars": 42, "open": true, "price": 1.25, "tags": ["zk", "evm"]}"#;
+
+    fn query(path: &str, value_type: ValueType) -> JsonQuery {
+        JsonQuery {
+            path: path.into(),
+            valueType: value_type as u8,
+            precision: 2,
+        }
+    }
+
+    fn batch(json: &str, queries: Vec<JsonQuery>) -> Bytes {
+        BatchInput {
+            json: json.into(),
+            queries,
+        }
+        .abi_encode()
+        .into()
+    }
+
+    #[test]
+    fn success() {
+        let input = batch(
+            TEST_JSON,
+            vec![
+                query("name", ValueType::String),
+                query("stars", ValueType::Int),
+                query("open", ValueType::Bool),
+                query("price", ValueType::FloatAsInt),
+                query("tags", ValueType::ArrayLength),
+                query("tags", ValueType::StringArray),
+            ],
+        );
+
+        let output = get_batch(&input).unwrap();
+
+        type Output = (
+            sol_data::String,
+            sol_data::Int<256>,
+            sol_data::Bool,
+            sol_data::Int<256>,
+            sol_data::Uint<256>,
+            sol_data::Array<sol_data::String>,
+        );
+        let (name, stars, open, price, tags_length, tags) =
+            Output::abi_decode_params(&output, true).unwrap();
+        assert_eq!(name, "vlayer");
+        assert_eq!(stars, I256::unchecked_from(42));
+        assert!(open);
+        assert_eq!(price, I256::unchecked_from(125));
+        assert_eq!(tags_length, U256::from(2));
+        assert_eq!(tags, vec!["zk".to_string(), "evm".to_string()]);
+    }
+
+    #[test]
+    fn success_no_queries() {
+        let output = get_batch(&batch(TEST_JSON, vec![])).unwrap();
+
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn fails_on_type_mismatch() {
+        let input =
+            batch(TEST_JSON, vec![query("name", ValueType::String), query("name", ValueType::Int)]);
+
+        assert_eq!(
+            get_batch(&input),
+            Err(Fatal {
+                msg: "Expected type 'Number' at name, but found String(\"vlayer\")".into()
+            })
+        );
+    }
+
+    #[test]
+    fn fails_on_unsupported_value_type() {
+        let input = batch(
+            TEST_JSON,
+            vec![JsonQuery {
+                path: "name".into(),
+                valueType: 9,
+                precision: 0,
+            }],
+        );
+
+        assert_eq!(
+            get_batch(&input),
+            Err(Fatal {
+                msg: "Unsupported JSON value type: 9".into()
+            })
+        );
+    }
+
+    #[test]
+    fn fails_on_invalid_json() {
+        let input = batch("not a json", vec![query("name", ValueType::String)]);
+
+        assert_eq!(
+            get_batch(&input),
+            Err(Fatal {
+                msg: "Error converting string body to json: expected ident at line 1 column 2"
+                    .into()
+            })
+        );
+    }
+}
@@ -48,6 +48,15 @@ fn extract_f64_from_json(json: &str, path: &str) -> Result<f64> {
     let variable = get_value_by_path(&json_body, path)
         .map_err(|e| map_to_fatal(format!("Error at path {path}: {e}")))?;
 
+    to_f64(&variable, path)
+}
+
+/// Reads the number at the path and scales it to an integer with the given precision.
+pub(super) fn to_float_as_int(variable: &Variable, path: &str, precision: u8) -> Result<i64> {
+    scale_float_to_int(to_f64(variable, path)?, precision)
+}
+
+fn to_f64(variable: &Variable, path: &str) -> Result<f64> {
     match variable {
         Variable::Number(num) => {
             let float_val = num.as_f64().ok_or_else(|| {
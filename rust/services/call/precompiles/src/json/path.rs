@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use jmespath::{JmespathError, Variable, compile};
+use jmespath::{JmespathError, Rcvar, Variable, compile};
 use serde_json::Value;
 
 pub fn get_value_by_path(value: &Value, path: &str) -> Result<Variable, JmespathError> {
@@ -11,6 +11,15 @@ pub fn get_value_by_path(value: &Value, path: &str) -> Result<Variable, Jmespath
     Ok(value)
 }
 
+/// Same as [get_value_by_path], but on a document already converted to a JMESPath [Rcvar], so
+/// that it can be queried many times while being converted once.
+pub fn search_by_path(value: &Rcvar, path: &str) -> Result<Variable, JmespathError> {
+    let expression = compile(path)?;
+    let value = expression.search(Rcvar::clone(value))?;
+    // The result can still be shared with the document, in which case it is cloned
+    Ok(Arc::try_unwrap(value).unwrap_or_else(|value| Variable::clone(&value)))
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -100,4 +109,13 @@ root.nested_level.field_number | length(@)
         let empty = json!({});
         assert_eq!(get_value_by_path(&empty, "root"), Ok(Variable::Null));
     }
+
+    #[test]
+    fn search_converted_document_many_times() {
+        let document = Rcvar::new(Variable::from(JSON.clone()));
+
+        for path in ["root.nested_level.field_string", "root.nested_level", "@", "missing"] {
+            assert_eq!(search_by_path(&document, path), get_value_by_path(&JSON, path));
+        }
+    }
 }
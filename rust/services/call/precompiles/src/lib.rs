@@ -1,5 +1,6 @@
 pub mod email_proof;
 mod helpers;
+pub mod html;
 pub mod json;
 pub mod precompile;
 pub mod regex;
@@ -8,11 +9,17 @@ pub mod url_pattern;
 mod web_proof;
 
 use alloy_primitives::{Address, Bytes};
-use email_proof::verify as email_proof;
-use helpers::generate_precompile;
+use email_proof::{
+    verify as email_proof, verify_arc as arc_email_proof, verify_attachment as email_attachment,
+};
+use helpers::{generate_precompile, precompile_address};
+use html::get_text as html_get_text;
 use json::{
-    get_bool as json_get_bool, get_float_as_int as json_get_float_as_int, get_int as json_get_int,
-    get_string as json_get_string,
+    get_array_length as json_get_array_length, get_batch as json_get_batch,
+    get_bool as json_get_bool, get_bool_array as json_get_bool_array,
+    get_float_as_int as json_get_float_as_int, get_int as json_get_int,
+    get_int_array as json_get_int_array, get_keys as json_get_keys, get_string as json_get_string,
+    get_string_array as json_get_string_array,
 };
 use precompile::{Precompile, Tag, gas_used};
 use regex::{capture as regex_capture, is_match as regex_is_match};
@@ -21,19 +28,40 @@ use revm::precompile::{
 };
 use thiserror::Error;
 use url_pattern::test as url_pattern_test;
-use web_proof::verify as web_proof;
 
-pub fn precompiles(is_vlayer_test: bool) -> Vec<Precompile> {
+/// `trusted_notary_keys` restricts the web proof precompile to proofs signed by the given notaries
+/// (PEM-encoded public keys). `None` accepts any notary.
+pub fn precompiles(
+    is_vlayer_test: bool,
+    trusted_notary_keys: Option<&[String]>,
+) -> Vec<Precompile> {
     let mut list = vec![
-        generate_precompile!(0x00, web_proof, 1000, 10, Tag::WebProof),
+        Precompile::new(
+            PrecompileWithAddress(
+                precompile_address(0x00),
+                RawPrecompile::new_stateful(web_proof::Verifier::new(
+                    trusted_notary_keys.map(<[String]>::to_vec),
+                )),
+            ),
+            Tag::WebProof,
+        ),
         generate_precompile!(0x01, email_proof, 1000, 10, Tag::EmailProof),
         generate_precompile!(0x02, json_get_string, 1000, 10, Tag::JsonGetString),
         generate_precompile!(0x03, json_get_int, 1000, 10, Tag::JsonGetInt),
         generate_precompile!(0x04, json_get_bool, 1000, 10, Tag::JsonGetBool),
         generate_precompile!(0x05, json_get_float_as_int, 1000, 10, Tag::JsonGetFloatAsInt),
+        generate_precompile!(0x06, json_get_array_length, 1000, 10, Tag::JsonGetArrayLength),
+        generate_precompile!(0x07, json_get_keys, 1000, 10, Tag::JsonGetKeys),
+        generate_precompile!(0x08, json_get_string_array, 1000, 10, Tag::JsonGetStringArray),
+        generate_precompile!(0x09, json_get_int_array, 1000, 10, Tag::JsonGetIntArray),
+        generate_precompile!(0x0a, json_get_bool_array, 1000, 10, Tag::JsonGetBoolArray),
+        generate_precompile!(0x0b, json_get_batch, 1000, 10, Tag::JsonGetBatch),
         generate_precompile!(0x10, regex_is_match, 1000, 10, Tag::RegexIsMatch),
         generate_precompile!(0x11, regex_capture, 1000, 10, Tag::RegexCapture),
         generate_precompile!(0x20, url_pattern_test, 1000, 10, Tag::UrlPatternTest),
+        generate_precompile!(0x40, html_get_text, 1000, 10, Tag::HtmlGetText),
+        generate_precompile!(0x50, arc_email_proof, 1000, 10, Tag::ArcEmailProof),
+        generate_precompile!(0x51, email_attachment, 1000, 10, Tag::EmailAttachment),
     ];
 
     if is_vlayer_test {
@@ -44,13 +72,13 @@ pub fn precompiles(is_vlayer_test: bool) -> Vec<Precompile> {
 }
 
 pub fn precompile_by_address(address: &Address, is_vlayer_test: bool) -> Option<Precompile> {
-    precompiles(is_vlayer_test)
+    precompiles(is_vlayer_test, None)
         .into_iter()
         .find(|precomp| precomp.address() == address)
 }
 
 pub fn precompile_by_tag(tag: &Tag) -> Option<Precompile> {
-    precompiles(false)
+    precompiles(false, None)
         .into_iter()
         .find(|precomp| precomp.tag() == *tag)
 }
@@ -61,14 +89,18 @@ pub struct PrecompileNotAllowedError(pub Tag);
 
 /// Returns `true` if the precompile is time-dependent and must not be used in travel calls.
 ///
-/// Specifically, `WebProof` and `EmailProof` rely on real-world data (e.g., DNS records, timestamps)
+/// Specifically, `WebProof` and the email proof precompiles (`EmailProof`, `ArcEmailProof` and
+/// `EmailAttachment`) rely on real-world data (e.g., DNS records, timestamps)
 /// that can be manipulated when paired with Time Travel:
 /// - A user may jump to a block where an expired `validUntil` timestamp is still valid.
 /// - Or abuse a DNS key that was valid in the past but has since been revoked.
 ///
 /// To preserve integrity, these precompiles are considered unsafe in historical locations.
 pub const fn is_time_dependent(precompile: &Precompile) -> bool {
-    matches!(precompile.tag(), Tag::WebProof | Tag::EmailProof)
+    matches!(
+        precompile.tag(),
+        Tag::WebProof | Tag::EmailProof | Tag::ArcEmailProof | Tag::EmailAttachment
+    )
 }
 
 #[cfg(test)]
@@ -83,6 +115,10 @@ mod tests {
         lazy_static! {
             static ref WEB_PROOF: Precompile = precompile_by_tag(&Tag::WebProof).unwrap();
             static ref EMAIL_PROOF: Precompile = precompile_by_tag(&Tag::EmailProof).unwrap();
+            static ref ARC_EMAIL_PROOF: Precompile =
+                precompile_by_tag(&Tag::ArcEmailProof).unwrap();
+            static ref EMAIL_ATTACHMENT: Precompile =
+                precompile_by_tag(&Tag::EmailAttachment).unwrap();
             static ref JSON_GET_STRING: Precompile =
                 precompile_by_tag(&Tag::JsonGetString).unwrap();
         }
@@ -96,6 +132,8 @@ mod tests {
         fn rejects_invalid_precompile() {
             assert!(is_time_dependent(&WEB_PROOF));
             assert!(is_time_dependent(&EMAIL_PROOF));
+            assert!(is_time_dependent(&ARC_EMAIL_PROOF));
+            assert!(is_time_dependent(&EMAIL_ATTACHMENT));
         }
     }
 }
@@ -11,11 +11,19 @@ use crate::helpers::Result;
 pub enum Tag {
     WebProof,
     EmailProof,
+    ArcEmailProof,
+    EmailAttachment,
     JsonGetString,
     JsonGetInt,
     JsonGetBool,
     JsonGetFloatAsInt,
     JsonGetArrayLength,
+    JsonGetKeys,
+    JsonGetStringArray,
+    JsonGetIntArray,
+    JsonGetBoolArray,
+    JsonGetBatch,
+    HtmlGetText,
     RegexIsMatch,
     RegexCapture,
     UrlPatternTest,
@@ -2,12 +2,23 @@ use std::convert::Into;
 
 use alloy_primitives::Bytes;
 use alloy_sol_types::{SolCall, sol};
+use revm::{
+    precompile::{PrecompileOutput, PrecompileResult, StatefulPrecompile},
+    primitives::Env,
+};
 use web_proof::{
     verifier::verify_and_parse,
-    web_proof::{BodyRedactionMode, Config, UrlTestMode},
+    web::abi_encode_exchanges,
+    web_proof::{BodyRedactionMode, Config, ContentType, ContentTypes, UrlTestMode},
+};
+
+use crate::{
+    helpers::{Result, map_to_fatal},
+    precompile::gas_used,
 };
 
-use crate::helpers::{Result, map_to_fatal};
+const BASE_COST: u64 = 1000;
+const BYTE_COST: u64 = 10;
 
 sol! {
     contract WebProof {
@@ -28,7 +39,15 @@ sol! {
             Enabled_UNSAFE
         }
 
-        function verify(Proof web_proof, UrlTestMode url_test_mode, BodyRedactionMode body_redaction_mode);
+        #[derive(Debug)]
+        enum ContentType {
+            Json,
+            Html,
+            Text,
+            FormUrlEncoded
+        }
+
+        function verify(Proof web_proof, UrlTestMode url_test_mode, BodyRedactionMode body_redaction_mode, ContentType[] allowed_content_types, string[] response_headers);
     }
 }
 
@@ -56,15 +75,59 @@ impl From<WebProof::UrlTestMode> for UrlTestMode {
     }
 }
 
-pub(super) fn verify(input: &Bytes) -> Result<Bytes> {
+impl From<WebProof::ContentType> for ContentType {
+    fn from(content_type: WebProof::ContentType) -> Self {
+        match content_type {
+            WebProof::ContentType::Json => ContentType::Json,
+            WebProof::ContentType::Html => ContentType::Html,
+            WebProof::ContentType::Text => ContentType::Text,
+            WebProof::ContentType::FormUrlEncoded => ContentType::FormUrlEncoded,
+            WebProof::ContentType::__Invalid => {
+                unreachable!("Invalid ContentType")
+            }
+        }
+    }
+}
+
+/// Web proof precompile. Unlike the others, it needs state: the notaries trusted by the host.
+#[derive(Debug, Clone)]
+pub(super) struct Verifier {
+    trusted_notary_keys: Option<Vec<String>>,
+}
+
+impl Verifier {
+    pub(super) const fn new(trusted_notary_keys: Option<Vec<String>>) -> Self {
+        Self {
+            trusted_notary_keys,
+        }
+    }
+}
+
+impl StatefulPrecompile for Verifier {
+    fn call(&self, input: &Bytes, gas_limit: u64, _env: &Env) -> PrecompileResult {
+        let gas_used = gas_used(input.len(), gas_limit, BASE_COST, BYTE_COST)?;
+        verify(input, self.trusted_notary_keys.clone())
+            .map(|out| PrecompileOutput::new(gas_used, out))
+    }
+}
+
+fn verify(input: &Bytes, trusted_notary_keys: Option<Vec<String>>) -> Result<Bytes> {
     let WebProof::verifyCall {
         web_proof: WebProof::Proof { web_proof_json },
         url_test_mode,
         body_redaction_mode,
+        allowed_content_types,
+        response_headers,
     } = WebProof::verifyCall::abi_decode_raw(input, true).map_err(map_to_fatal)?;
     let web_proof = serde_json::from_str(&web_proof_json).map_err(map_to_fatal)?;
-    let config = Config::new(body_redaction_mode, url_test_mode);
+    let allowed_content_types: ContentTypes = allowed_content_types
+        .into_iter()
+        .map(ContentType::from)
+        .collect();
+    let config =
+        Config::new(body_redaction_mode, url_test_mode, allowed_content_types, response_headers)
+            .with_trusted_notary_keys(trusted_notary_keys);
     verify_and_parse(web_proof, config)
-        .map(|x| x.abi_encode().into())
+        .map(|webs| abi_encode_exchanges(webs).into())
         .map_err(map_to_fatal)
 }
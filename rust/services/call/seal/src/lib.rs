@@ -164,6 +164,7 @@ mod test {
             settleChainId: Uint::<256, 4>::from(1),
             settleBlockNumber: Uint::<256, 4>::from(1),
             settleBlockHash: FixedBytes::new([0; 32]),
+            trustedNotaryKeysHash: FixedBytes::new([0; 32]),
         };
         call_assumptions.abi_encode()
     }
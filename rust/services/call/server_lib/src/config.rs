@@ -24,7 +24,14 @@ use server_utils::{ProofMode, jwt::config::Config as JwtConfig};
 use strum::VariantNames;
 use thiserror::Error;
 
-use crate::gas_meter::Config as GasMeterConfig;
+use crate::{
+    gas_meter::Config as GasMeterConfig,
+    queue::{
+        Config as QueueConfig, DEFAULT_MAX_QUEUE_SIZE, DEFAULT_PREFLIGHT_WORKERS,
+        DEFAULT_PROVING_WORKERS,
+    },
+    retention::{Config as RetentionConfig, DEFAULT_MAX_ENTRIES, DEFAULT_TTL},
+};
 
 pub const DEFAULT_HOST: &str = "127.0.0.1";
 pub const DEFAULT_PORT: u16 = 3000;
@@ -52,6 +59,8 @@ pub enum Error {
     JwtSigningAlgorithm(String),
     #[error(transparent)]
     Jwt(#[from] JwtError),
+    #[error("Queue needs at least one preflight and one proving worker")]
+    QueueWorkers,
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
@@ -91,6 +100,24 @@ pub struct ChainClientOptions {
     pub timeout: Option<u64>,
 }
 
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct QueueOptions {
+    /// Number of calls in preflight at once
+    pub preflight_workers: Option<usize>,
+    /// Number of calls proven at once
+    pub proving_workers: Option<usize>,
+    /// Number of calls waiting for a worker, above which new calls are rejected
+    pub max_size: Option<usize>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RetentionOptions {
+    /// Time in seconds for which finished proofs are kept
+    pub ttl: Option<u64>,
+    /// Number of finished proofs kept, above which least recently used ones are evicted
+    pub max_entries: Option<usize>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Into, PartialEq, Eq)]
 #[into((ChainId, String))]
 pub struct RpcUrl {
@@ -135,6 +162,18 @@ pub struct ConfigOptions {
     pub gas_meter: Option<GasMeterOptions>,
     /// Log format
     pub log_format: Option<LogFormat>,
+    /// PEM-encoded public keys of the notaries trusted to sign web proofs. Any notary is accepted if not set
+    pub trusted_notary_keys: Option<Vec<String>>,
+    /// Directory where proof jobs are persisted. Jobs are kept only in memory if not set
+    pub proof_store_path: Option<String>,
+    /// Proving queue config
+    pub queue: Option<QueueOptions>,
+    /// Finished proofs retention config
+    pub retention: Option<RetentionOptions>,
+    /// API key required by admin methods. Admin methods are disabled if not set
+    pub admin_api_key: Option<String>,
+    /// Secret used to sign webhook notifications. Webhooks are disabled if not set
+    pub webhook_secret: Option<String>,
 }
 
 pub(crate) fn parse_config_file(path: impl AsRef<Path>) -> Result<ConfigOptions, Error> {
@@ -234,6 +273,12 @@ impl Default for ConfigOptions {
             proof_mode: ProofMode::default(),
             rpc_urls: Vec::default(),
             log_format: None,
+            trusted_notary_keys: None,
+            proof_store_path: None,
+            queue: None,
+            retention: None,
+            admin_api_key: None,
+            webhook_secret: None,
         }
     }
 }
@@ -258,6 +303,31 @@ impl From<GasMeterOptions> for GasMeterConfig {
     }
 }
 
+impl From<QueueOptions> for QueueConfig {
+    fn from(
+        QueueOptions {
+            preflight_workers,
+            proving_workers,
+            max_size,
+        }: QueueOptions,
+    ) -> Self {
+        Self::new(
+            preflight_workers.unwrap_or(DEFAULT_PREFLIGHT_WORKERS),
+            proving_workers.unwrap_or(DEFAULT_PROVING_WORKERS),
+            max_size.unwrap_or(DEFAULT_MAX_QUEUE_SIZE),
+        )
+    }
+}
+
+impl From<RetentionOptions> for RetentionConfig {
+    fn from(RetentionOptions { ttl, max_entries }: RetentionOptions) -> Self {
+        Self::new(
+            Duration::from_secs(ttl.unwrap_or(DEFAULT_TTL)),
+            max_entries.unwrap_or(DEFAULT_MAX_ENTRIES),
+        )
+    }
+}
+
 impl TryFrom<JwtOptions> for JwtConfig {
     type Error = Error;
 
@@ -303,6 +373,8 @@ impl TryFrom<ConfigOptionsWithVersion> for Config {
             })
             .transpose()?;
         let chain_client_config = opts.config.chain_client.map(Into::into);
+        let queue_config = opts.config.queue.map(Into::into).unwrap_or_default();
+        let retention_config = opts.config.retention.map(Into::into).unwrap_or_default();
 
         ConfigBuilder::default()
             .with_chain_guest_ids(CHAIN_GUEST_IDS)
@@ -315,6 +387,12 @@ impl TryFrom<ConfigOptionsWithVersion> for Config {
             .with_gas_meter_config(gas_meter_config)
             .with_jwt_config(jwt_config)
             .with_chain_client_config(chain_client_config)
+            .with_trusted_notary_keys(opts.config.trusted_notary_keys)
+            .with_proof_store_path(opts.config.proof_store_path.map(Into::into))
+            .with_queue_config(queue_config)
+            .with_retention_config(retention_config)
+            .with_admin_api_key(opts.config.admin_api_key)
+            .with_webhook_secret(opts.config.webhook_secret)
             .build()
     }
 }
@@ -333,6 +411,14 @@ pub struct Config {
     pub semver: String,
     pub gas_meter_config: Option<GasMeterConfig>,
     pub jwt_config: Option<JwtConfig>,
+    pub trusted_notary_keys: Option<Vec<String>>,
+    pub proof_store_path: Option<PathBuf>,
+    pub queue_config: QueueConfig,
+    pub retention_config: RetentionConfig,
+    #[debug(skip)]
+    pub admin_api_key: Option<String>,
+    #[debug(skip)]
+    pub webhook_secret: Option<String>,
 }
 
 impl Config {
@@ -390,6 +476,12 @@ pub struct ConfigBuilder {
     semver: Option<String>,
     gas_meter_config: Option<GasMeterConfig>,
     jwt_config: Option<JwtConfig>,
+    trusted_notary_keys: Option<Vec<String>>,
+    proof_store_path: Option<PathBuf>,
+    queue_config: QueueConfig,
+    retention_config: RetentionConfig,
+    admin_api_key: Option<String>,
+    webhook_secret: Option<String>,
 }
 
 impl ConfigBuilder {
@@ -472,6 +564,42 @@ impl ConfigBuilder {
         self
     }
 
+    #[must_use]
+    pub fn with_trusted_notary_keys(mut self, trusted_notary_keys: Option<Vec<String>>) -> Self {
+        self.trusted_notary_keys = trusted_notary_keys;
+        self
+    }
+
+    #[must_use]
+    pub fn with_proof_store_path(mut self, proof_store_path: Option<PathBuf>) -> Self {
+        self.proof_store_path = proof_store_path;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_queue_config(mut self, queue_config: QueueConfig) -> Self {
+        self.queue_config = queue_config;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_retention_config(mut self, retention_config: RetentionConfig) -> Self {
+        self.retention_config = retention_config;
+        self
+    }
+
+    #[must_use]
+    pub fn with_admin_api_key(mut self, admin_api_key: Option<String>) -> Self {
+        self.admin_api_key = admin_api_key;
+        self
+    }
+
+    #[must_use]
+    pub fn with_webhook_secret(mut self, webhook_secret: Option<String>) -> Self {
+        self.webhook_secret = webhook_secret;
+        self
+    }
+
     pub fn build(self) -> Result<Config, Error> {
         let Self {
             socket_addr,
@@ -484,6 +612,12 @@ impl ConfigBuilder {
             semver,
             gas_meter_config,
             jwt_config,
+            trusted_notary_keys,
+            proof_store_path,
+            queue_config,
+            retention_config,
+            admin_api_key,
+            webhook_secret,
         } = self;
 
         let call_guest_elf = call_guest_elf.ok_or(Error::ConfigField("call_guest_elf".into()))?;
@@ -491,6 +625,9 @@ impl ConfigBuilder {
             chain_guest_ids.ok_or(Error::ConfigField("chain_guest_ids".into()))?;
         let semver = semver.ok_or(Error::ConfigField("semver".into()))?;
         let rpc_urls: BTreeMap<ChainId, String> = rpc_urls.0.into_iter().collect();
+        if queue_config.preflight_workers == 0 || queue_config.proving_workers == 0 {
+            return Err(Error::QueueWorkers);
+        }
 
         Ok(Config {
             socket_addr: socket_addr.0,
@@ -503,6 +640,12 @@ impl ConfigBuilder {
             semver,
             gas_meter_config,
             jwt_config,
+            trusted_notary_keys,
+            proof_store_path,
+            queue_config,
+            retention_config,
+            admin_api_key,
+            webhook_secret,
         })
     }
 }
@@ -514,6 +657,7 @@ impl From<&Config> for HostConfig {
             call_guest_elf: config.call_guest_elf.clone(),
             chain_guest_ids: config.chain_guest_ids.clone(),
             is_vlayer_test: false,
+            trusted_notary_keys: config.trusted_notary_keys.clone(),
         }
     }
 }
@@ -649,6 +793,12 @@ pub(crate) mod tests {
                         time_to_live: None
                     }),
                     log_format: None,
+                    trusted_notary_keys: None,
+                    proof_store_path: None,
+                    queue: None,
+                    retention: None,
+                    admin_api_key: None,
+                    webhook_secret: None,
                 }
             );
         }
@@ -685,10 +835,118 @@ pub(crate) mod tests {
                     auth: None,
                     gas_meter: None,
                     log_format: None,
+                    trusted_notary_keys: None,
+                    proof_store_path: None,
+                    queue: None,
+                    retention: None,
+                    admin_api_key: None,
+                    webhook_secret: None,
                 }
             );
         }
 
+        #[test]
+        fn correctly_parses_trusted_notary_keys() {
+            let config_file = save_config_file(
+                r#"
+        host = "127.0.0.1"
+        port = 3000
+        proof_mode = "fake"
+        trusted_notary_keys = ["-----BEGIN PUBLIC KEY-----\nkey\n-----END PUBLIC KEY-----\n"]
+        "#,
+            );
+
+            let opts = parse_config_file(config_file.path()).unwrap();
+            assert_eq!(
+                opts.trusted_notary_keys,
+                Some(vec![
+                    "-----BEGIN PUBLIC KEY-----\nkey\n-----END PUBLIC KEY-----\n".to_string()
+                ])
+            );
+        }
+
+        #[test]
+        fn correctly_parses_proof_store_path() {
+            let config_file = save_config_file(
+                r#"
+        host = "127.0.0.1"
+        port = 3000
+        proof_mode = "fake"
+        proof_store_path = "/var/lib/vlayer/proofs"
+        "#,
+            );
+
+            let opts = parse_config_file(config_file.path()).unwrap();
+            let config: Config = ConfigOptionsWithVersion {
+                semver: "0".to_string(),
+                config: opts,
+            }
+            .try_into()
+            .unwrap();
+            assert_eq!(config.proof_store_path, Some(PathBuf::from("/var/lib/vlayer/proofs")));
+        }
+
+        #[test]
+        fn correctly_parses_queue_options() {
+            let config_file = save_config_file(
+                r#"
+        host = "127.0.0.1"
+        port = 3000
+        proof_mode = "fake"
+
+        [queue]
+        proving_workers = 2
+        max_size = 10
+        "#,
+            );
+
+            let opts = parse_config_file(config_file.path()).unwrap();
+            let config: Config = ConfigOptionsWithVersion {
+                semver: "0".to_string(),
+                config: opts,
+            }
+            .try_into()
+            .unwrap();
+            assert_eq!(config.queue_config, QueueConfig::new(DEFAULT_PREFLIGHT_WORKERS, 2, 10));
+        }
+
+        #[test]
+        fn correctly_parses_retention_options() {
+            let config_file = save_config_file(
+                r#"
+        host = "127.0.0.1"
+        port = 3000
+        proof_mode = "fake"
+        admin_api_key = "secret"
+
+        [retention]
+        ttl = 600
+        "#,
+            );
+
+            let opts = parse_config_file(config_file.path()).unwrap();
+            let config: Config = ConfigOptionsWithVersion {
+                semver: "0".to_string(),
+                config: opts,
+            }
+            .try_into()
+            .unwrap();
+            assert_eq!(
+                config.retention_config,
+                RetentionConfig::new(Duration::from_secs(600), DEFAULT_MAX_ENTRIES)
+            );
+            assert_eq!(config.admin_api_key.as_deref(), Some("secret"));
+        }
+
+        #[test]
+        fn reports_no_queue_workers() {
+            let res = config_builder()
+                .with_queue_config(QueueConfig::new(0, 1, 1))
+                .build();
+
+            assert!(matches!(res.unwrap_err(), Error::QueueWorkers));
+        }
+
         #[test]
         fn reports_invalid_path_to_jwt_signing_key() {
             let config_file = save_config_file(
@@ -783,6 +1041,12 @@ pub(crate) mod tests {
                     })),
                     gas_meter: None,
                     log_format: None,
+                    trusted_notary_keys: None,
+                    proof_store_path: None,
+                    queue: None,
+                    retention: None,
+                    admin_api_key: None,
+                    webhook_secret: None,
                 }
             );
         }
@@ -44,7 +44,7 @@ impl Method for AllocateGas {
     const METHOD_NAME: &str = "v_allocateGas";
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Clone, Copy, PartialEq, Eq, Debug)]
 #[serde(rename_all = "snake_case")]
 pub enum ComputationStage {
     Preflight,
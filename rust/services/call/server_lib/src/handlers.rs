@@ -1,19 +1,39 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Instant};
 
 use async_trait::async_trait;
-use dashmap::DashMap;
-use derive_more::{Deref, DerefMut};
+use dashmap::{DashMap, Entry, mapref::one::Ref};
 use derive_new::new;
 use jsonrpsee::{Extensions, proc_macros::rpc};
+use jsonrpsee::{PendingSubscriptionSink, core::SubscriptionResult};
+use parking_lot::Mutex;
 use server_utils::{RequestId, jwt::axum::Token};
+use tokio::sync::broadcast;
+use tokio_util::sync::CancellationToken;
+use tracing::error;
 use v_call::types::{Call, CallContext, CallHash, Result as VCallResult};
+use v_cancel::types::{Error as VCancelError, Result as VCancelResult};
 use v_get_proof_receipt::types::{CallResult, Result as VGetProofReceiptResult};
+use v_purge_proof::types::Result as VPurgeProofResult;
 use v_versions::Versions;
 
-use crate::{config::Config, proof::Status as ProofStatus};
+use crate::{
+    config::Config,
+    metrics::Metrics,
+    proof::{
+        Status as ProofStatus,
+        state::State as ProofState,
+        store::{Error as JobStoreError, FinishedJob, Job, JobStore, Owner},
+    },
+    queue::{Config as QueueConfig, Queue, QueueFull},
+    retention::{Config as RetentionConfig, Evicted},
+    webhook::Webhooks,
+};
 
 pub mod v_call;
+pub mod v_cancel;
 pub mod v_get_proof_receipt;
+pub mod v_purge_proof;
+pub mod v_subscribe_proof_status;
 pub mod v_versions;
 
 #[derive(new, Clone)]
@@ -21,6 +41,8 @@ pub struct Params {
     pub config: Config,
     pub token: Option<Token>,
     pub req_id: RequestId,
+    /// Whether the request carries a valid admin API key
+    pub admin: bool,
 }
 
 #[rpc(server)]
@@ -34,10 +56,262 @@ pub trait Rpc {
 
     #[method(name = "v_versions", with_extensions)]
     async fn v_versions(&self) -> Versions;
+
+    #[method(name = "v_cancel", with_extensions)]
+    async fn v_cancel(&self, hash: CallHash) -> VCancelResult<()>;
+
+    #[method(name = "v_purgeProof", with_extensions)]
+    async fn v_purge_proof(&self, hash: CallHash) -> VPurgeProofResult<bool>;
+
+    #[subscription(
+        name = "v_subscribeProofStatus" => "v_proofStatus",
+        unsubscribe = "v_unsubscribeProofStatus",
+        item = CallResult
+    )]
+    async fn v_subscribe_proof_status(&self, hash: CallHash) -> SubscriptionResult;
+}
+
+/// Number of status updates buffered for each subscriber
+const STATUS_UPDATES_CAPACITY: usize = 1024;
+
+#[derive(new, Clone)]
+pub struct StatusUpdate {
+    pub call_hash: CallHash,
+    /// `None` once the call is purged or evicted, as no more updates will follow.
+    pub result: Option<CallResult>,
 }
 
-#[derive(Deref, DerefMut, Default)]
-pub struct Proofs(DashMap<CallHash, ProofStatus>);
+pub struct Proofs {
+    statuses: DashMap<CallHash, ProofStatus>,
+    store: Box<dyn JobStore>,
+    queue: Queue,
+    retention: RetentionConfig,
+    evicted: Mutex<Evicted>,
+    status_updates: broadcast::Sender<StatusUpdate>,
+    webhooks: Option<Webhooks>,
+}
+
+impl Proofs {
+    pub fn new(
+        store: Box<dyn JobStore>,
+        queue_config: QueueConfig,
+        retention: RetentionConfig,
+        webhooks: Option<Webhooks>,
+    ) -> Self {
+        Self {
+            statuses: DashMap::new(),
+            store,
+            queue: Queue::new(queue_config),
+            retention,
+            evicted: Mutex::new(Evicted::new(retention.max_entries)),
+            status_updates: broadcast::channel(STATUS_UPDATES_CAPACITY).0,
+            webhooks,
+        }
+    }
+
+    /// Loads finished proofs from the store and queues the unfinished ones.
+    /// Returns the unfinished jobs, which need to be restarted by the caller.
+    pub fn restore(&self) -> Result<Vec<(CallHash, Job)>, JobStoreError> {
+        for (call_hash, finished_job) in self.store.finished_jobs()? {
+            self.statuses.insert(call_hash, finished_job.into_status()?);
+        }
+        let unfinished_jobs = self.store.unfinished_jobs()?;
+        for (call_hash, job) in &unfinished_jobs {
+            let status = ProofStatus::new(job.owner, job.context.webhook_url.clone());
+            self.statuses.insert(*call_hash, status);
+            self.queue.push(*call_hash);
+        }
+        self.evict_finished(Instant::now());
+        Ok(unfinished_jobs)
+    }
+
+    /// Returns `None` for unknown calls, as well as for the ones whose proofs have expired.
+    pub fn get(&self, call_hash: &CallHash) -> Option<Ref<'_, CallHash, ProofStatus>> {
+        let now = Instant::now();
+        let mut status = self.statuses.get_mut(call_hash)?;
+        if self.is_expired(&status, now) {
+            drop(status);
+            self.statuses
+                .remove_if(call_hash, |_, status| self.is_expired(status, now));
+            self.forget([*call_hash]);
+            return None;
+        }
+        status.last_used = Some(now);
+        Some(status.downgrade())
+    }
+
+    /// Whether the call was known, but its proof has been evicted or purged.
+    pub fn is_evicted(&self, call_hash: &CallHash) -> bool {
+        self.evicted.lock().contains(call_hash)
+    }
+
+    /// Removes the call regardless of its state. Returns `false` if the call is unknown.
+    /// A call which is still in progress is cancelled.
+    pub fn purge(&self, call_hash: CallHash) -> bool {
+        let Some((_, status)) = self.statuses.remove(&call_hash) else {
+            return false;
+        };
+        status.cancellation.cancel();
+        self.queue.remove(&call_hash);
+        self.forget([call_hash]);
+        true
+    }
+
+    /// Requests cancellation of an unfinished call. If the call was made with a JWT token,
+    /// the same token is required.
+    pub fn cancel(&self, call_hash: CallHash, token: Option<&Token>) -> VCancelResult<()> {
+        let status = self
+            .statuses
+            .get(&call_hash)
+            .ok_or(VCancelError::HashNotFound(call_hash))?;
+        if status
+            .owner
+            .as_ref()
+            .is_some_and(|owner| token.is_none_or(|token| Owner::of(token) != *owner))
+        {
+            return Err(VCancelError::NotOwner);
+        }
+        if status.finished_at.is_some() {
+            return Err(VCancelError::AlreadyFinished(call_hash));
+        }
+        status.cancellation.cancel();
+        Ok(())
+    }
+
+    pub fn cancellation(&self, call_hash: &CallHash) -> Option<CancellationToken> {
+        self.statuses
+            .get(call_hash)
+            .map(|status| status.cancellation.clone())
+    }
+
+    /// Receives every status change of every call.
+    pub fn subscribe(&self) -> broadcast::Receiver<StatusUpdate> {
+        self.status_updates.subscribe()
+    }
+
+    pub const fn queue(&self) -> &Queue {
+        &self.queue
+    }
+
+    /// Queues a new job. Returns `false` if a job with the same hash already exists.
+    pub fn insert_job(&self, call_hash: CallHash, job: &Job) -> Result<bool, QueueFull> {
+        let Entry::Vacant(entry) = self.statuses.entry(call_hash) else {
+            return Ok(false);
+        };
+        self.queue.try_push(call_hash)?;
+        entry.insert(ProofStatus::new(job.owner, job.context.webhook_url.clone()));
+        if let Err(err) = self.store.add_job(call_hash, job) {
+            error!("Failed to store job {call_hash}: {err}");
+        }
+        Ok(true)
+    }
+
+    pub fn set_state(&self, call_hash: CallHash, state: ProofState) {
+        self.update(call_hash, |status| status.state = state);
+    }
+
+    pub fn set_state_with_metrics(&self, call_hash: CallHash, state: ProofState, metrics: Metrics) {
+        self.update(call_hash, |status| {
+            status.state = state;
+            status.metrics = metrics;
+        });
+    }
+
+    fn update(&self, call_hash: CallHash, f: impl FnOnce(&mut ProofStatus)) {
+        let (finished_job, webhook_url, result) = {
+            let Some(mut status) = self.statuses.get_mut(&call_hash) else {
+                return;
+            };
+            f(&mut status);
+            let finished_job = FinishedJob::from_status(&status);
+            if finished_job.is_some() {
+                let now = Instant::now();
+                status.finished_at = Some(now);
+                status.last_used = Some(now);
+            }
+            let webhook_url = finished_job.as_ref().and(status.webhook_url.clone());
+            // Receipt is built only if someone is going to receive it
+            let result = (webhook_url.is_some() || self.status_updates.receiver_count() > 0)
+                .then(|| CallResult::from(&*status));
+            (finished_job, webhook_url, result)
+        };
+        if let Some(result) = result {
+            self.notify(call_hash, webhook_url.as_deref(), result);
+        }
+        let Some(finished_job) = finished_job else {
+            return;
+        };
+        // Jobs can finish before taking a preflight worker, e.g. when resuming them fails
+        self.queue.remove(&call_hash);
+        if let Err(err) = self.store.finish_job(call_hash, &finished_job) {
+            error!("Failed to store result of job {call_hash}: {err}");
+        }
+        self.evict_finished(Instant::now());
+    }
+
+    fn notify(&self, call_hash: CallHash, webhook_url: Option<&str>, result: CallResult) {
+        if let (Some(webhooks), Some(webhook_url)) = (&self.webhooks, webhook_url) {
+            webhooks.notify(webhook_url, call_hash, &result);
+        }
+        // Fails only when there are no subscribers
+        self.status_updates
+            .send(StatusUpdate::new(call_hash, Some(result)))
+            .ok();
+    }
+
+    fn is_expired(&self, status: &ProofStatus, now: Instant) -> bool {
+        status
+            .finished_at
+            .is_some_and(|finished_at| self.retention.is_expired(finished_at, now))
+    }
+
+    /// Evicts finished proofs past their TTL and then least recently used ones
+    /// above `max_entries`. Unfinished calls are never evicted.
+    fn evict_finished(&self, now: Instant) {
+        let mut evicted = vec![];
+        self.statuses.retain(|call_hash, status| {
+            let expired = self.is_expired(status, now);
+            if expired {
+                evicted.push(*call_hash);
+            }
+            !expired
+        });
+
+        let mut finished: Vec<_> = self
+            .statuses
+            .iter()
+            .filter(|status| status.finished_at.is_some())
+            .map(|status| (*status.key(), status.last_used))
+            .collect();
+        let excess = finished.len().saturating_sub(self.retention.max_entries);
+        if excess > 0 {
+            finished.sort_unstable_by_key(|(_, last_used)| *last_used);
+            for (call_hash, _) in finished.into_iter().take(excess) {
+                if self.statuses.remove(&call_hash).is_some() {
+                    evicted.push(call_hash);
+                }
+            }
+        }
+
+        self.forget(evicted);
+    }
+
+    /// Removes already evicted calls from the store and remembers them as evicted.
+    /// Subscribers are notified, so that they don't wait for updates that will never come.
+    fn forget(&self, call_hashes: impl IntoIterator<Item = CallHash>) {
+        let mut evicted = self.evicted.lock();
+        for call_hash in call_hashes {
+            if let Err(err) = self.store.remove_job(call_hash) {
+                error!("Failed to remove job {call_hash} from store: {err}");
+            }
+            evicted.insert(call_hash);
+            // Fails only when there are no subscribers
+            self.status_updates
+                .send(StatusUpdate::new(call_hash, None))
+                .ok();
+        }
+    }
+}
 
 pub type State = Arc<Proofs>;
 
@@ -66,4 +340,276 @@ impl RpcServer for State {
             .expect("params should be extracted in the handler");
         v_versions::v_versions(&params.config)
     }
+
+    async fn v_cancel(&self, extensions: &Extensions, hash: CallHash) -> VCancelResult<()> {
+        let params = extensions
+            .get::<Params>()
+            .expect("params should be extracted in the handler");
+        v_cancel::v_cancel(self, hash, params.token.as_ref())
+    }
+
+    async fn v_purge_proof(
+        &self,
+        extensions: &Extensions,
+        hash: CallHash,
+    ) -> VPurgeProofResult<bool> {
+        let params = extensions
+            .get::<Params>()
+            .expect("params should be extracted in the handler");
+        v_purge_proof::v_purge_proof(self, hash, params.admin)
+    }
+
+    async fn v_subscribe_proof_status(
+        &self,
+        pending: PendingSubscriptionSink,
+        hash: CallHash,
+    ) -> SubscriptionResult {
+        v_subscribe_proof_status::v_subscribe_proof_status(self, pending, hash).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{thread, time::Duration};
+
+    use alloy_primitives::B256;
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::proof::{
+        Error as ProofError,
+        store::{MdbxJobStore, NoOpJobStore},
+    };
+
+    fn job() -> Job {
+        Job {
+            call: Call::new("0x7Ad53bbA1004e46dd456316912D55dBc5D311a03", "0x0000", 1),
+            context: CallContext {
+                chain_id: 1,
+                webhook_url: None,
+            },
+            start_block_number: 2,
+            owner: None,
+        }
+    }
+
+    fn proofs(dir: &TempDir) -> Proofs {
+        Proofs::new(
+            Box::new(MdbxJobStore::open(dir.path()).unwrap()),
+            QueueConfig::default(),
+            RetentionConfig::default(),
+            None,
+        )
+    }
+
+    fn hash(byte: u8) -> CallHash {
+        B256::repeat_byte(byte).into()
+    }
+
+    fn failed() -> ProofState {
+        ProofState::ProvingError(ProofError::Restored("failed".into()).into())
+    }
+
+    #[test]
+    fn restores_finished_and_unfinished_jobs() {
+        let dir = TempDir::new().unwrap();
+        let finished: CallHash = B256::repeat_byte(1).into();
+        let unfinished: CallHash = B256::repeat_byte(2).into();
+        {
+            let proofs = proofs(&dir);
+            assert!(proofs.insert_job(finished, &job()).unwrap());
+            assert!(proofs.insert_job(unfinished, &job()).unwrap());
+            assert!(!proofs.insert_job(unfinished, &job()).unwrap());
+            proofs.set_state(unfinished, ProofState::ProvingPending);
+            proofs.set_state(
+                finished,
+                ProofState::ProvingError(ProofError::Restored("failed".into()).into()),
+            );
+        }
+
+        let proofs = proofs(&dir);
+        let unfinished_jobs = proofs.restore().unwrap();
+
+        assert_eq!(unfinished_jobs.len(), 1);
+        assert_eq!(unfinished_jobs[0].0, unfinished);
+        assert!(matches!(proofs.get(&unfinished).unwrap().state, ProofState::Queued));
+        assert_eq!(proofs.queue().position(&unfinished), Some(0));
+        let finished = proofs.get(&finished).unwrap();
+        assert!(matches!(finished.state, ProofState::ProvingError(..)));
+        assert_eq!(finished.state.err().unwrap().to_string(), "failed");
+    }
+
+    #[test]
+    fn rejects_jobs_when_queue_is_full() {
+        let proofs = Proofs::new(
+            Box::new(NoOpJobStore),
+            QueueConfig::new(1, 1, 1),
+            RetentionConfig::default(),
+            None,
+        );
+        proofs
+            .insert_job(B256::repeat_byte(1).into(), &job())
+            .unwrap();
+
+        assert!(
+            proofs
+                .insert_job(B256::repeat_byte(2).into(), &job())
+                .is_err()
+        );
+        assert!(proofs.get(&B256::repeat_byte(2).into()).is_none());
+    }
+
+    #[test]
+    fn expires_finished_proofs() {
+        let retention = RetentionConfig::new(Duration::ZERO, 10);
+        let proofs = Proofs::new(Box::new(NoOpJobStore), QueueConfig::default(), retention, None);
+        proofs.insert_job(hash(1), &job()).unwrap();
+        proofs.insert_job(hash(2), &job()).unwrap();
+        proofs.set_state(hash(1), failed());
+
+        assert!(proofs.get(&hash(1)).is_none());
+        assert!(proofs.is_evicted(&hash(1)));
+        assert!(proofs.get(&hash(2)).is_some());
+        assert!(!proofs.is_evicted(&hash(2)));
+    }
+
+    #[test]
+    fn evicts_least_recently_used_proofs() {
+        let retention = RetentionConfig::new(Duration::from_secs(3600), 2);
+        let proofs = Proofs::new(Box::new(NoOpJobStore), QueueConfig::default(), retention, None);
+        for byte in 1..=3 {
+            proofs.insert_job(hash(byte), &job()).unwrap();
+        }
+        for byte in [1, 2] {
+            proofs.set_state(hash(byte), failed());
+            thread::sleep(Duration::from_millis(1));
+        }
+        proofs.get(&hash(1));
+        thread::sleep(Duration::from_millis(1));
+        proofs.set_state(hash(3), failed());
+
+        assert!(proofs.get(&hash(1)).is_some());
+        assert!(proofs.is_evicted(&hash(2)));
+        assert!(proofs.get(&hash(3)).is_some());
+    }
+
+    #[test]
+    fn purges_proofs() {
+        let dir = TempDir::new().unwrap();
+        {
+            let proofs = proofs(&dir);
+            proofs.insert_job(hash(1), &job()).unwrap();
+
+            assert!(proofs.purge(hash(1)));
+            assert!(!proofs.purge(hash(1)));
+            assert!(proofs.get(&hash(1)).is_none());
+            assert!(proofs.is_evicted(&hash(1)));
+            assert_eq!(proofs.queue().position(&hash(1)), None);
+        }
+
+        assert!(proofs(&dir).restore().unwrap().is_empty());
+    }
+
+    #[test]
+    fn cancels_unfinished_jobs_of_token_holder() {
+        let proofs = Proofs::new(
+            Box::new(NoOpJobStore),
+            QueueConfig::default(),
+            RetentionConfig::default(),
+            None,
+        );
+        let job = Job {
+            owner: Some(Owner::of(&Token::new("owner".into()))),
+            ..job()
+        };
+        proofs.insert_job(hash(1), &job).unwrap();
+        let cancellation = proofs.cancellation(&hash(1)).unwrap();
+
+        let other = Token::new("other".into());
+        assert!(matches!(proofs.cancel(hash(1), Some(&other)), Err(VCancelError::NotOwner)));
+        assert!(matches!(proofs.cancel(hash(1), None), Err(VCancelError::NotOwner)));
+        assert!(!cancellation.is_cancelled());
+
+        proofs
+            .cancel(hash(1), Some(&Token::new("owner".into())))
+            .unwrap();
+        assert!(cancellation.is_cancelled());
+    }
+
+    #[test]
+    fn finished_jobs_leave_queue() {
+        let dir = TempDir::new().unwrap();
+        proofs(&dir).insert_job(hash(1), &job()).unwrap();
+
+        let proofs = proofs(&dir);
+        proofs.restore().unwrap();
+        proofs.set_state(hash(1), failed());
+
+        assert_eq!(proofs.queue().position(&hash(1)), None);
+    }
+
+    #[test]
+    fn does_not_cancel_finished_jobs() {
+        let proofs = Proofs::new(
+            Box::new(NoOpJobStore),
+            QueueConfig::default(),
+            RetentionConfig::default(),
+            None,
+        );
+        proofs.insert_job(hash(1), &job()).unwrap();
+        proofs.set_state(hash(1), failed());
+
+        assert!(matches!(proofs.cancel(hash(1), None), Err(VCancelError::AlreadyFinished(..))));
+        assert!(matches!(proofs.cancel(hash(2), None), Err(VCancelError::HashNotFound(..))));
+    }
+
+    #[test]
+    fn restores_cancelled_jobs() {
+        let dir = TempDir::new().unwrap();
+        {
+            let proofs = proofs(&dir);
+            proofs.insert_job(hash(1), &job()).unwrap();
+            proofs.set_state(hash(1), ProofState::Cancelled);
+        }
+
+        let proofs = proofs(&dir);
+        assert!(proofs.restore().unwrap().is_empty());
+        assert!(matches!(proofs.get(&hash(1)).unwrap().state, ProofState::Cancelled));
+    }
+
+    #[test]
+    fn broadcasts_status_updates() {
+        let proofs = Proofs::new(
+            Box::new(NoOpJobStore),
+            QueueConfig::default(),
+            RetentionConfig::default(),
+            None,
+        );
+        proofs.insert_job(hash(1), &job()).unwrap();
+        let mut updates = proofs.subscribe();
+        proofs.set_state(hash(1), ProofState::PreflightPending);
+        proofs.set_state(hash(1), failed());
+
+        let update = updates.try_recv().unwrap();
+        assert_eq!(update.call_hash, hash(1));
+        assert!(!update.result.unwrap().is_finished());
+        assert!(updates.try_recv().unwrap().result.unwrap().is_finished());
+    }
+
+    #[test]
+    fn broadcasts_removal_of_purged_calls() {
+        let proofs = Proofs::new(
+            Box::new(NoOpJobStore),
+            QueueConfig::default(),
+            RetentionConfig::default(),
+            None,
+        );
+        proofs.insert_job(hash(1), &job()).unwrap();
+        let mut updates = proofs.subscribe();
+        proofs.purge(hash(1));
+
+        let update = updates.try_recv().unwrap();
+        assert_eq!(update.call_hash, hash(1));
+        assert!(update.result.is_none());
+    }
 }
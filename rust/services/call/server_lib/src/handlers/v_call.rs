@@ -1,15 +1,17 @@
-use std::sync::Arc;
-
-use alloy_primitives::ChainId;
+use alloy_primitives::{BlockNumber, ChainId};
 use call_host::{BuilderError, Call as EvmCall, Host};
 use provider::Address;
-use tracing::{Instrument, info, info_span};
+use tracing::{Instrument, error, info, info_span};
 use types::{Call, CallContext, CallHash, Result as VCallResult};
 
 use super::{Params, State};
 use crate::{
     Config, gas_meter,
-    proof::{self, Status as ProofStatus},
+    proof::{
+        self, Error as ProofError,
+        state::State as ProofState,
+        store::{Job, Owner},
+    },
 };
 
 pub mod types;
@@ -27,42 +29,86 @@ pub async fn v_call(
         config,
         token,
         req_id,
+        ..
     } = params;
 
-    let vgas_limit = call.vgas_limit;
-    let evm_call: EvmCall = call.parse_and_validate(config.max_calldata_size, EVM_GAS_LIMIT)?;
+    let evm_call: EvmCall = call
+        .clone()
+        .parse_and_validate(config.max_calldata_size, EVM_GAS_LIMIT)?;
+    context.validate_webhook_url(config.webhook_secret.is_some())?;
 
     let host = build_host(&config, context.chain_id, evm_call.to).await?;
-    let call_hash = (&host.start_execution_location(), &evm_call).into();
+    let start_exec_location = host.start_execution_location();
+    let call_hash = (&start_exec_location, &evm_call).into();
 
     info!(hash = tracing::field::display(call_hash), "Call");
 
-    let gas_meter_client = gas_meter::init(config.gas_meter_config.clone(), call_hash, token);
-
-    let mut found_existing = true;
-    state.entry(call_hash).or_insert_with(|| {
-        found_existing = false;
-        ProofStatus::default()
-    });
-
-    if !found_existing {
+    let job = Job {
+        call,
+        context,
+        start_block_number: start_exec_location.block_number,
+        owner: token.as_ref().map(Owner::of),
+    };
+    if state.insert_job(call_hash, &job)? {
+        let vgas_limit = job.call.vgas_limit;
+        let gas_meter_client = gas_meter::init(config.gas_meter_config.clone(), call_hash, token);
         tokio::spawn(async move {
             let span = info_span!("http", id = req_id.to_string());
-            proof::generator::Generator::new(
-                gas_meter_client,
-                vgas_limit,
-                Arc::clone(&state),
-                call_hash,
-            )
-            .run(host, evm_call)
-            .instrument(span)
-            .await;
+            proof::generator::Generator::new(gas_meter_client, vgas_limit, state, call_hash)
+                .run(host, evm_call)
+                .instrument(span)
+                .await;
         });
     }
 
     Ok(call_hash)
 }
 
+/// Restarts a job interrupted by a server restart. The host is built at the original
+/// start block, so that the call hash stays the same. Calls made with a JWT token fail if gas
+/// is metered, as the token is not persisted.
+pub fn resume(state: State, config: &Config, call_hash: CallHash, job: Job) {
+    info!(hash = tracing::field::display(call_hash), "Resuming interrupted call");
+
+    let Job {
+        call,
+        context,
+        start_block_number,
+        owner,
+    } = job;
+    let vgas_limit = call.vgas_limit;
+
+    if owner.is_some() && config.gas_meter_config.is_some() {
+        error!("Failed to resume call {call_hash}: the JWT token is not persisted");
+        state.set_state(
+            call_hash,
+            ProofState::AllocateGasError(ProofError::ResumeUnauthenticated.into()),
+        );
+        return;
+    }
+
+    let host_and_call = call
+        .parse_and_validate(config.max_calldata_size, EVM_GAS_LIMIT)
+        .and_then(|evm_call| {
+            let host = build_host_at(config, context.chain_id, start_block_number)?;
+            Ok((host, evm_call))
+        });
+    let (host, evm_call) = match host_and_call {
+        Ok(host_and_call) => host_and_call,
+        Err(err) => {
+            error!("Failed to resume call {call_hash}: {err}");
+            state.set_state(call_hash, ProofState::PreflightError(ProofError::Resume(err).into()));
+            return;
+        }
+    };
+
+    let gas_meter_client = gas_meter::init(config.gas_meter_config.clone(), call_hash, None);
+    tokio::spawn(
+        proof::generator::Generator::new(gas_meter_client, vgas_limit, state, call_hash)
+            .run(host, evm_call),
+    );
+}
+
 async fn build_host(
     config: &Config,
     chain_id: ChainId,
@@ -78,3 +124,18 @@ async fn build_host(
         .build(config.into())?;
     Ok(host)
 }
+
+fn build_host_at(
+    config: &Config,
+    chain_id: ChainId,
+    start_block_number: BlockNumber,
+) -> std::result::Result<Host, BuilderError> {
+    let host = Host::builder()
+        .with_rpc_urls(&config.rpc_urls)
+        .with_chain_guest_id(config.chain_guest_id())
+        .with_chain_client_config(config.chain_client_config.clone())?
+        .with_start_chain_id(chain_id)?
+        .with_start_block_number(start_block_number)
+        .build(config.into())?;
+    Ok(host)
+}
@@ -5,13 +5,13 @@ use call_common::ExecutionLocation;
 use call_engine::Call as EvmCall;
 use call_host::BuilderError;
 use common::Hashable;
-use derive_more::From;
+use derive_more::{Deref, From};
 use derive_new::new;
 use jsonrpsee::types::error::{self as jrpcerror, ErrorObjectOwned};
 use serde::{Deserialize, Serialize};
 use server_utils::{FieldValidationError, parse_address_field, parse_hex_field};
 
-use crate::gas_meter::Error as GasMeterError;
+use crate::{gas_meter::Error as GasMeterError, queue::QueueFull, webhook::is_allowed_host};
 
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -23,18 +23,31 @@ pub enum Error {
     GasMeter(#[from] GasMeterError),
     #[error("Host builder: {0}")]
     HostBuilder(#[from] BuilderError),
+    #[error(transparent)]
+    QueueFull(#[from] QueueFull),
+    #[error("Invalid webhook url: {0}")]
+    InvalidWebhookUrl(String),
+    #[error("Webhooks are not enabled on this server")]
+    WebhooksDisabled,
 }
 
 impl From<Error> for ErrorObjectOwned {
     fn from(error: Error) -> Self {
         match error {
-            Error::FieldValidation(..) => ErrorObjectOwned::owned::<()>(
-                jrpcerror::INVALID_PARAMS_CODE,
+            Error::FieldValidation(..) | Error::InvalidWebhookUrl(..) | Error::WebhooksDisabled => {
+                ErrorObjectOwned::owned::<()>(
+                    jrpcerror::INVALID_PARAMS_CODE,
+                    error.to_string(),
+                    None,
+                )
+            }
+            Error::HostBuilder(..) | Error::GasMeter(..) => ErrorObjectOwned::owned::<()>(
+                jrpcerror::INTERNAL_ERROR_CODE,
                 error.to_string(),
                 None,
             ),
-            Error::HostBuilder(..) | Error::GasMeter(..) => ErrorObjectOwned::owned::<()>(
-                jrpcerror::INTERNAL_ERROR_CODE,
+            Error::QueueFull(..) => ErrorObjectOwned::owned::<()>(
+                jrpcerror::SERVER_IS_BUSY_CODE,
                 error.to_string(),
                 None,
             ),
@@ -42,7 +55,7 @@ impl From<Error> for ErrorObjectOwned {
     }
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug)]
 #[serde(deny_unknown_fields)]
 pub struct Call {
     pub to: String,
@@ -88,14 +101,34 @@ const fn mainnet_chain_id() -> ChainId {
     Chain::mainnet().id()
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug)]
 #[serde(deny_unknown_fields)]
 pub struct CallContext {
     #[serde(default = "mainnet_chain_id")]
     pub chain_id: ChainId,
+    /// Url notified with a signed POST request once the proof generation finishes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_url: Option<String>,
+}
+
+impl CallContext {
+    pub fn validate_webhook_url(&self, webhooks_enabled: bool) -> Result<()> {
+        let Some(webhook_url) = &self.webhook_url else {
+            return Ok(());
+        };
+        if !webhooks_enabled {
+            return Err(Error::WebhooksDisabled);
+        }
+        let url = reqwest::Url::parse(webhook_url)
+            .map_err(|_| Error::InvalidWebhookUrl(webhook_url.clone()))?;
+        if !matches!(url.scheme(), "http" | "https") || !is_allowed_host(&url) {
+            return Err(Error::InvalidWebhookUrl(webhook_url.clone()));
+        }
+        Ok(())
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, From, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, From, Deref, Copy, Clone, Hash, PartialEq, Eq)]
 pub struct CallHash(B256);
 
 impl std::fmt::Display for CallHash {
@@ -168,4 +201,36 @@ mod test {
             Err(Error::FieldValidation(err)) if err.to_string() == "`data` is too long `1` > `0`"
         ));
     }
+
+    fn context(webhook_url: &str) -> CallContext {
+        CallContext {
+            chain_id: 1,
+            webhook_url: Some(webhook_url.to_string()),
+        }
+    }
+
+    #[test]
+    fn webhook_url() {
+        assert!(
+            context("https://example.com/hook")
+                .validate_webhook_url(true)
+                .is_ok()
+        );
+        assert!(matches!(
+            context("https://example.com/hook").validate_webhook_url(false),
+            Err(Error::WebhooksDisabled)
+        ));
+        assert!(matches!(
+            context("ftp://example.com").validate_webhook_url(true),
+            Err(Error::InvalidWebhookUrl(..))
+        ));
+        assert!(matches!(
+            context("not a url").validate_webhook_url(true),
+            Err(Error::InvalidWebhookUrl(..))
+        ));
+        assert!(matches!(
+            context("http://169.254.169.254/latest/meta-data").validate_webhook_url(true),
+            Err(Error::InvalidWebhookUrl(..))
+        ));
+    }
 }
@@ -0,0 +1,16 @@
+use server_utils::jwt::axum::Token;
+use tracing::{info, instrument};
+use types::Result;
+
+use super::Proofs;
+use crate::v_call::CallHash;
+
+pub mod types;
+
+/// Requests cancellation. The proof generation is stopped (and unused vgas refunded)
+/// at the next stage boundary, after which the call is in the `cancelled` state.
+#[instrument(name = "cancel", skip_all, fields(hash = %hash))]
+pub fn v_cancel(proofs: &Proofs, hash: CallHash, token: Option<&Token>) -> Result<()> {
+    info!("Cancelling proof generation");
+    proofs.cancel(hash, token)
+}
@@ -0,0 +1,29 @@
+use jsonrpsee::types::error::{self as jrpcerror, ErrorObjectOwned};
+
+use crate::v_call::CallHash;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Hash not found: {0}")]
+    HashNotFound(CallHash),
+    #[error("Call can only be cancelled with the token it was made with")]
+    NotOwner,
+    #[error("Proof generation already finished: {0}")]
+    AlreadyFinished(CallHash),
+}
+
+impl From<Error> for ErrorObjectOwned {
+    fn from(error: Error) -> Self {
+        match error {
+            Error::HashNotFound(..) | Error::NotOwner | Error::AlreadyFinished(..) => {
+                ErrorObjectOwned::owned::<()>(
+                    jrpcerror::INVALID_REQUEST_CODE,
+                    error.to_string(),
+                    None,
+                )
+            }
+        }
+    }
+}
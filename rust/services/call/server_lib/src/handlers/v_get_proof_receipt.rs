@@ -1,7 +1,7 @@
 use std::ops::Deref;
 
 use tracing::{info, instrument};
-use types::{CallResult, Error, Result};
+use types::{CallResult, Error, Result, State};
 
 use super::Proofs;
 use crate::v_call::CallHash;
@@ -11,9 +11,19 @@ pub mod types;
 #[instrument(name = "proof", skip_all, fields(hash = %hash))]
 pub fn v_get_proof_receipt(proofs: &Proofs, hash: CallHash) -> Result<CallResult> {
     info!("Getting proof receipt");
-    Ok(proofs
+    let mut result: CallResult = proofs
         .get(&hash)
-        .ok_or(Error::HashNotFound(hash))?
+        .ok_or_else(|| {
+            if proofs.is_evicted(&hash) {
+                Error::Expired(hash)
+            } else {
+                Error::HashNotFound(hash)
+            }
+        })?
         .deref()
-        .into())
+        .into();
+    if result.state == State::Queued {
+        result.queue_position = proofs.queue().position(&hash);
+    }
+    Ok(result)
 }
@@ -17,6 +17,7 @@ pub enum State {
     EstimatingVgas,
     Proving,
     Done,
+    Cancelled,
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -25,12 +26,14 @@ pub type Result<T> = std::result::Result<T, Error>;
 pub enum Error {
     #[error("Hash not found: {0}")]
     HashNotFound(CallHash),
+    #[error("Proof expired: {0}")]
+    Expired(CallHash),
 }
 
 impl From<Error> for ErrorObjectOwned {
     fn from(error: Error) -> Self {
         match error {
-            Error::HashNotFound(..) => ErrorObjectOwned::owned::<()>(
+            Error::HashNotFound(..) | Error::Expired(..) => ErrorObjectOwned::owned::<()>(
                 jrpcerror::INVALID_REQUEST_CODE,
                 error.to_string(),
                 None,
@@ -56,6 +59,7 @@ impl From<&ProofState> for State {
             }
             ProofState::ProvingPending | ProofState::ProvingError(..) => Self::Proving,
             ProofState::Done(..) => Self::Done,
+            ProofState::Cancelled => Self::Cancelled,
         }
     }
 }
@@ -68,6 +72,15 @@ pub struct CallResult {
     pub metrics: Metrics,
     pub data: Option<RawData>,
     pub error: Option<String>,
+    /// Number of calls waiting ahead of this one. Only set in the `Queued` state
+    pub queue_position: Option<usize>,
+}
+
+impl CallResult {
+    /// Whether the proof generation is done or failed
+    pub fn is_finished(&self) -> bool {
+        self.state == State::Done || self.status == 0
+    }
 }
 
 impl From<&ProofStatus> for CallResult {
@@ -77,7 +90,11 @@ impl From<&ProofStatus> for CallResult {
             status: if value.state.is_err() { 0 } else { 1 },
             metrics: value.metrics,
             data: value.state.data().cloned(),
-            error: value.state.err().map(ProofError::to_string),
+            error: match &value.state {
+                ProofState::Cancelled => Some(ProofError::Cancelled.to_string()),
+                state => state.err().map(ProofError::to_string),
+            },
+            queue_position: None,
         }
     }
 }
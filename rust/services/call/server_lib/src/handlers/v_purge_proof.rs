@@ -0,0 +1,17 @@
+use tracing::{info, instrument};
+use types::{Error, Result};
+
+use super::Proofs;
+use crate::v_call::CallHash;
+
+pub mod types;
+
+/// Admin-only. Returns `false` if there was no proof for the given hash.
+#[instrument(name = "purge", skip_all, fields(hash = %hash))]
+pub fn v_purge_proof(proofs: &Proofs, hash: CallHash, admin: bool) -> Result<bool> {
+    if !admin {
+        return Err(Error::Unauthorized);
+    }
+    info!("Purging proof");
+    Ok(proofs.purge(hash))
+}
@@ -0,0 +1,21 @@
+use jsonrpsee::types::error::{self as jrpcerror, ErrorObjectOwned};
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Missing or invalid admin API key")]
+    Unauthorized,
+}
+
+impl From<Error> for ErrorObjectOwned {
+    fn from(error: Error) -> Self {
+        match error {
+            Error::Unauthorized => ErrorObjectOwned::owned::<()>(
+                jrpcerror::INVALID_REQUEST_CODE,
+                error.to_string(),
+                None,
+            ),
+        }
+    }
+}
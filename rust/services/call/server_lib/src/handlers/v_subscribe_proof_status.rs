@@ -0,0 +1,66 @@
+use jsonrpsee::{
+    PendingSubscriptionSink, SubscriptionMessage, SubscriptionSink,
+    core::{StringError, SubscriptionResult},
+};
+use tokio::sync::broadcast::error::RecvError;
+use tracing::{info, instrument};
+
+use super::{
+    Proofs,
+    v_get_proof_receipt::{
+        types::{CallResult, Error},
+        v_get_proof_receipt,
+    },
+};
+use crate::v_call::CallHash;
+
+/// Sends the current status of the call and then every change of it, until the proof
+/// generation finishes or the client unsubscribes. If the call is purged or evicted in the
+/// meantime, the subscription is closed with an `Expired` error.
+#[instrument(name = "subscription", skip_all, fields(hash = %hash))]
+pub async fn v_subscribe_proof_status(
+    proofs: &Proofs,
+    pending: PendingSubscriptionSink,
+    hash: CallHash,
+) -> SubscriptionResult {
+    // Subscribe before reading the current status, so that no change is missed
+    let mut updates = proofs.subscribe();
+    let result = match v_get_proof_receipt(proofs, hash) {
+        Ok(result) => result,
+        Err(err) => {
+            pending.reject(err).await;
+            return Ok(());
+        }
+    };
+    let sink = pending.accept().await?;
+    info!("Subscribed to proof status");
+    if send(&sink, &result).await? {
+        return Ok(());
+    }
+
+    loop {
+        let update = tokio::select! {
+            update = updates.recv() => update,
+            () = sink.closed() => return Ok(()),
+        };
+        let result = match update {
+            Ok(update) if update.call_hash == hash => match update.result {
+                Some(result) => result,
+                None => return Err(Error::Expired(hash).into()),
+            },
+            Ok(_) => continue,
+            // Some updates were dropped, so the current status is sent instead
+            Err(RecvError::Lagged(..)) => v_get_proof_receipt(proofs, hash)?,
+            Err(RecvError::Closed) => return Ok(()),
+        };
+        if send(&sink, &result).await? {
+            return Ok(());
+        }
+    }
+}
+
+/// Returns whether the proof generation is finished, i.e. no more updates will follow.
+async fn send(sink: &SubscriptionSink, result: &CallResult) -> Result<bool, StringError> {
+    sink.send(SubscriptionMessage::from_json(result)?).await?;
+    Ok(result.is_finished())
+}
@@ -2,7 +2,10 @@ pub mod cli;
 pub mod config;
 pub mod gas_meter;
 pub mod jwt;
+pub mod queue;
+pub mod retention;
 pub mod server;
+pub mod webhook;
 
 mod handlers;
 mod metrics;
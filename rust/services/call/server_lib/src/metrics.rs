@@ -1,6 +1,6 @@
 use std::{num::TryFromIntError, time::Duration};
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -8,14 +8,14 @@ pub enum Error {
     TryFromInt(#[from] TryFromIntError),
 }
 
-#[derive(Copy, Clone, Serialize, Default)]
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Default)]
 pub struct Metrics {
     pub gas: u64,
     pub cycles: u64,
     pub times: Times,
 }
 
-#[derive(Copy, Clone, Serialize, Default)]
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Default)]
 pub struct Times {
     pub preflight: u64,
     pub proving: u64,
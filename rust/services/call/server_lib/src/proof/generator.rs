@@ -1,6 +1,7 @@
 use call_common::Metadata;
 use call_engine::{Call as EvmCall, CallGuestId};
 use call_host::{CycleEstimator, Host, PreflightResult, Prover, ProvingInput, Risc0CycleEstimator};
+use tokio_util::sync::CancellationToken;
 use tracing::{error, info, instrument, warn};
 
 use crate::{
@@ -9,9 +10,7 @@ use crate::{
     metrics::Metrics,
     preflight::{self},
     proof::{
-        Error, Vgas, allocate_error_to_state, preflight_error_to_state, set_metrics,
-        state::{State, set_state},
-        to_cycles,
+        Error, Vgas, allocate_error_to_state, preflight_error_to_state, state::State, to_cycles,
     },
     proving::{self, RawData},
     v_call::CallHash,
@@ -23,6 +22,7 @@ pub struct Generator {
     app_state: AppState,
     call_hash: CallHash,
     metrics: Metrics,
+    cancellation: CancellationToken,
 }
 
 impl Generator {
@@ -32,12 +32,14 @@ impl Generator {
         app_state: AppState,
         call_hash: CallHash,
     ) -> Self {
+        let cancellation = app_state.cancellation(&call_hash).unwrap_or_default();
         Self {
             gas_meter_client,
             vgas_limit,
             app_state,
             call_hash,
             metrics: Metrics::default(),
+            cancellation,
         }
     }
 
@@ -59,33 +61,78 @@ impl Generator {
         let prover = host.prover();
         let call_guest_id = host.call_guest_id();
 
+        let queue = self.app_state.queue();
+        let preflight_permit = tokio::select! {
+            permit = queue.preflight_permit(self.call_hash) => permit,
+            () = self.cancellation.cancelled() => {
+                queue.remove(&self.call_hash);
+                return self.stop_if_cancelled(&[]).await;
+            }
+        };
         self.allocate_vgas().await?;
+        self.stop_if_cancelled(&[(ComputationStage::Preflight, 0), (ComputationStage::Proving, 0)])
+            .await?;
         let preflight_result = self.preflight(host, evm_call).await?;
         let estimated_vgas = self.estimate_cycles(&preflight_result)?;
+        self.stop_if_cancelled(&[
+            (ComputationStage::Preflight, estimated_vgas.value),
+            (ComputationStage::Proving, 0),
+        ])
+        .await?;
         self.preflight_refund(estimated_vgas.value).await?;
         self.send_metadata(preflight_result.metadata.clone())
             .await?;
         self.validate_vgas_limit(estimated_vgas)?;
+        let _proving_permit = tokio::select! {
+            permit = self.app_state.queue().proving_permit() => permit,
+            () = self.cancellation.cancelled() => {
+                return self.stop_if_cancelled(&[(ComputationStage::Proving, 0)]).await;
+            }
+        };
+        drop(preflight_permit);
         let raw_data = self
             .proving(preflight_result, &prover, call_guest_id)
             .await?;
+        // Proving itself can't be interrupted, so its result is discarded instead, but still paid for
+        self.stop_if_cancelled(&[(ComputationStage::Proving, estimated_vgas.value)])
+            .await?;
         self.proving_refund(estimated_vgas.value).await?;
         self.mark_completed(raw_data);
 
         Ok(())
     }
 
+    /// Stops the pipeline if the call was cancelled. Allocated vgas is refunded for each of the
+    /// given stages that won't complete, except for the vgas already used in it: 0 if the stage
+    /// hasn't started yet. Nothing is refunded before the allocation.
+    async fn stop_if_cancelled(&self, refunds: &[(ComputationStage, u64)]) -> Result<(), ()> {
+        if !self.cancellation.is_cancelled() {
+            return Ok(());
+        }
+        info!("Proof generation cancelled");
+        for &(stage, vgas_used) in refunds {
+            if let Err(err) = self.gas_meter_client.refund(stage, vgas_used).await {
+                error!("Refund after cancellation failed with error: {err}");
+            }
+        }
+        self.app_state
+            .set_state_with_metrics(self.call_hash, State::Cancelled, self.metrics);
+        Err(())
+    }
+
     async fn allocate_vgas(&self) -> Result<(), ()> {
-        set_state(&self.app_state, self.call_hash, State::AllocateGasPending);
+        self.app_state
+            .set_state(self.call_hash, State::AllocateGasPending);
 
         match self.gas_meter_client.allocate(self.vgas_limit).await {
             Ok(()) => {
-                set_state(&self.app_state, self.call_hash, State::PreflightPending);
+                self.app_state
+                    .set_state(self.call_hash, State::PreflightPending);
                 Ok(())
             }
             Err(err) => {
                 let state = allocate_error_to_state(err, self.vgas_limit);
-                set_state(&self.app_state, self.call_hash, state);
+                self.app_state.set_state(self.call_hash, state);
                 Err(())
             }
         }
@@ -95,15 +142,17 @@ impl Generator {
         let evm_gas_limit = evm_call.gas_limit;
         match preflight::await_preflight(host, evm_call, &mut self.metrics).await {
             Ok(res) => {
-                let entry =
-                    set_state(&self.app_state, self.call_hash, State::EstimatingCyclesPending);
-                set_metrics(entry, self.metrics);
+                self.app_state.set_state_with_metrics(
+                    self.call_hash,
+                    State::EstimatingCyclesPending,
+                    self.metrics,
+                );
                 Ok(res)
             }
             Err(err) => {
                 let state = preflight_error_to_state(err, evm_gas_limit);
-                let entry = set_state(&self.app_state, self.call_hash, state);
-                set_metrics(entry, self.metrics);
+                self.app_state
+                    .set_state_with_metrics(self.call_hash, state, self.metrics);
                 Err(())
             }
         }
@@ -121,12 +170,11 @@ impl Generator {
             }
             Err(err) => {
                 error!("Cycle estimation failed with error: {err}");
-                let entry = set_state(
-                    &self.app_state,
+                self.app_state.set_state_with_metrics(
                     self.call_hash,
                     State::EstimatingCyclesError(Box::new(Error::EstimatingCycles(err))),
+                    self.metrics,
                 );
-                set_metrics(entry, self.metrics);
                 None
             }
         };
@@ -156,12 +204,11 @@ impl Generator {
             }
             Err(err) => {
                 error!("Preflight refund failed with error: {err}");
-                let entry = set_state(
-                    &self.app_state,
+                self.app_state.set_state_with_metrics(
                     self.call_hash,
                     State::PreflightError(Error::AllocateGasRpc(err).into()),
+                    self.metrics,
                 );
-                set_metrics(entry, self.metrics);
                 Err(())
             }
         }
@@ -175,12 +222,11 @@ impl Generator {
             }
             Err(err) => {
                 error!("Send metadata failed with error: {err}");
-                let entry = set_state(
-                    &self.app_state,
+                self.app_state.set_state_with_metrics(
                     self.call_hash,
                     State::PreflightError(Error::AllocateGasRpc(err).into()),
+                    self.metrics,
                 );
-                set_metrics(entry, self.metrics);
                 Err(())
             }
         }
@@ -195,15 +241,14 @@ impl Generator {
                 estimated_vgas.value,
                 estimated_vgas.cycles
             );
-            let entry = set_state(
-                &self.app_state,
+            self.app_state.set_state_with_metrics(
                 self.call_hash,
                 State::EstimatingCyclesError(Box::new(Error::InsufficientVgas {
                     provided: self.vgas_limit,
                     estimated: estimated_vgas.value,
                 })),
+                self.metrics,
             );
-            set_metrics(entry, self.metrics);
             Err(())
         } else {
             Ok(())
@@ -216,7 +261,8 @@ impl Generator {
         prover: &Prover,
         call_guest_id: CallGuestId,
     ) -> Result<RawData, ()> {
-        set_state(&self.app_state, self.call_hash, State::ProvingPending);
+        self.app_state
+            .set_state(self.call_hash, State::ProvingPending);
 
         let proving_input = ProvingInput::new(preflight_result.host_output, preflight_result.input);
         match proving::await_proving(
@@ -235,9 +281,11 @@ impl Generator {
             }
             Err(err) => {
                 error!("Proving failed with error: {err}");
-                let entry =
-                    set_state(&self.app_state, self.call_hash, State::ProvingError(err.into()));
-                set_metrics(entry, self.metrics);
+                self.app_state.set_state_with_metrics(
+                    self.call_hash,
+                    State::ProvingError(err.into()),
+                    self.metrics,
+                );
                 Err(())
             }
         }
@@ -255,19 +303,174 @@ impl Generator {
             }
             Err(err) => {
                 error!("Proving refund failed with error: {err}");
-                let entry = set_state(
-                    &self.app_state,
+                self.app_state.set_state_with_metrics(
                     self.call_hash,
                     State::ProvingError(Error::AllocateGasRpc(err).into()),
+                    self.metrics,
                 );
-                set_metrics(entry, self.metrics);
                 Err(())
             }
         }
     }
 
     fn mark_completed(&self, raw_data: RawData) {
-        let entry = set_state(&self.app_state, self.call_hash, State::Done(raw_data.into()));
-        set_metrics(entry, self.metrics);
+        self.app_state.set_state_with_metrics(
+            self.call_hash,
+            State::Done(raw_data.into()),
+            self.metrics,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, sync::Arc};
+
+    use alloy_primitives::{B256, ChainId};
+    use async_trait::async_trait;
+    use call_host::Config as HostConfig;
+    use parking_lot::Mutex;
+    use risc0_zkp::core::digest::Digest;
+    use server_utils::ProofMode;
+
+    use super::*;
+    use crate::{
+        gas_meter::Error as GasMeterError,
+        handlers::Proofs,
+        proof::store::{Job, NoOpJobStore},
+        queue::Config as QueueConfig,
+        retention::Config as RetentionConfig,
+        v_call::{Call, CallContext},
+    };
+
+    #[derive(Clone, Default)]
+    struct RecordingClient {
+        refunds: Arc<Mutex<Vec<(ComputationStage, u64)>>>,
+        // Cancelled once vgas is allocated, to stop the pipeline right after the allocation
+        cancel_on_allocate: Arc<Mutex<Option<CancellationToken>>>,
+    }
+
+    #[async_trait]
+    impl GasMeterClient for RecordingClient {
+        async fn allocate(&self, _vgas_limit: u64) -> Result<(), GasMeterError> {
+            if let Some(cancellation) = self.cancel_on_allocate.lock().as_ref() {
+                cancellation.cancel();
+            }
+            Ok(())
+        }
+
+        async fn refund(
+            &self,
+            stage: ComputationStage,
+            vgas_used: u64,
+        ) -> Result<(), GasMeterError> {
+            self.refunds.lock().push((stage, vgas_used));
+            Ok(())
+        }
+
+        async fn send_metadata(&self, _metadata: Box<[Metadata]>) -> Result<(), GasMeterError> {
+            Ok(())
+        }
+
+        async fn update_cycles(&self, _cycles_used: u64) -> Result<(), GasMeterError> {
+            Ok(())
+        }
+    }
+
+    fn generator(client: &RecordingClient) -> Generator {
+        let call_hash = B256::repeat_byte(1).into();
+        let app_state = Arc::new(Proofs::new(
+            Box::new(NoOpJobStore),
+            QueueConfig::default(),
+            RetentionConfig::default(),
+            None,
+        ));
+        let job = Job {
+            call: Call::new("0x7Ad53bbA1004e46dd456316912D55dBc5D311a03", "0x0000", 1),
+            context: CallContext {
+                chain_id: 1,
+                webhook_url: None,
+            },
+            start_block_number: 2,
+            owner: None,
+        };
+        app_state.insert_job(call_hash, &job).unwrap();
+        Generator::new(Box::new(client.clone()), 1, app_state, call_hash)
+    }
+
+    // Never reaches the network, as the pipelines under test stop before the preflight
+    fn host() -> Host {
+        Host::builder()
+            .with_rpc_urls(&HashMap::<ChainId, String>::new())
+            .with_chain_guest_id(Digest::default())
+            .with_chain_client_config(None)
+            .unwrap()
+            .with_start_chain_id(1)
+            .unwrap()
+            .with_start_block_number(2)
+            .build(HostConfig {
+                proof_mode: ProofMode::Groth16.into(),
+                ..HostConfig::default()
+            })
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn continues_if_not_cancelled() {
+        let client = RecordingClient::default();
+        let generator = generator(&client);
+
+        generator
+            .stop_if_cancelled(&[(ComputationStage::Proving, 5)])
+            .await
+            .unwrap();
+
+        assert!(client.refunds.lock().is_empty());
+    }
+
+    #[tokio::test]
+    async fn charges_used_vgas_on_cancellation() {
+        let client = RecordingClient::default();
+        let generator = generator(&client);
+        generator.cancellation.cancel();
+
+        generator
+            .stop_if_cancelled(&[(ComputationStage::Proving, 5)])
+            .await
+            .unwrap_err();
+
+        assert_eq!(*client.refunds.lock(), [(ComputationStage::Proving, 5)]);
+        let status = generator.app_state.get(&generator.call_hash).unwrap();
+        assert!(matches!(status.state, State::Cancelled));
+    }
+
+    #[tokio::test]
+    async fn does_not_refund_before_allocation() {
+        let client = RecordingClient::default();
+        let generator = generator(&client);
+        generator.cancellation.cancel();
+
+        generator.stop_if_cancelled(&[]).await.unwrap_err();
+
+        assert!(client.refunds.lock().is_empty());
+    }
+
+    #[tokio::test]
+    async fn refunds_both_stages_when_cancelled_before_preflight() {
+        let client = RecordingClient::default();
+        let mut generator = generator(&client);
+        *client.cancel_on_allocate.lock() = Some(generator.cancellation.clone());
+
+        generator
+            .run_pipeline(host(), EvmCall::default())
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            *client.refunds.lock(),
+            [(ComputationStage::Preflight, 0), (ComputationStage::Proving, 0)]
+        );
+        let status = generator.app_state.get(&generator.call_hash).unwrap();
+        assert!(matches!(status.state, State::Cancelled));
     }
 }
@@ -1,5 +1,7 @@
+use std::time::Instant;
+
 use call_host::CycleEstimatorError;
-use dashmap::Entry;
+use tokio_util::sync::CancellationToken;
 use tracing::error;
 
 pub use crate::proving::RawData;
@@ -7,13 +9,14 @@ use crate::{
     gas_meter::Error as GasMeterError,
     metrics::Metrics,
     preflight::{self, Error as PreflightError},
-    proof::state::State,
+    proof::{state::State, store::Owner},
     proving::Error as ProvingError,
-    v_call::CallHash,
+    v_call::Error as VCallError,
 };
 
 pub mod generator;
 pub mod state;
+pub mod store;
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -31,6 +34,15 @@ pub enum Error {
     InsufficientVgas { provided: u64, estimated: u64 },
     #[error("Proving: {0}")]
     Proving(#[from] ProvingError),
+    #[error("Resuming interrupted call: {0}")]
+    Resume(#[from] VCallError),
+    #[error("Resuming interrupted call: the JWT token is not persisted, make the call again")]
+    ResumeUnauthenticated,
+    /// Error of a proof generation finished before the restart. Only the message is preserved.
+    #[error("{0}")]
+    Restored(String),
+    #[error("Proof generation was cancelled")]
+    Cancelled,
 }
 
 const CYCLES_PER_VGAS: u64 = 1_000_000;
@@ -39,6 +51,26 @@ const CYCLES_PER_VGAS: u64 = 1_000_000;
 pub struct Status {
     pub state: State,
     pub metrics: Metrics,
+    /// Set once the proof generation is done or failed
+    pub finished_at: Option<Instant>,
+    /// Last time the status was set or read. Used to evict least recently used proofs
+    pub last_used: Option<Instant>,
+    /// Holder of the JWT token the call was made with. Only they can cancel the call
+    pub owner: Option<Owner>,
+    /// Cancelled to stop the proof generation at the next stage boundary
+    pub cancellation: CancellationToken,
+    /// Notified once the proof generation finishes
+    pub webhook_url: Option<String>,
+}
+
+impl Status {
+    pub fn new(owner: Option<Owner>, webhook_url: Option<String>) -> Self {
+        Self {
+            owner,
+            webhook_url,
+            ..Default::default()
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -56,13 +88,6 @@ impl Vgas {
     }
 }
 
-fn set_metrics(
-    entry: Entry<'_, CallHash, Status>,
-    metrics: Metrics,
-) -> Entry<'_, CallHash, Status> {
-    entry.and_modify(|status| status.metrics = metrics)
-}
-
 fn allocate_error_to_state(err: GasMeterError, vgas_limit: u64) -> State {
     if err.is_insufficient_gas_balance() {
         return State::AllocateGasError(
@@ -1,7 +1,4 @@
-use dashmap::Entry;
-
-use super::{Error, RawData, Status};
-use crate::{handlers::State as AppState, v_call::CallHash};
+use super::{Error, RawData};
 
 #[derive(Default)]
 pub enum State {
@@ -16,6 +13,7 @@ pub enum State {
     ProvingPending,
     ProvingError(Box<Error>),
     Done(Box<RawData>),
+    Cancelled,
 }
 
 impl State {
@@ -26,6 +24,7 @@ impl State {
                 | State::PreflightError(..)
                 | State::ProvingError(..)
                 | State::EstimatingCyclesError(..)
+                | State::Cancelled
         )
     }
 
@@ -46,13 +45,3 @@ impl State {
         }
     }
 }
-
-pub fn set_state(
-    app_state: &AppState,
-    call_hash: CallHash,
-    state: State,
-) -> Entry<'_, CallHash, Status> {
-    app_state
-        .entry(call_hash)
-        .and_modify(|res| res.state = state)
-}
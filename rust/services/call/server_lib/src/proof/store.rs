@@ -0,0 +1,399 @@
+use std::{
+    path::Path,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use alloy_primitives::{B256, BlockNumber, Bytes};
+use key_value::{Database, DbError, Mdbx, ReadTx, ReadWriteTx, WriteTx};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use server_utils::jwt::axum::Token;
+use sha2::{Digest, Sha256};
+
+use super::{Error as ProofError, RawData, Status, state::State};
+use crate::{
+    metrics::Metrics,
+    v_call::{Call, CallContext, CallHash},
+};
+
+/// Calls which are not finished yet. Holds `call_hash -> Job` mapping
+const JOBS: &str = "jobs";
+
+/// Finished calls. Holds `call_hash -> FinishedJob` mapping
+const RESULTS: &str = "results";
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Database: {0}")]
+    Db(#[from] DbError),
+    #[error("Serialization: {0}")]
+    Serde(#[from] serde_json::Error),
+    #[error("Invalid call hash: {0}")]
+    InvalidCallHash(String),
+    #[error("Decoding proof: {0}")]
+    DecodingProof(#[from] alloy_sol_types::Error),
+}
+
+/// Everything needed to restart a call from scratch.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Job {
+    pub call: Call,
+    pub context: CallContext,
+    /// Block the call was started at. It's a part of the call hash, so it must be
+    /// preserved when the call is restarted.
+    pub start_block_number: BlockNumber,
+    /// Holder of the JWT token the call was made with. The token itself is never persisted,
+    /// so a resumed call can't authenticate to the gas meter on its behalf.
+    #[serde(default)]
+    pub owner: Option<Owner>,
+}
+
+/// SHA-256 digest of a JWT token. Identifies the caller without keeping a replayable token.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Owner(B256);
+
+impl Owner {
+    pub fn of(token: &Token) -> Self {
+        Self(Sha256::digest(token.as_bytes()).into())
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorStage {
+    AllocateGas,
+    Preflight,
+    EstimatingCycles,
+    Proving,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    /// ABI-encoded proof and EVM call result
+    Done(Bytes),
+    Error {
+        stage: ErrorStage,
+        message: String,
+    },
+    Cancelled,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct FinishedJob {
+    pub outcome: Outcome,
+    pub metrics: Metrics,
+    /// Unix timestamp (in seconds) of when the job finished
+    #[serde(default)]
+    pub finished_at: u64,
+}
+
+impl FinishedJob {
+    /// Returns `None` if the proof generation is still in progress.
+    pub fn from_status(status: &Status) -> Option<Self> {
+        let outcome = match &status.state {
+            State::Done(raw_data) => Outcome::Done(raw_data.abi_encode().into()),
+            State::AllocateGasError(err) => Outcome::error(ErrorStage::AllocateGas, err),
+            State::PreflightError(err) => Outcome::error(ErrorStage::Preflight, err),
+            State::EstimatingCyclesError(err) => Outcome::error(ErrorStage::EstimatingCycles, err),
+            State::ProvingError(err) => Outcome::error(ErrorStage::Proving, err),
+            State::Cancelled => Outcome::Cancelled,
+            _ => return None,
+        };
+        Some(Self {
+            outcome,
+            metrics: status.metrics,
+            finished_at: unix_timestamp(SystemTime::now()),
+        })
+    }
+
+    pub fn into_status(self) -> Result<Status, Error> {
+        let state = match self.outcome {
+            Outcome::Done(data) => State::Done(RawData::abi_decode(&data)?.into()),
+            Outcome::Error { stage, message } => {
+                let err = Box::new(ProofError::Restored(message));
+                match stage {
+                    ErrorStage::AllocateGas => State::AllocateGasError(err),
+                    ErrorStage::Preflight => State::PreflightError(err),
+                    ErrorStage::EstimatingCycles => State::EstimatingCyclesError(err),
+                    ErrorStage::Proving => State::ProvingError(err),
+                }
+            }
+            Outcome::Cancelled => State::Cancelled,
+        };
+        // Translate the wall clock time into an `Instant`, so that the job keeps its age
+        let age = unix_timestamp(SystemTime::now()).saturating_sub(self.finished_at);
+        let now = Instant::now();
+        let finished_at = now.checked_sub(Duration::from_secs(age)).unwrap_or(now);
+        Ok(Status {
+            state,
+            metrics: self.metrics,
+            finished_at: Some(finished_at),
+            last_used: Some(finished_at),
+            ..Default::default()
+        })
+    }
+}
+
+fn unix_timestamp(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+impl Outcome {
+    fn error(stage: ErrorStage, err: &ProofError) -> Self {
+        Self::Error {
+            stage,
+            message: err.to_string(),
+        }
+    }
+}
+
+/// Persists proof jobs, so that they can be restored after a restart.
+pub trait JobStore: Send + Sync {
+    fn add_job(&self, call_hash: CallHash, job: &Job) -> Result<(), Error>;
+    /// Stores the result and removes the job from unfinished ones.
+    fn finish_job(&self, call_hash: CallHash, job: &FinishedJob) -> Result<(), Error>;
+    fn unfinished_jobs(&self) -> Result<Vec<(CallHash, Job)>, Error>;
+    fn finished_jobs(&self) -> Result<Vec<(CallHash, FinishedJob)>, Error>;
+    /// Removes the job and its result, if any.
+    fn remove_job(&self, call_hash: CallHash) -> Result<(), Error>;
+}
+
+/// Default store. Jobs live only in memory and are lost on restart.
+pub struct NoOpJobStore;
+
+impl JobStore for NoOpJobStore {
+    fn add_job(&self, _call_hash: CallHash, _job: &Job) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn finish_job(&self, _call_hash: CallHash, _job: &FinishedJob) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn unfinished_jobs(&self) -> Result<Vec<(CallHash, Job)>, Error> {
+        Ok(vec![])
+    }
+
+    fn finished_jobs(&self) -> Result<Vec<(CallHash, FinishedJob)>, Error> {
+        Ok(vec![])
+    }
+
+    fn remove_job(&self, _call_hash: CallHash) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+pub struct MdbxJobStore {
+    db: RwLock<Mdbx>,
+}
+
+impl MdbxJobStore {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let mut db = Mdbx::open(path)?;
+        let mut tx = db.begin_rw()?;
+        tx.create_table(JOBS)?;
+        tx.create_table(RESULTS)?;
+        Box::new(tx).commit()?;
+        Ok(Self {
+            db: RwLock::new(db),
+        })
+    }
+
+    fn entries<T: DeserializeOwned>(&self, table: &str) -> Result<Vec<(CallHash, T)>, Error> {
+        let db = self.db.read();
+        let entries = db.begin_ro()?.entries(table)?;
+        entries
+            .into_iter()
+            .map(|(key, value)| {
+                let call_hash = B256::try_from(&*key)
+                    .map_err(|_| Error::InvalidCallHash(hex::encode(&key)))?
+                    .into();
+                Ok((call_hash, serde_json::from_slice(&value)?))
+            })
+            .collect()
+    }
+}
+
+impl JobStore for MdbxJobStore {
+    fn add_job(&self, call_hash: CallHash, job: &Job) -> Result<(), Error> {
+        let value = serde_json::to_vec(job)?;
+        let mut db = self.db.write();
+        let mut tx = db.begin_rw()?;
+        tx.upsert(JOBS, call_hash.as_slice(), &value)?;
+        Box::new(tx).commit()?;
+        Ok(())
+    }
+
+    fn finish_job(&self, call_hash: CallHash, job: &FinishedJob) -> Result<(), Error> {
+        let value = serde_json::to_vec(job)?;
+        let mut db = self.db.write();
+        let mut tx = db.begin_rw()?;
+        delete_if_exists(&mut *tx, JOBS, call_hash)?;
+        tx.upsert(RESULTS, call_hash.as_slice(), &value)?;
+        Box::new(tx).commit()?;
+        Ok(())
+    }
+
+    fn unfinished_jobs(&self) -> Result<Vec<(CallHash, Job)>, Error> {
+        self.entries(JOBS)
+    }
+
+    fn finished_jobs(&self) -> Result<Vec<(CallHash, FinishedJob)>, Error> {
+        self.entries(RESULTS)
+    }
+
+    fn remove_job(&self, call_hash: CallHash) -> Result<(), Error> {
+        let mut db = self.db.write();
+        let mut tx = db.begin_rw()?;
+        delete_if_exists(&mut *tx, JOBS, call_hash)?;
+        delete_if_exists(&mut *tx, RESULTS, call_hash)?;
+        Box::new(tx).commit()?;
+        Ok(())
+    }
+}
+
+fn delete_if_exists(
+    tx: &mut dyn ReadWriteTx,
+    table: &str,
+    call_hash: CallHash,
+) -> Result<(), Error> {
+    if tx.get(table, call_hash.as_slice())?.is_some() {
+        tx.delete(table, call_hash.as_slice())?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn hash() -> CallHash {
+        B256::repeat_byte(1).into()
+    }
+
+    fn job() -> Job {
+        Job {
+            call: Call::new("0x7Ad53bbA1004e46dd456316912D55dBc5D311a03", "0x0000", 1),
+            context: CallContext {
+                chain_id: 1,
+                webhook_url: None,
+            },
+            start_block_number: 2,
+            owner: Some(Owner::of(&Token::new("token".into()))),
+        }
+    }
+
+    fn failed_job() -> FinishedJob {
+        FinishedJob {
+            outcome: Outcome::Error {
+                stage: ErrorStage::Preflight,
+                message: "Preflight: failed".into(),
+            },
+            metrics: Metrics::default(),
+            finished_at: 0,
+        }
+    }
+
+    #[test]
+    fn empty_store() {
+        let dir = TempDir::new().unwrap();
+        let store = MdbxJobStore::open(dir.path()).unwrap();
+
+        assert!(store.unfinished_jobs().unwrap().is_empty());
+        assert!(store.finished_jobs().unwrap().is_empty());
+    }
+
+    #[test]
+    fn unfinished_job() {
+        let dir = TempDir::new().unwrap();
+        let store = MdbxJobStore::open(dir.path()).unwrap();
+        store.add_job(hash(), &job()).unwrap();
+
+        let jobs = store.unfinished_jobs().unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].0, hash());
+        assert_eq!(jobs[0].1.start_block_number, 2);
+        assert_eq!(jobs[0].1.owner, Some(Owner::of(&Token::new("token".into()))));
+    }
+
+    #[test]
+    fn does_not_persist_token() {
+        let dir = TempDir::new().unwrap();
+        let store = MdbxJobStore::open(dir.path()).unwrap();
+        store.add_job(hash(), &job()).unwrap();
+
+        let db = store.db.read();
+        let entries = db.begin_ro().unwrap().entries(JOBS).unwrap();
+        assert!(!String::from_utf8_lossy(&entries[0].1).contains("token"));
+    }
+
+    #[test]
+    fn restores_jobs_stored_with_token() {
+        let mut job = serde_json::to_value(job()).unwrap();
+        job["token"] = "token".into();
+        job.as_object_mut().unwrap().remove("owner");
+
+        let job: Job = serde_json::from_value(job).unwrap();
+        assert_eq!(job.owner, None);
+    }
+
+    #[test]
+    fn finished_job_is_no_longer_unfinished() {
+        let dir = TempDir::new().unwrap();
+        let store = MdbxJobStore::open(dir.path()).unwrap();
+        store.add_job(hash(), &job()).unwrap();
+        store.finish_job(hash(), &failed_job()).unwrap();
+
+        assert!(store.unfinished_jobs().unwrap().is_empty());
+        let jobs = store.finished_jobs().unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].1.outcome, failed_job().outcome);
+    }
+
+    #[test]
+    fn survives_reopening() {
+        let dir = TempDir::new().unwrap();
+        let store = MdbxJobStore::open(dir.path()).unwrap();
+        store.add_job(hash(), &job()).unwrap();
+        drop(store);
+
+        let store = MdbxJobStore::open(dir.path()).unwrap();
+        assert_eq!(store.unfinished_jobs().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn removed_job_is_gone() {
+        let dir = TempDir::new().unwrap();
+        let store = MdbxJobStore::open(dir.path()).unwrap();
+        store.add_job(hash(), &job()).unwrap();
+        store.finish_job(hash(), &failed_job()).unwrap();
+        store.remove_job(hash()).unwrap();
+
+        assert!(store.unfinished_jobs().unwrap().is_empty());
+        assert!(store.finished_jobs().unwrap().is_empty());
+    }
+
+    #[test]
+    fn restored_job_keeps_its_age() {
+        let finished_job = FinishedJob {
+            finished_at: unix_timestamp(SystemTime::now()) - 60,
+            ..failed_job()
+        };
+        let status = finished_job.into_status().unwrap();
+
+        assert!(status.finished_at.unwrap().elapsed() >= Duration::from_secs(60));
+    }
+
+    #[test]
+    fn restored_error_keeps_stage_and_message() {
+        let status = failed_job().into_status().unwrap();
+
+        assert!(matches!(status.state, State::PreflightError(..)));
+        assert_eq!(status.state.err().unwrap().to_string(), "Preflight: failed");
+    }
+}
@@ -1,4 +1,4 @@
-use alloy_primitives::{U256, hex::ToHexExt};
+use alloy_primitives::{Bytes, U256, hex::ToHexExt};
 use alloy_sol_types::SolValue;
 use call_engine::{CallGuestId, HostOutput, Proof, Seal};
 use call_host::{Host, Prover, ProvingError, ProvingInput};
@@ -70,6 +70,20 @@ where
     state.serialize_str(&evm_call_result.encode_hex_with_prefix())
 }
 
+impl RawData {
+    pub fn abi_encode(&self) -> Vec<u8> {
+        (self.proof.clone(), Bytes::copy_from_slice(&self.evm_call_result)).abi_encode()
+    }
+
+    pub fn abi_decode(data: &[u8]) -> Result<Self, alloy_sol_types::Error> {
+        let (proof, evm_call_result) = <(Proof, Bytes)>::abi_decode(data, true)?;
+        Ok(Self {
+            proof,
+            evm_call_result: evm_call_result.to_vec(),
+        })
+    }
+}
+
 impl TryFrom<HostOutput> for RawData {
     type Error = seal::Error;
 
@@ -0,0 +1,180 @@
+use std::{collections::VecDeque, sync::Arc};
+
+use derive_new::new;
+use parking_lot::Mutex;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+use crate::v_call::CallHash;
+
+pub const DEFAULT_PREFLIGHT_WORKERS: usize = 8;
+pub const DEFAULT_PROVING_WORKERS: usize = 4;
+pub const DEFAULT_MAX_QUEUE_SIZE: usize = 1000;
+
+#[derive(new, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Config {
+    /// Number of calls that can be in preflight (or waiting for a proving worker) at once
+    pub preflight_workers: usize,
+    /// Number of calls that can be proven at once
+    pub proving_workers: usize,
+    /// Number of calls that can wait for a preflight worker
+    pub max_size: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            preflight_workers: DEFAULT_PREFLIGHT_WORKERS,
+            proving_workers: DEFAULT_PROVING_WORKERS,
+            max_size: DEFAULT_MAX_QUEUE_SIZE,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Proving queue is full ({0} calls waiting). Try again later")]
+pub struct QueueFull(pub usize);
+
+/// Calls waiting for a worker. Preflight workers are limited by `preflight_workers`
+/// and proving workers by `proving_workers`. A call keeps its preflight worker until it gets
+/// a proving one, so that finished preflights don't pile up in memory.
+pub struct Queue {
+    waiting: Mutex<VecDeque<CallHash>>,
+    max_size: usize,
+    preflight_workers: Arc<Semaphore>,
+    proving_workers: Arc<Semaphore>,
+}
+
+impl Queue {
+    pub fn new(config: Config) -> Self {
+        Self {
+            waiting: Mutex::new(VecDeque::new()),
+            max_size: config.max_size,
+            preflight_workers: Arc::new(Semaphore::new(config.preflight_workers)),
+            proving_workers: Arc::new(Semaphore::new(config.proving_workers)),
+        }
+    }
+
+    pub fn try_push(&self, call_hash: CallHash) -> Result<(), QueueFull> {
+        let mut waiting = self.waiting.lock();
+        if waiting.len() >= self.max_size {
+            return Err(QueueFull(waiting.len()));
+        }
+        waiting.push_back(call_hash);
+        Ok(())
+    }
+
+    /// Adds the call regardless of the queue size. Used for calls resumed after a restart.
+    pub fn push(&self, call_hash: CallHash) {
+        self.waiting.lock().push_back(call_hash);
+    }
+
+    /// Removes the call from the queue, if it's still waiting.
+    pub fn remove(&self, call_hash: &CallHash) {
+        let mut waiting = self.waiting.lock();
+        if let Some(position) = waiting.iter().position(|waiting| waiting == call_hash) {
+            waiting.remove(position);
+        }
+    }
+
+    /// Number of calls waiting ahead of the given one.
+    pub fn position(&self, call_hash: &CallHash) -> Option<usize> {
+        self.waiting
+            .lock()
+            .iter()
+            .position(|waiting| waiting == call_hash)
+    }
+
+    /// Waits for a free preflight worker and removes the call from the queue.
+    #[allow(clippy::expect_used)]
+    pub async fn preflight_permit(&self, call_hash: CallHash) -> OwnedSemaphorePermit {
+        let permit = Arc::clone(&self.preflight_workers)
+            .acquire_owned()
+            .await
+            .expect("semaphore is never closed");
+        self.remove(&call_hash);
+        permit
+    }
+
+    #[allow(clippy::expect_used)]
+    pub async fn proving_permit(&self) -> OwnedSemaphorePermit {
+        Arc::clone(&self.proving_workers)
+            .acquire_owned()
+            .await
+            .expect("semaphore is never closed")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::B256;
+
+    use super::*;
+
+    fn hash(byte: u8) -> CallHash {
+        B256::repeat_byte(byte).into()
+    }
+
+    fn queue(max_size: usize) -> Queue {
+        Queue::new(Config::new(1, 1, max_size))
+    }
+
+    #[test]
+    fn rejects_calls_when_full() {
+        let queue = queue(1);
+        queue.try_push(hash(1)).unwrap();
+
+        assert!(matches!(queue.try_push(hash(2)), Err(QueueFull(1))));
+    }
+
+    #[test]
+    fn resumed_calls_ignore_max_size() {
+        let queue = queue(0);
+        queue.push(hash(1));
+
+        assert_eq!(queue.position(&hash(1)), Some(0));
+    }
+
+    #[test]
+    fn reports_position() {
+        let queue = queue(2);
+        queue.try_push(hash(1)).unwrap();
+        queue.try_push(hash(2)).unwrap();
+
+        assert_eq!(queue.position(&hash(1)), Some(0));
+        assert_eq!(queue.position(&hash(2)), Some(1));
+        assert_eq!(queue.position(&hash(3)), None);
+    }
+
+    #[test]
+    fn removes_call() {
+        let queue = queue(2);
+        queue.try_push(hash(1)).unwrap();
+        queue.try_push(hash(2)).unwrap();
+        queue.remove(&hash(1));
+
+        assert_eq!(queue.position(&hash(2)), Some(0));
+    }
+
+    #[tokio::test]
+    async fn call_leaves_queue_when_it_gets_a_worker() {
+        let queue = queue(2);
+        queue.try_push(hash(1)).unwrap();
+        queue.try_push(hash(2)).unwrap();
+
+        let _permit = queue.preflight_permit(hash(1)).await;
+
+        assert_eq!(queue.position(&hash(1)), None);
+        assert_eq!(queue.position(&hash(2)), Some(0));
+        queue.try_push(hash(3)).unwrap();
+    }
+
+    #[tokio::test]
+    async fn limits_workers() {
+        let queue = queue(1);
+        let permit = queue.proving_permit().await;
+
+        assert_eq!(queue.proving_workers.available_permits(), 0);
+        drop(permit);
+        assert_eq!(queue.proving_workers.available_permits(), 1);
+    }
+}
@@ -0,0 +1,101 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    time::{Duration, Instant},
+};
+
+use derive_new::new;
+
+use crate::v_call::CallHash;
+
+pub const DEFAULT_TTL: u64 = 24 * 3600;
+pub const DEFAULT_MAX_ENTRIES: usize = 10_000;
+
+#[derive(new, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Config {
+    /// How long finished proofs (and errors) are kept
+    pub ttl: Duration,
+    /// Number of finished proofs kept. Least recently used ones are evicted first
+    pub max_entries: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            ttl: Duration::from_secs(DEFAULT_TTL),
+            max_entries: DEFAULT_MAX_ENTRIES,
+        }
+    }
+}
+
+impl Config {
+    pub fn is_expired(&self, finished_at: Instant, now: Instant) -> bool {
+        now.saturating_duration_since(finished_at) >= self.ttl
+    }
+}
+
+/// Hashes of recently evicted calls, so that they can be told apart from unknown ones.
+/// Only the last `capacity` hashes are remembered.
+#[derive(Default)]
+pub struct Evicted {
+    capacity: usize,
+    order: VecDeque<CallHash>,
+    hashes: HashSet<CallHash>,
+}
+
+impl Evicted {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            ..Default::default()
+        }
+    }
+
+    pub fn insert(&mut self, call_hash: CallHash) {
+        if self.capacity == 0 || !self.hashes.insert(call_hash) {
+            return;
+        }
+        self.order.push_back(call_hash);
+        if self.order.len() > self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.hashes.remove(&oldest);
+            }
+        }
+    }
+
+    pub fn contains(&self, call_hash: &CallHash) -> bool {
+        self.hashes.contains(call_hash)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::B256;
+
+    use super::*;
+
+    fn hash(byte: u8) -> CallHash {
+        B256::repeat_byte(byte).into()
+    }
+
+    #[test]
+    fn expires_after_ttl() {
+        let config = Config::new(Duration::from_secs(10), 1);
+        let finished_at = Instant::now();
+
+        assert!(!config.is_expired(finished_at, finished_at + Duration::from_secs(9)));
+        assert!(config.is_expired(finished_at, finished_at + Duration::from_secs(10)));
+    }
+
+    #[test]
+    fn remembers_last_evicted_hashes() {
+        let mut evicted = Evicted::new(2);
+        evicted.insert(hash(1));
+        evicted.insert(hash(2));
+        evicted.insert(hash(2));
+        evicted.insert(hash(3));
+
+        assert!(!evicted.contains(&hash(1)));
+        assert!(evicted.contains(&hash(2)));
+        assert!(evicted.contains(&hash(3)));
+    }
+}
@@ -21,6 +21,7 @@ pub struct CallAssumptionsDTO {
     settleChainId: U256,
     settleBlockNumber: U256,
     settleBlockHash: B256,
+    trustedNotaryKeysHash: B256,
 }
 
 #[derive(Serialize)]
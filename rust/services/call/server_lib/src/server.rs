@@ -1,11 +1,11 @@
-use std::iter::once;
+use std::sync::Arc;
 
 use axum::{
     Extension, Router,
     body::Bytes,
-    extract::State as AxumState,
-    http::header::AUTHORIZATION,
-    response::IntoResponse,
+    extract::{State as AxumState, WebSocketUpgrade},
+    http::{HeaderMap, HeaderName, header::AUTHORIZATION},
+    response::{IntoResponse, Response},
     routing::{get, post},
 };
 use derive_new::new;
@@ -13,6 +13,7 @@ use server_utils::{
     RequestId, RequestIdLayer, Router as JrpcRouter, cors, init_trace_layer,
     jwt::axum::TokenExtractor,
 };
+use subtle::ConstantTimeEq;
 use tokio::net::TcpListener;
 use tower_http::{
     sensitive_headers::SetSensitiveRequestHeadersLayer,
@@ -22,24 +23,58 @@ use tracing::info;
 
 use crate::{
     config::Config,
-    handlers::{Params, RpcServer, State as AppState},
+    handlers::{Params, Proofs, RpcServer, State as AppState, v_call},
+    proof::store::{JobStore, MdbxJobStore, NoOpJobStore},
+    webhook::Webhooks,
 };
 
+const ADMIN_API_KEY_HEADER_NAME: &str = "x-admin-api-key";
+
 pub async fn serve(config: Config) -> anyhow::Result<()> {
+    let app_state = restore_app_state(&config)?;
     let listener = TcpListener::bind(&config.socket_addr).await?;
 
     info!("Listening on {}", listener.local_addr()?);
-    axum::serve(listener, server(config)).await?;
+    axum::serve(listener, server_with_state(config, app_state)).await?;
 
     Ok(())
 }
 
+/// Opens the proof store (if configured) and restarts jobs interrupted by the previous shutdown.
+fn restore_app_state(config: &Config) -> anyhow::Result<AppState> {
+    let Some(path) = &config.proof_store_path else {
+        return Ok(new_app_state(config, NoOpJobStore));
+    };
+    info!("Using proof store at {}", path.display());
+    let app_state = new_app_state(config, MdbxJobStore::open(path)?);
+    let unfinished_jobs = app_state.restore()?;
+    if !unfinished_jobs.is_empty() {
+        info!("Resuming {} interrupted call(s)", unfinished_jobs.len());
+    }
+    for (call_hash, job) in unfinished_jobs {
+        v_call::resume(Arc::clone(&app_state), config, call_hash, job);
+    }
+    Ok(app_state)
+}
+
+fn is_admin(config: &Config, headers: &HeaderMap) -> bool {
+    let (Some(admin_api_key), Some(header)) =
+        (&config.admin_api_key, headers.get(ADMIN_API_KEY_HEADER_NAME))
+    else {
+        return false;
+    };
+    // Constant-time, so that the key can't be guessed byte by byte from response times
+    header.as_bytes().ct_eq(admin_api_key.as_bytes()).into()
+}
+
 async fn handle(
     AxumState(State { router, config }): AxumState<State>,
     Extension(req_id): Extension<RequestId>,
+    headers: HeaderMap,
     body: Bytes,
 ) -> impl IntoResponse {
-    let params = Params::new(config, None, req_id);
+    let admin = is_admin(&config, &headers);
+    let params = Params::new(config, None, req_id, admin);
     router.handle_request_with_params(body, params).await
 }
 
@@ -47,12 +82,60 @@ async fn handle_with_auth(
     TokenExtractor(token): TokenExtractor,
     AxumState(State { router, config }): AxumState<State>,
     Extension(req_id): Extension<RequestId>,
+    headers: HeaderMap,
     body: Bytes,
 ) -> impl IntoResponse {
-    let params = Params::new(config, Some(token), req_id);
+    let admin = is_admin(&config, &headers);
+    let params = Params::new(config, Some(token), req_id, admin);
     router.handle_request_with_params(body, params).await
 }
 
+async fn handle_subscription(
+    AxumState(State { router, config }): AxumState<State>,
+    Extension(req_id): Extension<RequestId>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> impl IntoResponse {
+    let admin = is_admin(&config, &headers);
+    let params = Params::new(config, None, req_id, admin);
+    router.subscribe_with_params(body, params).await
+}
+
+async fn handle_subscription_with_auth(
+    TokenExtractor(token): TokenExtractor,
+    AxumState(State { router, config }): AxumState<State>,
+    Extension(req_id): Extension<RequestId>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> impl IntoResponse {
+    let admin = is_admin(&config, &headers);
+    let params = Params::new(config, Some(token), req_id, admin);
+    router.subscribe_with_params(body, params).await
+}
+
+async fn handle_websocket_subscription(
+    AxumState(State { router, config }): AxumState<State>,
+    Extension(req_id): Extension<RequestId>,
+    headers: HeaderMap,
+    websocket: WebSocketUpgrade,
+) -> Response {
+    let admin = is_admin(&config, &headers);
+    let params = Params::new(config, None, req_id, admin);
+    websocket.on_upgrade(move |socket| router.subscribe_over_websocket(socket, params))
+}
+
+async fn handle_websocket_subscription_with_auth(
+    TokenExtractor(token): TokenExtractor,
+    AxumState(State { router, config }): AxumState<State>,
+    Extension(req_id): Extension<RequestId>,
+    headers: HeaderMap,
+    websocket: WebSocketUpgrade,
+) -> Response {
+    let admin = is_admin(&config, &headers);
+    let params = Params::new(config, Some(token), req_id, admin);
+    websocket.on_upgrade(move |socket| router.subscribe_over_websocket(socket, params))
+}
+
 #[derive(new, Clone)]
 pub(super) struct State {
     pub config: Config,
@@ -60,20 +143,77 @@ pub(super) struct State {
 }
 
 pub fn server(config: Config) -> Router {
-    let handler = if config.jwt_config.is_some() {
-        post(handle_with_auth)
+    let app_state = new_app_state(&config, NoOpJobStore);
+    server_with_state(config, app_state)
+}
+
+fn new_app_state(config: &Config, store: impl JobStore + 'static) -> AppState {
+    let webhooks = config.webhook_secret.as_deref().map(Webhooks::new);
+    Arc::new(Proofs::new(
+        Box::new(store),
+        config.queue_config,
+        config.retention_config,
+        webhooks,
+    ))
+}
+
+fn server_with_state(config: Config, app_state: AppState) -> Router {
+    // Subscriptions are served as server-sent events on POST and over WebSocket on GET
+    let (handler, subscription_handler) = if config.jwt_config.is_some() {
+        (
+            post(handle_with_auth),
+            post(handle_subscription_with_auth).get(handle_websocket_subscription_with_auth),
+        )
     } else {
-        post(handle)
+        (post(handle), post(handle_subscription).get(handle_websocket_subscription))
     };
-    let router = State::new(config, JrpcRouter::new(AppState::default().into_rpc()));
+    let router = State::new(config, JrpcRouter::new(app_state.into_rpc()));
     Router::new()
         .route("/", handler)
+        .route("/subscribe", subscription_handler)
         .route_layer(init_trace_layer())
         // NOTE: RequestIdLayer should be added after the Trace layer
         .route_layer(RequestIdLayer)
         .with_state(router)
         .route("/health", get(|| async { "OK" }))
         .layer(cors())
-        .layer(SetSensitiveRequestHeadersLayer::new(once(AUTHORIZATION)))
+        .layer(SetSensitiveRequestHeadersLayer::new([
+            AUTHORIZATION,
+            HeaderName::from_static(ADMIN_API_KEY_HEADER_NAME),
+        ]))
         .layer(ValidateRequestHeaderLayer::accept(mime::APPLICATION_JSON.as_ref()))
 }
+
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderValue;
+
+    use super::*;
+    use crate::config::tests::config_builder;
+
+    fn headers(admin_api_key: &'static str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(ADMIN_API_KEY_HEADER_NAME, HeaderValue::from_static(admin_api_key));
+        headers
+    }
+
+    #[test]
+    fn recognizes_admin_by_api_key() {
+        let config = config_builder()
+            .with_admin_api_key(Some("s3cret".into()))
+            .build()
+            .unwrap();
+
+        assert!(is_admin(&config, &headers("s3cret")));
+        assert!(!is_admin(&config, &headers("s3cret2")));
+        assert!(!is_admin(&config, &headers("s3cre")));
+        assert!(!is_admin(&config, &HeaderMap::new()));
+    }
+
+    #[test]
+    fn has_no_admin_without_api_key() {
+        let config = config_builder().build().unwrap();
+
+        assert!(!is_admin(&config, &headers("")));
+    }
+}
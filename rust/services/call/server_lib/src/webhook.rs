@@ -0,0 +1,209 @@
+use std::{
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use hmac::{Hmac, Mac};
+use reqwest::{
+    Client, Response, Url,
+    dns::{Addrs, Name, Resolve, Resolving},
+    header::CONTENT_TYPE,
+    redirect::Policy,
+};
+use serde::Serialize;
+use sha2::Sha256;
+use tracing::{info, warn};
+
+use crate::{v_call::CallHash, v_get_proof_receipt::CallResult};
+
+/// Hex-encoded HMAC-SHA256 of `<timestamp>.<body>`, keyed with the webhook secret
+pub const SIGNATURE_HEADER_NAME: &str = "x-vlayer-signature";
+
+/// Unix timestamp (in seconds) of when the request was signed. Lets receivers reject replays.
+pub const TIMESTAMP_HEADER_NAME: &str = "x-vlayer-timestamp";
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Serialize)]
+struct Notification<'a> {
+    hash: CallHash,
+    result: &'a CallResult,
+}
+
+/// Notifies clients about finished proof generations with signed POST requests.
+pub struct Webhooks {
+    client: Client,
+    secret: String,
+}
+
+impl Webhooks {
+    /// Redirects are not followed and hosts are resolved to public addresses only, so that
+    /// callers can't make the prover send requests into its own network.
+    #[allow(clippy::expect_used)]
+    pub fn new(secret: impl Into<String>) -> Self {
+        let client = Client::builder()
+            .redirect(Policy::none())
+            .dns_resolver(Arc::new(PublicResolver))
+            .build()
+            .expect("TLS backend can be initialized");
+        Self {
+            client,
+            secret: secret.into(),
+        }
+    }
+
+    /// Sends the request in the background. Failed deliveries are only logged.
+    pub fn notify(&self, url: &str, call_hash: CallHash, result: &CallResult) {
+        if !Url::parse(url).is_ok_and(|url| is_allowed_host(&url)) {
+            warn!("Webhook notification for {call_hash} not sent to disallowed url {url}");
+            return;
+        }
+        let body = match serde_json::to_vec(&Notification {
+            hash: call_hash,
+            result,
+        }) {
+            Ok(body) => body,
+            Err(err) => {
+                warn!("Failed to serialize webhook notification for {call_hash}: {err}");
+                return;
+            }
+        };
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let request = self
+            .client
+            .post(url)
+            .timeout(TIMEOUT)
+            .header(CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+            .header(TIMESTAMP_HEADER_NAME, timestamp)
+            .header(SIGNATURE_HEADER_NAME, self.sign(timestamp, &body))
+            .body(body);
+        tokio::spawn(async move {
+            match request.send().await.and_then(Response::error_for_status) {
+                Ok(_) => info!("Webhook notification for {call_hash} delivered"),
+                Err(err) => warn!("Webhook notification for {call_hash} failed: {err}"),
+            }
+        });
+    }
+
+    #[allow(clippy::expect_used)]
+    fn sign(&self, timestamp: u64, body: &[u8]) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.secret.as_bytes())
+            .expect("HMAC accepts keys of any length");
+        mac.update(format!("{timestamp}.").as_bytes());
+        mac.update(body);
+        hex::encode(mac.finalize().into_bytes())
+    }
+}
+
+/// Whether webhooks can be sent to the host of the url. Domains are checked again once resolved.
+pub fn is_allowed_host(url: &Url) -> bool {
+    let Some(host) = url.host_str() else {
+        return false;
+    };
+    match host
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .parse::<IpAddr>()
+    {
+        Ok(ip) => is_public(ip),
+        Err(_) => !host.eq_ignore_ascii_case("localhost"),
+    }
+}
+
+/// Rejects loopback, private, link-local (including cloud metadata endpoints) and other
+/// special-purpose addresses.
+fn is_public(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [first, second, ..] = ip.octets();
+            let is_shared = first == 100 && (second & 0xc0) == 64;
+            !(first == 0
+                || ip.is_private()
+                || ip.is_loopback()
+                || ip.is_link_local()
+                || ip.is_broadcast()
+                || ip.is_multicast()
+                || ip.is_documentation()
+                || is_shared)
+        }
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_public(ip.into()),
+            None => {
+                !(ip.is_unspecified()
+                    || ip.is_loopback()
+                    || ip.is_multicast()
+                    || ip.is_unique_local()
+                    || ip.is_unicast_link_local())
+            }
+        },
+    }
+}
+
+/// System resolver which drops addresses that are not public. The resolved addresses are the ones
+/// connected to, so a domain can't be rebound to an internal address after the check.
+struct PublicResolver;
+
+impl Resolve for PublicResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let addrs: Vec<SocketAddr> = tokio::net::lookup_host((name.as_str(), 0))
+                .await?
+                .filter(|addr| is_public(addr.ip()))
+                .collect();
+            if addrs.is_empty() {
+                return Err(format!("{} has no public address", name.as_str()).into());
+            }
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // HMAC-SHA256 of "1.body" keyed with "Jefe"
+    const SIGNATURE: &str = "25d254c27761adb1423727084ca395476858b2401387226230b6358cf07fad8b";
+
+    fn url(url: &str) -> Url {
+        Url::parse(url).unwrap()
+    }
+
+    #[test]
+    fn signs_timestamp_and_body_with_secret() {
+        let webhooks = Webhooks::new("Jefe");
+
+        assert_eq!(webhooks.sign(1, b"body"), SIGNATURE);
+        assert_ne!(webhooks.sign(2, b"body"), SIGNATURE);
+    }
+
+    #[test]
+    fn allows_public_hosts() {
+        assert!(is_allowed_host(&url("https://example.com/hook")));
+        assert!(is_allowed_host(&url("http://8.8.8.8/hook")));
+        assert!(is_allowed_host(&url("http://[2001:4860:4860::8888]/hook")));
+    }
+
+    #[test]
+    fn rejects_internal_hosts() {
+        for internal in [
+            "http://localhost/hook",
+            "http://127.0.0.1/hook",
+            "http://10.0.0.1/hook",
+            "http://192.168.1.1/hook",
+            "http://169.254.169.254/latest/meta-data",
+            "http://100.64.0.1/hook",
+            "http://0.0.0.0/hook",
+            "http://[::1]/hook",
+            "http://[fd00:ec2::254]/hook",
+            "http://[fe80::1]/hook",
+            "http://[::ffff:127.0.0.1]/hook",
+        ] {
+            assert!(!is_allowed_host(&url(internal)), "{internal}");
+        }
+    }
+}
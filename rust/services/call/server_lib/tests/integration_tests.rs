@@ -310,7 +310,7 @@ mod server_tests {
                     "data": {
                         "evm_call_result": evm_call_result.into(),
                         "proof": {
-                            "length": 192,
+                            "length": 224,
                             "seal": {
                                 "verifierSelector": "0xdeafbeef",
                                 "mode": 1,
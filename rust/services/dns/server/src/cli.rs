@@ -1,10 +1,22 @@
-use std::{net::SocketAddr, path::PathBuf};
+use std::{
+    fs,
+    net::SocketAddr,
+    path::{Path, PathBuf},
+};
 
+use anyhow::Context;
 use clap::Parser;
 use common::GlobalArgs;
+use serde::Deserialize;
 use server_utils::jwt::cli::Args as JwtArgs;
+use verifiable_dns::{
+    DNSRecord, ExternalProvider, StubProvider,
+    verifiable_dns::{key_set::KeySet, signer::Signer, time::Timestamp},
+};
 use version::version;
 
+use crate::{config::DEFAULT_CACHE_CAPACITY, provider::DnsProvider};
+
 #[derive(Debug, clap::Args)]
 #[group(required = false, multiple = false)]
 pub(crate) struct PrivateKeyArgs {
@@ -17,6 +29,51 @@ pub(crate) struct PrivateKeyArgs {
     private_key: Option<String>,
     #[arg(long, short = 'f', env = "PRIVATE_KEY_PATH", help = "Path to PEM file")]
     private_key_path: Option<PathBuf>,
+    #[arg(
+        long,
+        env = "KEY_SET_PATH",
+        help = "Path to a JSON array of signing keys with their validity windows, for key rotation"
+    )]
+    key_set_path: Option<PathBuf>,
+}
+
+/// Entry of the key set file. Key paths are relative to the file.
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+struct KeySetEntry {
+    private_key_path: PathBuf,
+    not_before: Option<Timestamp>,
+    not_after: Option<Timestamp>,
+}
+
+#[derive(Debug, clap::Args)]
+pub(crate) struct ProviderArgs {
+    #[arg(
+        long = "provider-url",
+        env = "PROVIDER_URLS",
+        value_delimiter = ',',
+        help = "URL of a DNS over HTTPS JSON API provider. Defaults to Google and DNS.SB"
+    )]
+    provider_urls: Vec<String>,
+    #[arg(
+        long,
+        env = "STUB_RECORDS_PATH",
+        help = "Path to a JSON array of DNS records to serve without querying the network"
+    )]
+    stub_records: Option<PathBuf>,
+    #[arg(
+        long,
+        env,
+        help = "Number of providers which have to agree on a response. Defaults to all of them"
+    )]
+    pub(crate) quorum: Option<usize>,
+    #[arg(
+        long,
+        env,
+        default_value_t = DEFAULT_CACHE_CAPACITY,
+        help = "Maximum number of cached responses, 0 disables caching"
+    )]
+    pub(crate) cache_capacity: usize,
 }
 
 #[derive(Debug, Parser)]
@@ -34,6 +91,9 @@ pub(crate) struct Cli {
     #[clap(flatten)]
     pub(crate) private_key: PrivateKeyArgs,
 
+    #[clap(flatten)]
+    pub(crate) providers: ProviderArgs,
+
     #[clap(flatten)]
     pub(crate) jwt_args: JwtArgs,
 
@@ -48,6 +108,53 @@ impl PrivateKeyArgs {
             .transpose()
             .map(|key| key.or(self.private_key))
     }
+
+    pub(crate) fn key_set(self) -> anyhow::Result<Option<KeySet>> {
+        if let Some(path) = &self.key_set_path {
+            return read_key_set(path).map(Some);
+        }
+        let key = self.private_key()?;
+        Ok(key.map(|key| KeySet::new(Signer::new(&key))))
+    }
+}
+
+fn read_key_set(path: &Path) -> anyhow::Result<KeySet> {
+    let entries = fs::read_to_string(path)
+        .with_context(|| format!("failed to read key set from {}", path.display()))?;
+    let entries: Vec<KeySetEntry> = serde_json::from_str(&entries)
+        .with_context(|| format!("failed to parse key set from {}", path.display()))?;
+    let dir = path.parent().unwrap_or_else(|| Path::new(""));
+
+    entries
+        .into_iter()
+        .try_fold(KeySet::default(), |key_set, entry| {
+            let key_path = dir.join(&entry.private_key_path);
+            let key = fs::read_to_string(&key_path).with_context(|| {
+                format!("failed to read private key from {}", key_path.display())
+            })?;
+            Ok(key_set.with_key(Signer::new(&key), entry.not_before, entry.not_after))
+        })
+}
+
+impl ProviderArgs {
+    pub(crate) fn providers(&self) -> anyhow::Result<Vec<DnsProvider>> {
+        let mut providers: Vec<DnsProvider> = self
+            .provider_urls
+            .iter()
+            .map(|url| DnsProvider::External(ExternalProvider::new(url)))
+            .collect();
+        if let Some(path) = &self.stub_records {
+            let records = fs::read_to_string(path)
+                .with_context(|| format!("failed to read stub records from {}", path.display()))?;
+            let records: Vec<DNSRecord> = serde_json::from_str(&records)
+                .with_context(|| format!("failed to parse stub records from {}", path.display()))?;
+            providers.push(DnsProvider::Stub(StubProvider::new(records)));
+        }
+        if providers.is_empty() {
+            providers = DnsProvider::default_providers();
+        }
+        Ok(providers)
+    }
 }
 
 #[cfg(test)]
@@ -61,6 +168,7 @@ mod test {
         let args = PrivateKeyArgs {
             private_key: Some("ignored param".into()),
             private_key_path: Some("test_fixtures/signer.pem".into()),
+            key_set_path: None,
         };
         assert_eq!(args.private_key()?.as_deref(), Some(PRIVATE_KEY));
         Ok(())
@@ -71,6 +179,7 @@ mod test {
         let args = PrivateKeyArgs {
             private_key: Some(PRIVATE_KEY.into()),
             private_key_path: None,
+            key_set_path: None,
         };
         assert_eq!(args.private_key()?.as_deref(), Some(PRIVATE_KEY));
         Ok(())
@@ -81,8 +190,80 @@ mod test {
         let args = PrivateKeyArgs {
             private_key: None,
             private_key_path: None,
+            key_set_path: None,
         };
         assert_eq!(args.private_key()?, None);
         Ok(())
     }
+
+    mod key_set {
+        use super::*;
+
+        fn args(key_set_path: &str) -> PrivateKeyArgs {
+            PrivateKeyArgs {
+                private_key: None,
+                private_key_path: None,
+                key_set_path: Some(key_set_path.into()),
+            }
+        }
+
+        #[test]
+        fn reads_keys_relative_to_key_set_file() -> anyhow::Result<()> {
+            let key_set = args("test_fixtures/key_set.json").key_set()?.unwrap();
+
+            let keys = key_set.published_keys(1_700_000_000);
+
+            assert_eq!(keys.len(), 2);
+            assert_eq!(keys[0].public_key, Signer::new(PRIVATE_KEY).public_key());
+            assert_eq!(keys[0].not_after, Some(1_800_000_000));
+            assert_eq!(keys[1].not_before, Some(1_750_000_000));
+            Ok(())
+        }
+
+        #[test]
+        fn fails_for_missing_key_file() {
+            assert!(args("test_fixtures/missing.json").key_set().is_err());
+        }
+    }
+
+    mod providers {
+        use super::*;
+
+        fn args(provider_urls: &[&str], stub_records: Option<&str>) -> ProviderArgs {
+            ProviderArgs {
+                provider_urls: provider_urls.iter().map(ToString::to_string).collect(),
+                stub_records: stub_records.map(Into::into),
+                quorum: None,
+                cache_capacity: 0,
+            }
+        }
+
+        #[test]
+        fn defaults_to_google_and_dns_sb() -> anyhow::Result<()> {
+            assert_eq!(args(&[], None).providers()?.len(), 2);
+            Ok(())
+        }
+
+        #[test]
+        fn uses_only_given_providers() -> anyhow::Result<()> {
+            let providers =
+                args(&["https://1.1.1.1/dns-query"], Some("test_fixtures/stub_records.json"))
+                    .providers()?;
+
+            assert!(matches!(
+                providers.as_slice(),
+                [DnsProvider::External(_), DnsProvider::Stub(_)]
+            ));
+            Ok(())
+        }
+
+        #[test]
+        fn fails_for_missing_stub_records_file() {
+            assert!(
+                args(&[], Some("test_fixtures/missing.json"))
+                    .providers()
+                    .is_err()
+            );
+        }
+    }
 }
@@ -1,13 +1,22 @@
 use std::net::{IpAddr, Ipv4Addr, SocketAddr as RawSocketAddr};
 
+use anyhow::ensure;
 use server_utils::jwt::config::Config as JwtConfig;
+use verifiable_dns::verifiable_dns::key_set::KeySet;
+
+use crate::provider::DnsProvider;
+
+pub(crate) const DEFAULT_CACHE_CAPACITY: usize = 1000;
 
 #[derive(Clone)]
 #[allow(clippy::struct_field_names)]
 pub struct Config {
     pub socket_addr: RawSocketAddr,
-    pub private_key: Option<String>,
+    pub key_set: Option<KeySet>,
     pub jwt_config: Option<JwtConfig>,
+    pub providers: Vec<DnsProvider>,
+    pub quorum: usize,
+    pub cache_capacity: usize,
 }
 
 pub struct SocketAddr(RawSocketAddr);
@@ -18,11 +27,26 @@ impl Default for SocketAddr {
     }
 }
 
-#[derive(Default)]
 pub struct ConfigBuilder {
     socket_addr: SocketAddr,
-    private_key: Option<String>,
+    key_set: Option<KeySet>,
     jwt_config: Option<JwtConfig>,
+    providers: Vec<DnsProvider>,
+    quorum: Option<usize>,
+    cache_capacity: usize,
+}
+
+impl Default for ConfigBuilder {
+    fn default() -> Self {
+        Self {
+            socket_addr: SocketAddr::default(),
+            key_set: None,
+            jwt_config: None,
+            providers: DnsProvider::default_providers(),
+            quorum: None,
+            cache_capacity: DEFAULT_CACHE_CAPACITY,
+        }
+    }
 }
 
 impl ConfigBuilder {
@@ -33,8 +57,8 @@ impl ConfigBuilder {
     }
 
     #[must_use]
-    pub fn with_private_key(mut self, key: impl Into<Option<String>>) -> Self {
-        self.private_key = key.into();
+    pub fn with_key_set(mut self, key_set: impl Into<Option<KeySet>>) -> Self {
+        self.key_set = key_set.into();
         self
     }
 
@@ -44,14 +68,85 @@ impl ConfigBuilder {
         self
     }
 
-    pub fn build(self) -> Config {
+    #[must_use]
+    pub fn with_providers(mut self, providers: impl IntoIterator<Item = DnsProvider>) -> Self {
+        self.providers = providers.into_iter().collect();
+        self
+    }
+
+    /// Defaults to all providers having to agree
+    #[must_use]
+    pub fn with_quorum(mut self, quorum: impl Into<Option<usize>>) -> Self {
+        self.quorum = quorum.into();
+        self
+    }
+
+    #[must_use]
+    pub const fn with_cache_capacity(mut self, capacity: usize) -> Self {
+        self.cache_capacity = capacity;
+        self
+    }
+
+    pub fn build(self) -> anyhow::Result<Config> {
         let socket_addr = self.socket_addr.0;
-        let private_key = self.private_key;
+        let key_set = self.key_set;
         let jwt_config = self.jwt_config;
-        Config {
+        let providers = self.providers;
+        ensure!(!providers.is_empty(), "at least one DNS provider is required");
+        let quorum = self.quorum.unwrap_or(providers.len());
+        ensure!(
+            (1..=providers.len()).contains(&quorum),
+            "quorum must be between 1 and the number of providers ({}), got {quorum}",
+            providers.len()
+        );
+        Ok(Config {
             socket_addr,
-            private_key,
+            key_set,
             jwt_config,
-        }
+            providers,
+            quorum,
+            cache_capacity: self.cache_capacity,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use verifiable_dns::StubProvider;
+
+    use super::*;
+
+    fn stub_providers(count: usize) -> Vec<DnsProvider> {
+        vec![DnsProvider::Stub(StubProvider::default()); count]
+    }
+
+    #[test]
+    fn quorum_defaults_to_all_providers() -> anyhow::Result<()> {
+        let config = ConfigBuilder::default()
+            .with_providers(stub_providers(3))
+            .build()?;
+        assert_eq!(config.quorum, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn fails_for_quorum_larger_than_number_of_providers() {
+        let result = ConfigBuilder::default()
+            .with_providers(stub_providers(2))
+            .with_quorum(3)
+            .build();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn fails_for_zero_quorum() {
+        let result = ConfigBuilder::default().with_quorum(0).build();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn fails_without_providers() {
+        let result = ConfigBuilder::default().with_providers([]).build();
+        assert!(result.is_err());
     }
 }
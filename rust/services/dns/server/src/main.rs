@@ -1,5 +1,6 @@
 mod cli;
 mod config;
+mod provider;
 mod server;
 
 use clap::Parser;
@@ -20,9 +21,12 @@ async fn main() -> anyhow::Result<()> {
     let jwt_config: Option<JwtConfig> = cli.jwt_args.try_into()?;
     let config = ConfigBuilder::default()
         .with_socket_addr(cli.listen_addr)
-        .with_private_key(cli.private_key.private_key()?)
+        .with_key_set(cli.private_key.key_set()?)
         .with_jwt_config(jwt_config)
-        .build();
+        .with_providers(cli.providers.providers()?)
+        .with_quorum(cli.providers.quorum)
+        .with_cache_capacity(cli.providers.cache_capacity)
+        .build()?;
 
     serve(config).await?;
 
@@ -0,0 +1,33 @@
+use verifiable_dns::{
+    ExternalProvider, ExternalProviderError, Provider, Query, Response, StubProvider,
+};
+
+/// Upstream the server resolves queries with
+#[derive(Clone, Debug)]
+pub enum DnsProvider {
+    External(ExternalProvider),
+    Stub(StubProvider),
+}
+
+impl DnsProvider {
+    pub fn default_providers() -> Vec<Self> {
+        vec![
+            Self::External(ExternalProvider::google_provider()),
+            Self::External(ExternalProvider::dns_sb_provider()),
+        ]
+    }
+}
+
+impl Provider for DnsProvider {
+    type Error = ExternalProviderError;
+
+    async fn resolve(&self, query: &Query) -> Result<Response, Self::Error> {
+        match self {
+            Self::External(provider) => provider.resolve(query).await,
+            Self::Stub(provider) => {
+                let Ok(response) = provider.resolve(query).await;
+                Ok(response)
+            }
+        }
+    }
+}
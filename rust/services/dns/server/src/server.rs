@@ -5,9 +5,11 @@ use axum::Router;
 use server_utils::{RequestIdLayer, cors, init_trace_layer};
 use tokio::net::TcpListener;
 use tracing::{info, warn};
-use verifiable_dns::{ExternalProvider, VerifiableDNSResolver};
+use verifiable_dns::verifiable_dns::{Resolver, time::RTClock};
 
-use crate::config::Config;
+use crate::{config::Config, provider::DnsProvider};
+
+type VerifiableDNSResolver = Resolver<RTClock, DnsProvider>;
 
 #[derive(Clone)]
 struct AppState {
@@ -17,12 +19,14 @@ struct AppState {
 
 impl AppState {
     fn new(config: Config) -> Self {
-        let providers = [ExternalProvider::google_provider(), ExternalProvider::dns_sb_provider()];
-        let vdns_resolver = match config.private_key.as_deref() {
-            Some(key) => VerifiableDNSResolver::new(providers).with_key(key),
+        let resolver = VerifiableDNSResolver::new(config.providers.clone())
+            .with_quorum(config.quorum)
+            .with_cache(config.cache_capacity);
+        let vdns_resolver = match config.key_set.clone() {
+            Some(key_set) => resolver.with_key_set(key_set),
             None => {
                 warn!("Private key not provided, using default resolver key");
-                VerifiableDNSResolver::new(providers)
+                resolver
             }
         };
 
@@ -57,14 +61,28 @@ fn server(config: Config, state: AppState) -> Router {
 #[cfg(test)]
 mod test_helpers {
     use server_utils::jwt::{DecodingKey, config::Config as JwtConfig};
+    use verifiable_dns::{DNSRecord, RecordType, StubProvider};
 
     use super::*;
     use crate::config::ConfigBuilder;
 
     pub const JWT_SECRET: &[u8] = b"deadbeef";
 
+    fn stub_provider() -> DnsProvider {
+        DnsProvider::Stub(StubProvider::new(vec![DNSRecord {
+            name: "google._domainkey.vlayer.xyz".into(),
+            record_type: RecordType::TXT,
+            ttl: 300,
+            data: "v=DKIM1; k=rsa; p=MIIBIjANBgkqhkiG9w0BAQEFAAOCAQ8AMIIBCgKCAQEA".into(),
+        }]))
+    }
+
+    fn config_builder() -> ConfigBuilder {
+        ConfigBuilder::default().with_providers([stub_provider()])
+    }
+
     pub fn app() -> Router {
-        let config = ConfigBuilder::default().build();
+        let config = config_builder().build().unwrap();
         let state = AppState::new(config.clone());
         server(config, state)
     }
@@ -72,7 +90,10 @@ mod test_helpers {
     pub fn app_with_jwt_auth() -> Router {
         let public_key = DecodingKey::from_secret(JWT_SECRET);
         let jwt_config = JwtConfig::new(public_key, Default::default(), vec![]);
-        let config = ConfigBuilder::default().with_jwt_config(jwt_config).build();
+        let config = config_builder()
+            .with_jwt_config(jwt_config)
+            .build()
+            .unwrap();
         let state = AppState::new(config.clone());
         server(config, state)
     }
@@ -1,4 +1,5 @@
 mod dns_query;
+mod keys;
 
 use std::collections::HashMap;
 
@@ -8,9 +9,15 @@ use super::{AppState, Config};
 
 const DNS_QUERY_PATH: &str = "/dns-query";
 const HEALTH_PATH: &str = "/health";
+const KEYS_PATH: &str = "/keys";
 
 pub(super) fn handlers(config: Config) -> HashMap<&'static str, MethodRouter<AppState>> {
-    [(DNS_QUERY_PATH, dns_query::handler(config)), (HEALTH_PATH, health_handler())].into()
+    [
+        (DNS_QUERY_PATH, dns_query::handler(config)),
+        (HEALTH_PATH, health_handler()),
+        (KEYS_PATH, keys::handler()),
+    ]
+    .into()
 }
 
 fn health_handler() -> MethodRouter<AppState> {
@@ -0,0 +1,47 @@
+use axum::{
+    Json,
+    extract::State,
+    routing::{MethodRouter, get},
+};
+use serde::Serialize;
+use verifiable_dns::verifiable_dns::{
+    key_set::PublishedKey,
+    time::{Now, RTClock},
+};
+
+use crate::server::AppState;
+
+#[derive(Serialize, Debug)]
+struct KeysResponse {
+    keys: Vec<PublishedKey>,
+}
+
+pub fn handler() -> MethodRouter<AppState> {
+    get(keys_handler)
+}
+
+async fn keys_handler(State(state): State<AppState>) -> Json<KeysResponse> {
+    let keys = state.vdns_resolver.key_set().published_keys(RTClock::now());
+    Json(KeysResponse { keys })
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::{StatusCode, header::ACCEPT};
+    use server_utils::{body_to_json, get};
+    use verifiable_dns::verifiable_dns::signer::Signer;
+
+    use super::*;
+    use crate::server::{handlers::KEYS_PATH, test_helpers::app};
+
+    #[tokio::test]
+    async fn lists_active_key() {
+        let response = get(app(), KEYS_PATH, &[(&ACCEPT, "application/json")], &[]).await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = body_to_json(response.into_body()).await;
+
+        assert_eq!(body["keys"][0]["key_id"], Signer::default().key_id().to_string());
+        assert_eq!(body["keys"][0]["status"], "active");
+    }
+}
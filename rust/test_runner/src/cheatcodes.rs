@@ -36,13 +36,16 @@ sol!(
         uint64 validUntil;
         bytes signature;
         bytes pubKey;
+        bytes32 keyId;
+        bytes dnssecProof;
     }
 
     #[derive(Default)]
     struct UnverifiedEmail {
         string email;
-        DnsRecord dnsRecord;
-        VerificationData verificationData;
+        DnsRecord[] dnsRecords;
+        VerificationData[] verificationData;
+        string[] requiredSignedHeaders;
     }
 
     function callProver() external returns (bool);
@@ -55,7 +58,10 @@ impl From<verifiable_dns::VerificationData> for VerificationData {
         Self {
             validUntil: value.valid_until,
             signature: value.signature.0.into(),
+            keyId: value.pub_key.key_id().0.into(),
             pubKey: value.pub_key.0.into(),
+            // Records resolved by the test runner are signed by the notary
+            dnssecProof: Default::default(),
         }
     }
 }
@@ -45,8 +45,9 @@ pub fn preverify_email(input: &Bytes) -> Result<UnverifiedEmail, EnhanceEmailErr
 
     Ok(UnverifiedEmail {
         email,
-        dnsRecord: dns_record,
-        verificationData: verification_data.into(),
+        dnsRecords: vec![dns_record],
+        verificationData: vec![verification_data.into()],
+        requiredSignedHeaders: vec![],
     })
 }
 
@@ -5,7 +5,7 @@ use forge::revm::primitives::{
     Account, Address, B256, Bytes, EvmState, U256,
     alloy_primitives::{BlockNumber, ChainId, StorageKey, StorageValue, TxNumber},
 };
-use provider::{BlockingProvider, EIP1186Proof, ProviderFactory, Result};
+use provider::{BlockingProvider, EIP1186Proof, ProviderFactory, Receipt, Result};
 
 use crate::proof::{account_proof, prove_storage, storage_root};
 
@@ -97,6 +97,14 @@ impl BlockingProvider for PendingStateProvider {
         Ok(self.account(address).info.nonce)
     }
 
+    fn get_block_receipts(&self, _block: BlockNumber) -> Result<Vec<Receipt>> {
+        Ok(vec![])
+    }
+
+    fn get_block_transactions(&self, _block: BlockNumber) -> Result<Vec<Bytes>> {
+        Ok(vec![])
+    }
+
     fn get_latest_block_number(&self) -> Result<BlockNumber> {
         Ok(self.block_number)
     }
@@ -7,6 +7,10 @@ impl MockProvider {
     pub const fn new(response: Response) -> Self {
         Self(Some(response))
     }
+
+    pub const fn failing() -> Self {
+        Self(None)
+    }
 }
 
 impl Provider for MockProvider {
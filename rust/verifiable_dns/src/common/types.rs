@@ -1,6 +1,9 @@
+use std::fmt;
+
 use bytes::Bytes;
+use rsa::sha2::{Digest, Sha256};
 use serde::{Deserialize, Serialize};
-use serde_with::{base64::Base64, serde_as};
+use serde_with::{base64::Base64, hex::Hex, serde_as};
 
 #[serde_as]
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
@@ -10,6 +13,26 @@ pub struct Signature(#[serde_as(as = "Base64")] pub Bytes);
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub struct PublicKey(#[serde_as(as = "Base64")] pub Bytes);
 
+impl PublicKey {
+    /// SHA-256 digest of the DER-encoded key
+    pub fn key_id(&self) -> KeyId {
+        KeyId(Sha256::digest(&self.0).into())
+    }
+}
+
+/// Identifies the key a record was signed with, so that verifiers can tell keys apart during
+/// rotation. Serialized as a hex string, matches the `bytes32 keyId` of the contracts.
+#[serde_as]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Hash, Debug)]
+#[serde(transparent)]
+pub struct KeyId(#[serde_as(as = "Hex")] pub [u8; 32]);
+
+impl fmt::Display for KeyId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
 pub(crate) type Timestamp = u64;
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
@@ -17,4 +40,55 @@ pub struct VerificationData {
     pub valid_until: Timestamp,
     pub signature: Signature,
     pub pub_key: PublicKey,
+    /// Id of `pub_key`, missing in data signed before key ids were introduced
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_id: Option<KeyId>,
+    /// Replaces the notary signature for records of DNSSEC-signed zones
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dnssec_proof: Option<DnssecProof>,
+}
+
+/// RRset in DNS wire format, together with its RRSIG records
+#[serde_as]
+#[derive(Serialize, Deserialize, Clone, Default, PartialEq, Debug)]
+pub struct SignedRRset {
+    #[serde_as(as = "Vec<Base64>")]
+    pub records: Vec<Bytes>,
+    #[serde_as(as = "Vec<Base64>")]
+    pub signatures: Vec<Bytes>,
+}
+
+/// Chain of trust from the root zone down to the zone of the record
+#[derive(Serialize, Deserialize, Clone, Default, PartialEq, Debug)]
+pub struct DnssecProof {
+    /// DNSKEY RRsets, starting with the root zone
+    pub dnskeys: Vec<SignedRRset>,
+    /// DS RRsets delegating to each zone but the root, signed by its parent
+    pub delegations: Vec<SignedRRset>,
+    /// TXT RRset containing the record
+    pub answer: SignedRRset,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_id_is_sha256_of_public_key() {
+        let key_id = PublicKey(Bytes::from_static(b"public key")).key_id();
+
+        assert_eq!(
+            key_id.to_string(),
+            "f569a86d3c2c8d7dda26b5dbea20bd5c19eeb35dfc63fdb724bac4f21c227850"
+        );
+    }
+
+    #[test]
+    fn key_id_is_serialized_as_hex() {
+        let key_id = PublicKey(Bytes::from_static(b"public key")).key_id();
+        let json = serde_json::to_value(&key_id).unwrap();
+
+        assert_eq!(json, "f569a86d3c2c8d7dda26b5dbea20bd5c19eeb35dfc63fdb724bac4f21c227850");
+        assert_eq!(serde_json::from_value::<KeyId>(json).unwrap(), key_id);
+    }
 }
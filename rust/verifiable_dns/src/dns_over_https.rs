@@ -1,11 +1,13 @@
 #[cfg(feature = "http")]
 mod external_provider;
 pub(crate) mod provider;
+mod stub_provider;
 pub(crate) mod types;
 
 #[cfg(feature = "http")]
-pub use external_provider::ExternalProvider;
+pub use external_provider::{ExternalProvider, ExternalProviderError};
 pub use provider::Provider;
+pub use stub_provider::StubProvider;
 pub use types::{Query, Response};
 
 pub const MIME_DNS_JSON_CONTENT_TYPE: &str = "application/dns-json";
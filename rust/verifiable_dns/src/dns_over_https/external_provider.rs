@@ -11,9 +11,9 @@ const GOOGLE_BASE_URL: &str = "https://8.8.8.8/resolve";
 const DNS_SB_BASE_URL: &str = "https://185.222.222.222/dns-query";
 const REQUEST_TIMEOUT_SECS: u64 = 10;
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct ExternalProvider {
-    base_url: &'static str,
+    base_url: String,
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -37,16 +37,19 @@ impl Provider for ExternalProvider {
 }
 
 impl ExternalProvider {
-    pub const fn google_provider() -> Self {
+    /// Provider serving the JSON API of DNS over HTTPS at the given URL
+    pub fn new(base_url: impl Into<String>) -> Self {
         Self {
-            base_url: GOOGLE_BASE_URL,
+            base_url: base_url.into(),
         }
     }
 
-    pub const fn dns_sb_provider() -> Self {
-        Self {
-            base_url: DNS_SB_BASE_URL,
-        }
+    pub fn google_provider() -> Self {
+        Self::new(GOOGLE_BASE_URL)
+    }
+
+    pub fn dns_sb_provider() -> Self {
+        Self::new(DNS_SB_BASE_URL)
     }
 
     fn client(&self) -> RequestBuilder {
@@ -55,7 +58,7 @@ impl ExternalProvider {
         ClientBuilder::new(Client::new())
             .with(RetryTransientMiddleware::new_with_policy(retry_policy))
             .build()
-            .get(self.base_url)
+            .get(&self.base_url)
             .header(ACCEPT, MIME_DNS_JSON_CONTENT_TYPE)
             .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
     }
@@ -0,0 +1,89 @@
+use std::convert::Infallible;
+
+use super::{
+    Query, Response,
+    types::{Record, RecordType},
+};
+use crate::Provider;
+
+const NXDOMAIN: u32 = 3;
+
+/// Answers queries from a fixed set of records, so that the DNS service can run without network
+/// access, e.g. in tests
+#[derive(Clone, Default, Debug)]
+pub struct StubProvider {
+    records: Vec<Record>,
+}
+
+impl StubProvider {
+    pub const fn new(records: Vec<Record>) -> Self {
+        Self { records }
+    }
+
+    fn matching_records(&self, name: &str, record_type: &RecordType) -> Vec<Record> {
+        let name = name.trim_end_matches('.');
+        self.records
+            .iter()
+            .filter(|record| {
+                record.name.trim_end_matches('.').eq_ignore_ascii_case(name)
+                    && record.record_type == *record_type
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+impl Provider for StubProvider {
+    type Error = Infallible;
+
+    async fn resolve(&self, query: &Query) -> Result<Response, Self::Error> {
+        let answer = self.matching_records(&query.name, &query.record_type);
+
+        Ok(Response {
+            status: if answer.is_empty() { NXDOMAIN } else { 0 },
+            question: vec![query.clone()],
+            answer: Some(answer).filter(|answer| !answer.is_empty()),
+            ..Response::default()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(name: &str) -> Record {
+        Record {
+            name: name.into(),
+            record_type: RecordType::TXT,
+            ttl: 300,
+            data: "v=DKIM1; p=key".into(),
+        }
+    }
+
+    fn provider() -> StubProvider {
+        StubProvider::new(vec![record("google._domainkey.vlayer.xyz"), record("vlayer.xyz")])
+    }
+
+    #[tokio::test]
+    async fn answers_with_matching_records() {
+        let query = "Google._domainkey.vlayer.xyz".into();
+
+        let response = provider().resolve(&query).await.unwrap();
+
+        assert_eq!(response.status, 0);
+        assert_eq!(response.question, [query]);
+        assert_eq!(response.answer, Some(vec![record("google._domainkey.vlayer.xyz")]));
+    }
+
+    #[tokio::test]
+    async fn returns_nxdomain_for_unknown_name() {
+        let response = provider()
+            .resolve(&"selector._domainkey.vlayer.xyz".into())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status, NXDOMAIN);
+        assert_eq!(response.answer, None);
+    }
+}
@@ -1,16 +1,19 @@
 use serde::{Deserialize, Deserializer, Serialize};
 use serde_repr::Serialize_repr;
 
-use crate::{RecordVerifierError, VerificationData, verifier::verify_signature};
+use crate::{
+    RecordVerifierError, VerificationData,
+    verifier::{dnssec::verify_dnssec_proof, verify_signature},
+};
 
-#[derive(Serialize, Deserialize, Clone, Default, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash, Debug)]
 pub struct Query {
     pub name: String,
     #[serde(rename = "type")]
-    record_type: RecordType,
+    pub(crate) record_type: RecordType,
 }
 
-#[derive(Serialize_repr, Clone, Default, PartialEq, Debug)]
+#[derive(Serialize_repr, Clone, Default, PartialEq, Eq, Hash, Debug)]
 #[repr(u8)]
 pub enum RecordType {
     #[allow(clippy::upper_case_acronyms)]
@@ -90,6 +93,14 @@ pub struct Record {
 
 impl Record {
     pub fn verify(&self, verification_data: &VerificationData) -> Result<(), RecordVerifierError> {
+        if let Some(dnssec_proof) = &verification_data.dnssec_proof {
+            return Ok(verify_dnssec_proof(self, dnssec_proof, verification_data.valid_until)?);
+        }
+        if let Some(key_id) = &verification_data.key_id {
+            if *key_id != verification_data.pub_key.key_id() {
+                return Err(RecordVerifierError::KeyIdMismatch(key_id.clone()));
+            }
+        }
         verify_signature(
             self,
             verification_data.valid_until,
@@ -5,15 +5,15 @@ mod common;
 pub mod verifiable_dns;
 
 #[cfg(feature = "http")]
-pub use dns_over_https::ExternalProvider;
+pub use dns_over_https::{ExternalProvider, ExternalProviderError};
 #[allow(dead_code)]
 mod verifier;
 
-pub use common::types::{PublicKey, Signature, VerificationData};
+pub use common::types::{DnssecProof, KeyId, PublicKey, Signature, SignedRRset, VerificationData};
 pub use dns_over_https::{
-    MIME_DNS_JSON_CONTENT_TYPE, Provider, Query, Response,
+    MIME_DNS_JSON_CONTENT_TYPE, Provider, Query, Response, StubProvider,
     types::{Record as DNSRecord, RecordType},
 };
 #[cfg(feature = "http")]
 pub use verifiable_dns::VerifiableDNSResolver;
-pub use verifier::RecordVerifierError;
+pub use verifier::{RecordVerifierError, dnssec::DnssecError};
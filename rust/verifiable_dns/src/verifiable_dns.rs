@@ -1,3 +1,4 @@
+pub mod key_set;
 pub(crate) mod resolver;
 pub mod sign_record;
 pub mod signer;
@@ -11,4 +12,4 @@ use time::RTClock;
 use crate::dns_over_https::ExternalProvider;
 
 #[cfg(feature = "http")]
-pub type VerifiableDNSResolver = Resolver<RTClock, ExternalProvider, 2>;
+pub type VerifiableDNSResolver = Resolver<RTClock, ExternalProvider>;
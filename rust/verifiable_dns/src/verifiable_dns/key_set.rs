@@ -0,0 +1,183 @@
+use serde::{Deserialize, Serialize};
+
+use super::{signer::Signer, time::Timestamp};
+use crate::{KeyId, PublicKey};
+
+/// Signing key together with the window in which it is published
+#[derive(Clone)]
+pub struct KeySetEntry {
+    signer: Signer,
+    not_before: Option<Timestamp>,
+    not_after: Option<Timestamp>,
+}
+
+impl KeySetEntry {
+    pub const fn signer(&self) -> &Signer {
+        &self.signer
+    }
+
+    pub const fn not_after(&self) -> Option<Timestamp> {
+        self.not_after
+    }
+
+    fn is_valid_at(&self, now: Timestamp) -> bool {
+        self.not_before.is_none_or(|not_before| not_before <= now)
+            && self.not_after.is_none_or(|not_after| now < not_after)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyStatus {
+    /// Not used for signing yet, published so that verifiers can trust it before the rotation
+    Pending,
+    /// Used for signing new records
+    Active,
+    /// Superseded by a newer key, but still valid for records signed with it before
+    Retiring,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct PublishedKey {
+    pub key_id: KeyId,
+    pub public_key: PublicKey,
+    pub status: KeyStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub not_before: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub not_after: Option<Timestamp>,
+}
+
+/// Keys a resolver can sign with. At any time, records are signed with the valid key which became
+/// valid most recently, and the other valid keys are retiring.
+#[derive(Clone, Default)]
+pub struct KeySet {
+    keys: Vec<KeySetEntry>,
+}
+
+impl KeySet {
+    /// Key set with a single key which never expires
+    pub fn new(signer: Signer) -> Self {
+        Self::default().with_key(signer, None, None)
+    }
+
+    /// Adds a key valid from `not_before` (inclusive) until `not_after` (exclusive)
+    #[must_use]
+    pub fn with_key(
+        mut self,
+        signer: Signer,
+        not_before: Option<Timestamp>,
+        not_after: Option<Timestamp>,
+    ) -> Self {
+        self.keys.push(KeySetEntry {
+            signer,
+            not_before,
+            not_after,
+        });
+        self
+    }
+
+    /// Key used for signing at `now`. Of keys becoming valid at the same time, the one added last
+    /// is used.
+    pub fn active_key(&self, now: Timestamp) -> Option<&KeySetEntry> {
+        self.keys
+            .iter()
+            .filter(|key| key.is_valid_at(now))
+            .max_by_key(|key| key.not_before)
+    }
+
+    /// Keys which are or will be valid, expired keys are omitted
+    pub fn published_keys(&self, now: Timestamp) -> Vec<PublishedKey> {
+        let active_key = self.active_key(now);
+        self.keys
+            .iter()
+            .filter(|key| key.not_after.is_none_or(|not_after| now < not_after))
+            .map(|key| {
+                let status = if active_key.is_some_and(|active| std::ptr::eq(active, key)) {
+                    KeyStatus::Active
+                } else if key.is_valid_at(now) {
+                    KeyStatus::Retiring
+                } else {
+                    KeyStatus::Pending
+                };
+                PublishedKey {
+                    key_id: key.signer.key_id(),
+                    public_key: key.signer.public_key(),
+                    status,
+                    not_before: key.not_before,
+                    not_after: key.not_after,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OTHER_PRIV_KEY: &str = include_str!("../../assets/test_private_key.pem");
+
+    fn key_set() -> KeySet {
+        KeySet::default()
+            .with_key(Signer::default(), None, Some(200))
+            .with_key(Signer::new(OTHER_PRIV_KEY), Some(100), None)
+    }
+
+    fn statuses(key_set: &KeySet, now: Timestamp) -> Vec<(KeyId, KeyStatus)> {
+        key_set
+            .published_keys(now)
+            .into_iter()
+            .map(|key| (key.key_id, key.status))
+            .collect()
+    }
+
+    #[test]
+    fn signs_with_most_recent_valid_key() {
+        let key_set = key_set();
+
+        assert_eq!(key_set.active_key(99).unwrap().signer().key_id(), Signer::default().key_id());
+        assert_eq!(
+            key_set.active_key(100).unwrap().signer().key_id(),
+            Signer::new(OTHER_PRIV_KEY).key_id()
+        );
+    }
+
+    #[test]
+    fn has_no_active_key_outside_of_windows() {
+        let key_set = KeySet::default().with_key(Signer::default(), Some(100), Some(200));
+
+        assert!(key_set.active_key(99).is_none());
+        assert!(key_set.active_key(200).is_none());
+    }
+
+    #[test]
+    fn publishes_pending_key_before_rotation() {
+        assert_eq!(
+            statuses(&key_set(), 50),
+            [
+                (Signer::default().key_id(), KeyStatus::Active),
+                (Signer::new(OTHER_PRIV_KEY).key_id(), KeyStatus::Pending),
+            ]
+        );
+    }
+
+    #[test]
+    fn publishes_retiring_key_after_rotation() {
+        assert_eq!(
+            statuses(&key_set(), 150),
+            [
+                (Signer::default().key_id(), KeyStatus::Retiring),
+                (Signer::new(OTHER_PRIV_KEY).key_id(), KeyStatus::Active),
+            ]
+        );
+    }
+
+    #[test]
+    fn omits_expired_keys() {
+        assert_eq!(
+            statuses(&key_set(), 200),
+            [(Signer::new(OTHER_PRIV_KEY).key_id(), KeyStatus::Active)]
+        );
+    }
+}
@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+
+use parking_lot::Mutex;
+
+use crate::{Query, Response, verifiable_dns::time::Timestamp};
+
+struct Entry {
+    response: Response,
+    cached_at: Timestamp,
+    expires_at: Timestamp,
+}
+
+/// Provider responses kept for as long as the shortest TTL of their records
+pub(super) struct ResponseCache {
+    capacity: usize,
+    entries: Mutex<HashMap<Query, Entry>>,
+}
+
+impl ResponseCache {
+    pub(super) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Mutex::default(),
+        }
+    }
+
+    /// Returns the cached response with TTLs reduced by the time it spent in the cache
+    pub(super) fn get(&self, query: &Query, now: Timestamp) -> Option<Response> {
+        let mut entries = self.entries.lock();
+        let (cached_at, expires_at) = entries
+            .get(query)
+            .map(|entry| (entry.cached_at, entry.expires_at))?;
+        if now >= expires_at {
+            entries.remove(query);
+            return None;
+        }
+
+        let age = now.saturating_sub(cached_at);
+        let mut response = entries.get(query)?.response.clone();
+        for record in response.answer.iter_mut().flatten() {
+            record.ttl = record.ttl.saturating_sub(age);
+        }
+        Some(response)
+    }
+
+    /// Responses without records or with a zero TTL are not cached. Once the cache is full, expired
+    /// entries are evicted, and if none are, the response is not cached.
+    pub(super) fn insert(&self, query: Query, response: Response, now: Timestamp) {
+        let Some(ttl) = response
+            .answer
+            .iter()
+            .flatten()
+            .map(|record| record.ttl)
+            .min()
+            .filter(|ttl| *ttl > 0)
+        else {
+            return;
+        };
+
+        let mut entries = self.entries.lock();
+        if entries.len() >= self.capacity && !entries.contains_key(&query) {
+            entries.retain(|_, entry| entry.expires_at > now);
+            if entries.len() >= self.capacity {
+                return;
+            }
+        }
+        entries.insert(
+            query,
+            Entry {
+                response,
+                cached_at: now,
+                expires_at: now.saturating_add(ttl),
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DNSRecord, RecordType};
+
+    fn response(ttls: &[u64]) -> Response {
+        let records = ttls
+            .iter()
+            .map(|ttl| DNSRecord {
+                name: "vlayer.xyz".into(),
+                record_type: RecordType::TXT,
+                ttl: *ttl,
+                data: "some data".into(),
+            })
+            .collect();
+        Response {
+            answer: Some(records),
+            ..Default::default()
+        }
+    }
+
+    fn ttls(response: &Response) -> Vec<u64> {
+        response
+            .answer
+            .iter()
+            .flatten()
+            .map(|record| record.ttl)
+            .collect()
+    }
+
+    #[test]
+    fn returns_response_with_remaining_ttl() {
+        let cache = ResponseCache::new(10);
+        cache.insert("vlayer.xyz".into(), response(&[300, 100]), 1000);
+
+        let cached = cache.get(&"vlayer.xyz".into(), 1060).unwrap();
+
+        assert_eq!(ttls(&cached), [240, 40]);
+    }
+
+    #[test]
+    fn expires_with_shortest_ttl() {
+        let cache = ResponseCache::new(10);
+        cache.insert("vlayer.xyz".into(), response(&[300, 100]), 1000);
+
+        assert!(cache.get(&"vlayer.xyz".into(), 1099).is_some());
+        assert!(cache.get(&"vlayer.xyz".into(), 1100).is_none());
+    }
+
+    #[test]
+    fn skips_responses_with_zero_ttl() {
+        let cache = ResponseCache::new(10);
+        cache.insert("vlayer.xyz".into(), response(&[300, 0]), 1000);
+        cache.insert("google._domainkey.vlayer.xyz".into(), response(&[]), 1000);
+
+        assert!(cache.get(&"vlayer.xyz".into(), 1000).is_none());
+        assert!(
+            cache
+                .get(&"google._domainkey.vlayer.xyz".into(), 1000)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn evicts_expired_entries_when_full() {
+        let cache = ResponseCache::new(1);
+        cache.insert("vlayer.xyz".into(), response(&[100]), 1000);
+        cache.insert("google._domainkey.vlayer.xyz".into(), response(&[100]), 1100);
+
+        assert!(
+            cache
+                .get(&"google._domainkey.vlayer.xyz".into(), 1100)
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn keeps_valid_entries_when_full() {
+        let cache = ResponseCache::new(1);
+        cache.insert("vlayer.xyz".into(), response(&[100]), 1000);
+        cache.insert("google._domainkey.vlayer.xyz".into(), response(&[100]), 1050);
+
+        assert!(cache.get(&"vlayer.xyz".into(), 1050).is_some());
+        assert!(
+            cache
+                .get(&"google._domainkey.vlayer.xyz".into(), 1050)
+                .is_none()
+        );
+    }
+}
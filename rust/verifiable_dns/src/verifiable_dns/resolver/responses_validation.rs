@@ -213,6 +213,8 @@ mod tests {
                     valid_until: 1,
                     signature: Signature(Default::default()),
                     pub_key: PublicKey(Default::default()),
+                    key_id: None,
+                    dnssec_proof: None,
                 }),
                 ..Default::default()
             };
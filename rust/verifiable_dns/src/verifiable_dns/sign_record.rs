@@ -15,5 +15,7 @@ pub fn sign_record(
         signature,
         valid_until,
         pub_key: signer.public_key(),
+        key_id: Some(signer.key_id()),
+        dnssec_proof: None,
     }
 }
@@ -6,7 +6,7 @@ use rsa::{
     signature::{Keypair, RandomizedSigner, SignatureEncoding},
 };
 
-use crate::{PublicKey, Signature, common::to_payload::ToPayload};
+use crate::{KeyId, PublicKey, Signature, common::to_payload::ToPayload};
 
 const PRIV_KEY: &str = include_str!("../../assets/private_key.pem");
 
@@ -47,6 +47,10 @@ impl Signer {
             .expect("Failed to encode public key");
         PublicKey(pub_key.into_vec().into())
     }
+
+    pub fn key_id(&self) -> KeyId {
+        self.public_key().key_id()
+    }
 }
 
 impl From<PublicKey> for pkcs1v15::VerifyingKey<Sha256> {
@@ -0,0 +1,560 @@
+//! DNSSEC validation (RFC 4035 §5) of a TXT record against the root trust anchors. Supports the
+//! RSASHA256 and ECDSAP256SHA256 algorithms with SHA-256 DS digests, which cover the vast majority
+//! of signed zones. Wildcard expansions are rejected.
+
+mod crypto;
+mod wire;
+
+use std::fmt;
+
+use crypto::{DIGEST_SHA256, RSASHA256, ds_matches, verify_signature};
+use hex_literal::hex;
+use wire::{Dnskey, Ds, Name, ResourceRecord, Rrsig, TYPE_DNSKEY, TYPE_DS, TYPE_RRSIG, TYPE_TXT};
+
+use crate::{
+    common::types::{DnssecProof, SignedRRset},
+    dns_over_https::types::{Record as DNSRecord, RecordType},
+};
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum DnssecError {
+    #[error("Invalid wire format: {0}")]
+    InvalidWireFormat(String),
+    #[error("Invalid chain of trust: {0}")]
+    InvalidChain(String),
+    #[error("No DNSKEY of zone {0} matches a trusted DS record")]
+    UntrustedZone(String),
+    #[error("No RRSIG of {0} made with a key of the zone")]
+    MissingSignature(String),
+    #[error("RRSIG of {0} is not valid at {1}")]
+    SignatureExpired(String, u64),
+    #[error("Invalid RRSIG of {0}")]
+    SignatureVerification(String),
+    #[error("Unsupported DNSSEC algorithm: {0}")]
+    UnsupportedAlgorithm(u8),
+    #[error("Invalid DNSKEY: {0}")]
+    InvalidPublicKey(String),
+    #[error("Record {0} is not part of the signed answer")]
+    RecordMismatch(String),
+}
+
+/// Root zone KSK-2017 and KSK-2024 from https://data.iana.org/root-anchors/root-anchors.xml
+fn root_trust_anchors() -> [Ds; 2] {
+    [
+        Ds {
+            key_tag: 20326,
+            algorithm: RSASHA256,
+            digest_type: DIGEST_SHA256,
+            digest: hex!("E06D44B80B8F1D39A95C0B0D7C65D08458E880409BBC683457104237C7F8EC8D")
+                .to_vec(),
+        },
+        Ds {
+            key_tag: 38696,
+            algorithm: RSASHA256,
+            digest_type: DIGEST_SHA256,
+            digest: hex!("683D2D0ACB8C9B712A1948B27F741219298D0A450D612C483AF444A4C0FB2B16")
+                .to_vec(),
+        },
+    ]
+}
+
+/// Verifies that the record belongs to a TXT RRset signed by a chain of keys leading to the root
+/// trust anchors. Every signature of the chain has to be valid at `valid_until`, so that it doesn't
+/// expire before the record does.
+pub fn verify_dnssec_proof(
+    record: &DNSRecord,
+    proof: &DnssecProof,
+    valid_until: u64,
+) -> Result<(), DnssecError> {
+    verify_chain(record, proof, valid_until, &root_trust_anchors())
+}
+
+fn verify_chain(
+    record: &DNSRecord,
+    proof: &DnssecProof,
+    valid_until: u64,
+    trust_anchors: &[Ds],
+) -> Result<(), DnssecError> {
+    let Some((root_keys, zone_keys)) = proof.dnskeys.split_first() else {
+        return Err(DnssecError::InvalidChain("missing root DNSKEY RRset".into()));
+    };
+    if zone_keys.len() != proof.delegations.len() {
+        return Err(DnssecError::InvalidChain(format!(
+            "expected {} DS RRsets, found {}",
+            zone_keys.len(),
+            proof.delegations.len()
+        )));
+    }
+
+    let root_keys = RRset::parse(root_keys, TYPE_DNSKEY)?;
+    if root_keys.owner != Name::root() {
+        return Err(DnssecError::InvalidChain(format!("{} is not the root zone", root_keys.owner)));
+    }
+    let mut zone = Zone::authenticate(&root_keys, trust_anchors, valid_until)?;
+
+    for (dnskeys, delegation) in zone_keys.iter().zip(&proof.delegations) {
+        let dnskeys = RRset::parse(dnskeys, TYPE_DNSKEY)?;
+        let delegation = RRset::parse(delegation, TYPE_DS)?;
+        if delegation.owner != dnskeys.owner || delegation.owner == zone.name {
+            return Err(DnssecError::InvalidChain(format!(
+                "{} is not delegated from {}",
+                dnskeys.owner, zone.name
+            )));
+        }
+        zone.verify(&delegation, valid_until)?;
+
+        let trusted = delegation
+            .rdatas
+            .iter()
+            .map(|rdata| Ds::parse(rdata))
+            .collect::<Result<Vec<_>, _>>()?;
+        zone = Zone::authenticate(&dnskeys, &trusted, valid_until)?;
+    }
+
+    let answer = RRset::parse(&proof.answer, TYPE_TXT)?;
+    zone.verify(&answer, valid_until)?;
+    verify_record_in_answer(record, &answer)
+}
+
+fn verify_record_in_answer(record: &DNSRecord, answer: &RRset) -> Result<(), DnssecError> {
+    if record.record_type == RecordType::TXT && Name::from_ascii(&record.name)? == answer.owner {
+        for rdata in &answer.rdatas {
+            if wire::txt_data(rdata)? == record.data.as_bytes() {
+                return Ok(());
+            }
+        }
+    }
+    Err(DnssecError::RecordMismatch(record.name.clone()))
+}
+
+struct RRset {
+    owner: Name,
+    record_type: u16,
+    rdatas: Vec<Vec<u8>>,
+    signatures: Vec<Rrsig>,
+}
+
+impl RRset {
+    fn parse(signed: &SignedRRset, record_type: u16) -> Result<Self, DnssecError> {
+        let records = signed
+            .records
+            .iter()
+            .map(|record| ResourceRecord::parse(record))
+            .collect::<Result<Vec<_>, _>>()?;
+        let owner = records
+            .first()
+            .map(|record| record.owner.clone())
+            .ok_or_else(|| DnssecError::InvalidChain("empty RRset".into()))?;
+        if records
+            .iter()
+            .any(|record| record.owner != owner || record.record_type != record_type)
+        {
+            return Err(DnssecError::InvalidChain(format!("mixed RRset at {owner}")));
+        }
+
+        let mut signatures = Vec::new();
+        for signature in &signed.signatures {
+            let signature = ResourceRecord::parse(signature)?;
+            if signature.owner != owner || signature.record_type != TYPE_RRSIG {
+                return Err(DnssecError::InvalidChain(format!("unrelated RRSIG of {owner}")));
+            }
+            let rrsig = Rrsig::parse(&signature.rdata)?;
+            if rrsig.type_covered == record_type {
+                signatures.push(rrsig);
+            }
+        }
+
+        Ok(Self {
+            owner,
+            record_type,
+            rdatas: records.into_iter().map(|record| record.rdata).collect(),
+            signatures,
+        })
+    }
+
+    /// Accepts the RRset if any of its signatures was made by one of the keys of the signer zone.
+    /// RRSIGs with fewer labels than the owner name come from wildcard expansion and are ignored.
+    fn verify(&self, signer: &Name, keys: &[&Dnskey], valid_until: u64) -> Result<(), DnssecError> {
+        let mut result = Err(DnssecError::MissingSignature(self.to_string()));
+        let signatures = self.signatures.iter().filter(|rrsig| {
+            rrsig.signer == *signer && usize::from(rrsig.labels) == self.owner.label_count()
+        });
+        for rrsig in signatures {
+            let matching_keys = keys
+                .iter()
+                .filter(|key| key.algorithm == rrsig.algorithm && key.key_tag() == rrsig.key_tag);
+            for key in matching_keys {
+                if !rrsig.is_valid_at(valid_until) {
+                    result = Err(DnssecError::SignatureExpired(self.to_string(), valid_until));
+                    continue;
+                }
+                let signed_data = rrsig.signed_data(&self.owner, &self.rdatas)?;
+                match verify_signature(key, &signed_data, &rrsig.signature) {
+                    Ok(true) => return Ok(()),
+                    Ok(false) => {
+                        result = Err(DnssecError::SignatureVerification(self.to_string()));
+                    }
+                    Err(err) => result = Err(err),
+                }
+            }
+        }
+        result
+    }
+}
+
+impl fmt::Display for RRset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let record_type = match self.record_type {
+            TYPE_TXT => "TXT",
+            TYPE_DS => "DS",
+            TYPE_DNSKEY => "DNSKEY",
+            _ => "RRset",
+        };
+        write!(f, "{} {record_type}", self.owner)
+    }
+}
+
+/// Zone whose DNSKEY RRset has been authenticated
+struct Zone {
+    name: Name,
+    keys: Vec<Dnskey>,
+}
+
+impl Zone {
+    /// Trusts the DNSKEY RRset if it is signed by one of its keys matching a trusted DS record
+    fn authenticate(
+        dnskeys: &RRset,
+        trusted: &[Ds],
+        valid_until: u64,
+    ) -> Result<Self, DnssecError> {
+        let keys: Vec<Dnskey> = dnskeys
+            .rdatas
+            .iter()
+            .map(|rdata| Dnskey::parse(rdata))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .filter(Dnskey::is_zone_key)
+            .collect();
+        let entry_keys: Vec<&Dnskey> = keys
+            .iter()
+            .filter(|key| trusted.iter().any(|ds| ds_matches(ds, &dnskeys.owner, key)))
+            .collect();
+        if entry_keys.is_empty() {
+            return Err(DnssecError::UntrustedZone(dnskeys.owner.to_string()));
+        }
+        dnskeys.verify(&dnskeys.owner, &entry_keys, valid_until)?;
+
+        Ok(Self {
+            name: dnskeys.owner.clone(),
+            keys,
+        })
+    }
+
+    fn verify(&self, rrset: &RRset, valid_until: u64) -> Result<(), DnssecError> {
+        if !rrset.owner.is_subdomain_of(&self.name) {
+            return Err(DnssecError::InvalidChain(format!(
+                "{} is outside of zone {}",
+                rrset.owner, self.name
+            )));
+        }
+        rrset.verify(&self.name, &self.keys.iter().collect::<Vec<_>>(), valid_until)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use p256::ecdsa;
+    use rsa::{
+        RsaPrivateKey, pkcs1v15,
+        pkcs8::DecodePrivateKey,
+        sha2::{Digest, Sha256},
+        signature::{SignatureEncoding, Signer},
+        traits::PublicKeyParts,
+    };
+
+    use super::{crypto::ECDSAP256SHA256, *};
+
+    const PRIV_KEY: &str = include_str!("../../assets/private_key.pem");
+    const RECORD_NAME: &str = "google._domainkey.example";
+    const INCEPTION: u32 = 1_700_000_000;
+    const EXPIRATION: u32 = 1_800_000_000;
+    const VALID_UNTIL: u64 = 1_750_000_000;
+
+    enum TestKey {
+        Ecdsa(ecdsa::SigningKey),
+        Rsa(pkcs1v15::SigningKey<Sha256>),
+    }
+
+    struct TestZone {
+        name: Name,
+        key: TestKey,
+    }
+
+    impl TestZone {
+        fn ecdsa(name: &str, seed: u8) -> Self {
+            let key = ecdsa::SigningKey::from_slice(&[seed; 32]).unwrap();
+            Self::new(name, TestKey::Ecdsa(key))
+        }
+
+        fn rsa(name: &str) -> Self {
+            let key = RsaPrivateKey::from_pkcs8_pem(PRIV_KEY).unwrap();
+            Self::new(name, TestKey::Rsa(pkcs1v15::SigningKey::new(key)))
+        }
+
+        fn new(name: &str, key: TestKey) -> Self {
+            Self {
+                name: Name::from_ascii(name).unwrap(),
+                key,
+            }
+        }
+
+        const fn algorithm(&self) -> u8 {
+            match self.key {
+                TestKey::Ecdsa(_) => ECDSAP256SHA256,
+                TestKey::Rsa(_) => RSASHA256,
+            }
+        }
+
+        fn dnskey(&self) -> Dnskey {
+            let public_key = match &self.key {
+                TestKey::Ecdsa(key) => {
+                    key.verifying_key().to_encoded_point(false).as_bytes()[1..].to_vec()
+                }
+                TestKey::Rsa(key) => {
+                    let key = key.as_ref();
+                    let exponent = key.e().to_bytes_be();
+                    [
+                        &[u8::try_from(exponent.len()).unwrap()][..],
+                        &exponent,
+                        &key.n().to_bytes_be(),
+                    ]
+                    .concat()
+                }
+            };
+            // Flags 257: zone key and secure entry point
+            Dnskey::parse(&[&[1, 1, 3, self.algorithm()][..], &public_key].concat()).unwrap()
+        }
+
+        fn ds(&self) -> Vec<u8> {
+            let dnskey = self.dnskey();
+            let digest = Sha256::new()
+                .chain_update(self.name.to_wire())
+                .chain_update(dnskey.rdata())
+                .finalize();
+            [
+                &dnskey.key_tag().to_be_bytes()[..],
+                &[self.algorithm(), DIGEST_SHA256],
+                digest.as_slice(),
+            ]
+            .concat()
+        }
+
+        fn trust_anchor(&self) -> Ds {
+            Ds::parse(&self.ds()).unwrap()
+        }
+
+        fn sign(&self, owner: &Name, record_type: u16, rdatas: &[Vec<u8>]) -> SignedRRset {
+            let rrsig = Rrsig {
+                type_covered: record_type,
+                algorithm: self.algorithm(),
+                labels: u8::try_from(owner.label_count()).unwrap(),
+                original_ttl: 300,
+                expiration: EXPIRATION,
+                inception: INCEPTION,
+                key_tag: self.dnskey().key_tag(),
+                signer: self.name.clone(),
+                signature: vec![],
+            };
+            let signed_data = rrsig.signed_data(owner, rdatas).unwrap();
+            let signature = match &self.key {
+                TestKey::Ecdsa(key) => {
+                    let signature: ecdsa::Signature = key.sign(&signed_data);
+                    signature.to_bytes().to_vec()
+                }
+                TestKey::Rsa(key) => key.sign(&signed_data).to_vec(),
+            };
+            // RRSIG RDATA is the signed data of an empty RRset followed by the signature
+            let rrsig_rdata = [rrsig.signed_data(owner, &[]).unwrap(), signature].concat();
+
+            SignedRRset {
+                records: rdatas
+                    .iter()
+                    .map(|rdata| resource_record(owner, record_type, rdata))
+                    .collect(),
+                signatures: vec![resource_record(owner, TYPE_RRSIG, &rrsig_rdata)],
+            }
+        }
+
+        fn signed_dnskeys(&self) -> SignedRRset {
+            self.sign(&self.name, TYPE_DNSKEY, &[self.dnskey().rdata().to_vec()])
+        }
+
+        fn delegate(&self, child: &TestZone) -> SignedRRset {
+            self.sign(&child.name, TYPE_DS, &[child.ds()])
+        }
+    }
+
+    fn resource_record(owner: &Name, record_type: u16, rdata: &[u8]) -> Bytes {
+        let rdata_length = u16::try_from(rdata.len()).unwrap();
+        [
+            &owner.to_wire()[..],
+            &record_type.to_be_bytes(),
+            &[0, 1, 0, 0, 1, 44],
+            &rdata_length.to_be_bytes(),
+            rdata,
+        ]
+        .concat()
+        .into()
+    }
+
+    fn txt(strings: &[&str]) -> Vec<u8> {
+        strings
+            .iter()
+            .flat_map(|string| {
+                [&[u8::try_from(string.len()).unwrap()][..], string.as_bytes()].concat()
+            })
+            .collect()
+    }
+
+    fn record(data: &str) -> DNSRecord {
+        DNSRecord {
+            name: RECORD_NAME.into(),
+            record_type: RecordType::TXT,
+            ttl: 300,
+            data: data.into(),
+        }
+    }
+
+    fn proof(root: &TestZone, zone: &TestZone) -> DnssecProof {
+        let owner = Name::from_ascii(RECORD_NAME).unwrap();
+        DnssecProof {
+            dnskeys: vec![root.signed_dnskeys(), zone.signed_dnskeys()],
+            delegations: vec![root.delegate(zone)],
+            answer: zone.sign(&owner, TYPE_TXT, &[txt(&["v=DKIM1; ", "p=key"])]),
+        }
+    }
+
+    fn verify(record: &DNSRecord, proof: &DnssecProof) -> Result<(), DnssecError> {
+        verify_chain(record, proof, VALID_UNTIL, &[root().trust_anchor()])
+    }
+
+    fn root() -> TestZone {
+        TestZone::ecdsa(".", 1)
+    }
+
+    fn zone() -> TestZone {
+        TestZone::ecdsa("example", 2)
+    }
+
+    #[test]
+    fn verifies_chain() {
+        verify(&record("v=DKIM1; p=key"), &proof(&root(), &zone())).unwrap();
+    }
+
+    #[test]
+    fn verifies_rsa_signatures() {
+        let root = TestZone::rsa(".");
+        let proof = proof(&root, &zone());
+
+        verify_chain(&record("v=DKIM1; p=key"), &proof, VALID_UNTIL, &[root.trust_anchor()])
+            .unwrap();
+    }
+
+    #[test]
+    fn fails_for_record_outside_of_answer() {
+        assert_eq!(
+            verify(&record("v=DKIM1; p=other"), &proof(&root(), &zone())).unwrap_err(),
+            DnssecError::RecordMismatch(RECORD_NAME.into())
+        );
+    }
+
+    #[test]
+    fn fails_for_record_of_other_name() {
+        let record = DNSRecord {
+            name: "selector._domainkey.example".into(),
+            ..record("v=DKIM1; p=key")
+        };
+
+        assert_eq!(
+            verify(&record, &proof(&root(), &zone())).unwrap_err(),
+            DnssecError::RecordMismatch("selector._domainkey.example".into())
+        );
+    }
+
+    #[test]
+    fn fails_for_tampered_answer() {
+        let owner = Name::from_ascii(RECORD_NAME).unwrap();
+        let mut proof = proof(&root(), &zone());
+        proof.answer.records = vec![resource_record(&owner, TYPE_TXT, &txt(&["v=DKIM1; p=other"]))];
+
+        assert_eq!(
+            verify(&record("v=DKIM1; p=other"), &proof).unwrap_err(),
+            DnssecError::SignatureVerification("google._domainkey.example. TXT".into())
+        );
+    }
+
+    #[test]
+    fn fails_for_untrusted_root() {
+        let proof = proof(&root(), &zone());
+
+        assert_eq!(
+            verify_chain(&record("v=DKIM1; p=key"), &proof, VALID_UNTIL, &[zone().trust_anchor()])
+                .unwrap_err(),
+            DnssecError::UntrustedZone(".".into())
+        );
+    }
+
+    #[test]
+    fn fails_for_undelegated_zone() {
+        let impostor = TestZone::ecdsa("example", 3);
+        let proof = DnssecProof {
+            delegations: vec![root().delegate(&zone())],
+            ..proof(&root(), &impostor)
+        };
+
+        assert_eq!(
+            verify(&record("v=DKIM1; p=key"), &proof).unwrap_err(),
+            DnssecError::UntrustedZone("example.".into())
+        );
+    }
+
+    #[test]
+    fn fails_for_answer_signed_by_other_zone() {
+        let owner = Name::from_ascii(RECORD_NAME).unwrap();
+        let proof = DnssecProof {
+            answer: root().sign(&owner, TYPE_TXT, &[txt(&["v=DKIM1; p=key"])]),
+            ..proof(&root(), &zone())
+        };
+
+        assert_eq!(
+            verify(&record("v=DKIM1; p=key"), &proof).unwrap_err(),
+            DnssecError::MissingSignature("google._domainkey.example. TXT".into())
+        );
+    }
+
+    #[test]
+    fn fails_for_expired_signatures() {
+        let proof = proof(&root(), &zone());
+        let valid_until = u64::from(EXPIRATION) + 1;
+
+        assert_eq!(
+            verify_chain(&record("v=DKIM1; p=key"), &proof, valid_until, &[root().trust_anchor()])
+                .unwrap_err(),
+            DnssecError::SignatureExpired(". DNSKEY".into(), valid_until)
+        );
+    }
+
+    #[test]
+    fn fails_for_missing_delegation() {
+        let proof = DnssecProof {
+            delegations: vec![],
+            ..proof(&root(), &zone())
+        };
+
+        assert_eq!(
+            verify(&record("v=DKIM1; p=key"), &proof).unwrap_err(),
+            DnssecError::InvalidChain("expected 1 DS RRsets, found 0".into())
+        );
+    }
+}
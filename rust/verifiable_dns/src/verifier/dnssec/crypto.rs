@@ -0,0 +1,76 @@
+use p256::ecdsa;
+use rsa::{
+    BigUint, RsaPublicKey, pkcs1v15,
+    sha2::{Digest, Sha256},
+    signature::Verifier,
+};
+
+use super::{
+    DnssecError,
+    wire::{Dnskey, Ds, Name},
+};
+
+pub(super) const RSASHA256: u8 = 8;
+pub(super) const ECDSAP256SHA256: u8 = 13;
+pub(super) const DIGEST_SHA256: u8 = 2;
+
+/// Returns `Ok(false)` if the signature doesn't match, and an error if it can't be checked at all
+pub(super) fn verify_signature(
+    dnskey: &Dnskey,
+    data: &[u8],
+    signature: &[u8],
+) -> Result<bool, DnssecError> {
+    match dnskey.algorithm {
+        RSASHA256 => verify_rsa(&dnskey.public_key, data, signature),
+        ECDSAP256SHA256 => verify_ecdsa(&dnskey.public_key, data, signature),
+        algorithm => Err(DnssecError::UnsupportedAlgorithm(algorithm)),
+    }
+}
+
+// RFC 3110 §2: exponent length in one byte, or in two bytes after a zero, followed by the exponent
+// and the modulus
+fn verify_rsa(public_key: &[u8], data: &[u8], signature: &[u8]) -> Result<bool, DnssecError> {
+    let (exponent_length, rest) = match public_key {
+        [0, high, low, rest @ ..] => (usize::from(u16::from_be_bytes([*high, *low])), rest),
+        [length, rest @ ..] => (usize::from(*length), rest),
+        [] => return Err(DnssecError::InvalidPublicKey("empty RSA key".into())),
+    };
+    if rest.len() <= exponent_length {
+        return Err(DnssecError::InvalidPublicKey("RSA key too short".into()));
+    }
+    let (exponent, modulus) = rest.split_at(exponent_length);
+    let public_key =
+        RsaPublicKey::new(BigUint::from_bytes_be(modulus), BigUint::from_bytes_be(exponent))
+            .map_err(|err| DnssecError::InvalidPublicKey(err.to_string()))?;
+
+    let Ok(signature) = pkcs1v15::Signature::try_from(signature) else {
+        return Ok(false);
+    };
+    Ok(pkcs1v15::VerifyingKey::<Sha256>::new(public_key)
+        .verify(data, &signature)
+        .is_ok())
+}
+
+// RFC 6605 §4: the key is the uncompressed point without the 0x04 prefix, the signature is r | s
+fn verify_ecdsa(public_key: &[u8], data: &[u8], signature: &[u8]) -> Result<bool, DnssecError> {
+    let public_key = ecdsa::VerifyingKey::from_sec1_bytes(&[&[0x04][..], public_key].concat())
+        .map_err(|err| DnssecError::InvalidPublicKey(err.to_string()))?;
+
+    let Ok(signature) = ecdsa::Signature::from_slice(signature) else {
+        return Ok(false);
+    };
+    Ok(public_key.verify(data, &signature).is_ok())
+}
+
+/// RFC 4034 §5.1.4: the digest covers the owner name and the DNSKEY RDATA
+pub(super) fn ds_matches(ds: &Ds, owner: &Name, dnskey: &Dnskey) -> bool {
+    ds.digest_type == DIGEST_SHA256
+        && ds.algorithm == dnskey.algorithm
+        && ds.key_tag == dnskey.key_tag()
+        && Sha256::new()
+            .chain_update(owner.to_wire())
+            .chain_update(dnskey.rdata())
+            .finalize()
+            .as_slice()
+            == ds.digest
+}
@@ -0,0 +1,396 @@
+use std::fmt;
+
+use super::DnssecError;
+
+pub(super) const TYPE_TXT: u16 = 16;
+pub(super) const TYPE_DS: u16 = 43;
+pub(super) const TYPE_RRSIG: u16 = 46;
+pub(super) const TYPE_DNSKEY: u16 = 48;
+
+const CLASS_IN: u16 = 1;
+const MAX_LABEL_LENGTH: u8 = 63;
+const DNSKEY_PROTOCOL: u8 = 3;
+const ZONE_KEY_FLAG: u16 = 0x0100;
+
+fn invalid(message: &str) -> DnssecError {
+    DnssecError::InvalidWireFormat(message.into())
+}
+
+/// Domain name in canonical form (RFC 4034 §6.2): lowercase labels, each prefixed with its length.
+/// The empty root label is omitted.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(super) struct Name(Vec<Vec<u8>>);
+
+impl Name {
+    pub(super) const fn root() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Parses a name in presentation format, e.g. `google._domainkey.vlayer.xyz`. Escape sequences
+    /// are not supported.
+    pub(super) fn from_ascii(name: &str) -> Result<Self, DnssecError> {
+        name.split('.')
+            .filter(|label| !label.is_empty())
+            .map(|label| {
+                let length = u8::try_from(label.len())
+                    .ok()
+                    .filter(|length| *length <= MAX_LABEL_LENGTH)
+                    .ok_or_else(|| invalid("label longer than 63 bytes"))?;
+                Ok([&[length][..], label.to_ascii_lowercase().as_bytes()].concat())
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, DnssecError> {
+        let mut labels = Vec::new();
+        loop {
+            let length = reader.u8()?;
+            if length == 0 {
+                return Ok(Self(labels));
+            }
+            if length > MAX_LABEL_LENGTH {
+                return Err(invalid("compressed names are not supported"));
+            }
+            let label = reader.bytes(length.into())?.to_ascii_lowercase();
+            labels.push([&[length][..], label.as_slice()].concat());
+        }
+    }
+
+    pub(super) fn to_wire(&self) -> Vec<u8> {
+        [self.0.concat(), vec![0]].concat()
+    }
+
+    pub(super) fn label_count(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true for the zone itself too
+    pub(super) fn is_subdomain_of(&self, zone: &Name) -> bool {
+        self.0.ends_with(&zone.0)
+    }
+}
+
+impl fmt::Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return write!(f, ".");
+        }
+        for label in &self.0 {
+            let text = label
+                .split_first()
+                .map(|(_, text)| text)
+                .unwrap_or_default();
+            write!(f, "{}.", String::from_utf8_lossy(text))?;
+        }
+        Ok(())
+    }
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    const fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    fn bytes(&mut self, length: usize) -> Result<&'a [u8], DnssecError> {
+        if self.data.len() < length {
+            return Err(invalid("unexpected end of data"));
+        }
+        let (bytes, rest) = self.data.split_at(length);
+        self.data = rest;
+        Ok(bytes)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], DnssecError> {
+        self.bytes(N)?
+            .try_into()
+            .map_err(|_| invalid("unexpected end of data"))
+    }
+
+    fn u8(&mut self) -> Result<u8, DnssecError> {
+        Ok(u8::from_be_bytes(self.array()?))
+    }
+
+    fn u16(&mut self) -> Result<u16, DnssecError> {
+        Ok(u16::from_be_bytes(self.array()?))
+    }
+
+    fn u32(&mut self) -> Result<u32, DnssecError> {
+        Ok(u32::from_be_bytes(self.array()?))
+    }
+
+    fn rest(&mut self) -> &'a [u8] {
+        std::mem::take(&mut self.data)
+    }
+
+    fn finish(self) -> Result<(), DnssecError> {
+        if self.data.is_empty() {
+            Ok(())
+        } else {
+            Err(invalid("unexpected trailing data"))
+        }
+    }
+}
+
+/// Uncompressed resource record of the IN class. The TTL is skipped, as the original TTL from the
+/// RRSIG record is the one covered by the signature.
+pub(super) struct ResourceRecord {
+    pub(super) owner: Name,
+    pub(super) record_type: u16,
+    pub(super) rdata: Vec<u8>,
+}
+
+impl ResourceRecord {
+    pub(super) fn parse(data: &[u8]) -> Result<Self, DnssecError> {
+        let mut reader = Reader::new(data);
+        let owner = Name::read(&mut reader)?;
+        let record_type = reader.u16()?;
+        let class = reader.u16()?;
+        let _ttl = reader.u32()?;
+        let rdata_length = reader.u16()?;
+        let rdata = reader.bytes(rdata_length.into())?.to_vec();
+        reader.finish()?;
+
+        if class != CLASS_IN {
+            return Err(DnssecError::InvalidWireFormat(format!("unsupported class {class}")));
+        }
+        Ok(Self {
+            owner,
+            record_type,
+            rdata,
+        })
+    }
+}
+
+pub(super) struct Rrsig {
+    pub(super) type_covered: u16,
+    pub(super) algorithm: u8,
+    pub(super) labels: u8,
+    pub(super) original_ttl: u32,
+    pub(super) expiration: u32,
+    pub(super) inception: u32,
+    pub(super) key_tag: u16,
+    pub(super) signer: Name,
+    pub(super) signature: Vec<u8>,
+}
+
+impl Rrsig {
+    pub(super) fn parse(rdata: &[u8]) -> Result<Self, DnssecError> {
+        let mut reader = Reader::new(rdata);
+        Ok(Self {
+            type_covered: reader.u16()?,
+            algorithm: reader.u8()?,
+            labels: reader.u8()?,
+            original_ttl: reader.u32()?,
+            expiration: reader.u32()?,
+            inception: reader.u32()?,
+            key_tag: reader.u16()?,
+            signer: Name::read(&mut reader)?,
+            signature: reader.rest().to_vec(),
+        })
+    }
+
+    // Timestamps are compared as plain integers rather than with the serial number arithmetic of
+    // RFC 1982, which only matters after 2106.
+    pub(super) fn is_valid_at(&self, timestamp: u64) -> bool {
+        (u64::from(self.inception)..=u64::from(self.expiration)).contains(&timestamp)
+    }
+
+    /// Data covered by the signature (RFC 4034 §3.1.8.1): the RRSIG RDATA without the signature,
+    /// followed by the RRset in canonical form and order. RDATA of the supported types contains no
+    /// domain names, so it is canonical as is.
+    pub(super) fn signed_data(
+        &self,
+        owner: &Name,
+        rdatas: &[Vec<u8>],
+    ) -> Result<Vec<u8>, DnssecError> {
+        let mut data = Vec::new();
+        data.extend(self.type_covered.to_be_bytes());
+        data.extend([self.algorithm, self.labels]);
+        data.extend(self.original_ttl.to_be_bytes());
+        data.extend(self.expiration.to_be_bytes());
+        data.extend(self.inception.to_be_bytes());
+        data.extend(self.key_tag.to_be_bytes());
+        data.extend(self.signer.to_wire());
+
+        let mut rdatas: Vec<&Vec<u8>> = rdatas.iter().collect();
+        rdatas.sort();
+        rdatas.dedup();
+        let owner = owner.to_wire();
+        for rdata in rdatas {
+            let rdata_length =
+                u16::try_from(rdata.len()).map_err(|_| invalid("RDATA longer than 65535 bytes"))?;
+            data.extend(&owner);
+            data.extend(self.type_covered.to_be_bytes());
+            data.extend(CLASS_IN.to_be_bytes());
+            data.extend(self.original_ttl.to_be_bytes());
+            data.extend(rdata_length.to_be_bytes());
+            data.extend(rdata);
+        }
+        Ok(data)
+    }
+}
+
+pub(super) struct Dnskey {
+    flags: u16,
+    protocol: u8,
+    pub(super) algorithm: u8,
+    pub(super) public_key: Vec<u8>,
+    rdata: Vec<u8>,
+}
+
+impl Dnskey {
+    pub(super) fn parse(rdata: &[u8]) -> Result<Self, DnssecError> {
+        let mut reader = Reader::new(rdata);
+        Ok(Self {
+            flags: reader.u16()?,
+            protocol: reader.u8()?,
+            algorithm: reader.u8()?,
+            public_key: reader.rest().to_vec(),
+            rdata: rdata.to_vec(),
+        })
+    }
+
+    pub(super) const fn is_zone_key(&self) -> bool {
+        self.flags & ZONE_KEY_FLAG != 0 && self.protocol == DNSKEY_PROTOCOL
+    }
+
+    /// RFC 4034 Appendix B
+    pub(super) fn key_tag(&self) -> u16 {
+        let sum = self
+            .rdata
+            .chunks(2)
+            .map(|chunk| match chunk {
+                [high, low] => u32::from(u16::from_be_bytes([*high, *low])),
+                [high] => u32::from(*high) << 8,
+                _ => 0,
+            })
+            .fold(0_u32, u32::wrapping_add);
+        let [_, _, high, low] = sum.wrapping_add(sum >> 16).to_be_bytes();
+        u16::from_be_bytes([high, low])
+    }
+
+    pub(super) fn rdata(&self) -> &[u8] {
+        &self.rdata
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) struct Ds {
+    pub(super) key_tag: u16,
+    pub(super) algorithm: u8,
+    pub(super) digest_type: u8,
+    pub(super) digest: Vec<u8>,
+}
+
+impl Ds {
+    pub(super) fn parse(rdata: &[u8]) -> Result<Self, DnssecError> {
+        let mut reader = Reader::new(rdata);
+        Ok(Self {
+            key_tag: reader.u16()?,
+            algorithm: reader.u8()?,
+            digest_type: reader.u8()?,
+            digest: reader.rest().to_vec(),
+        })
+    }
+}
+
+/// Concatenated character-strings of TXT RDATA, the way DKIM keys split into multiple strings are
+/// joined by resolvers
+pub(super) fn txt_data(rdata: &[u8]) -> Result<Vec<u8>, DnssecError> {
+    let mut reader = Reader::new(rdata);
+    let mut data = Vec::new();
+    while !reader.data.is_empty() {
+        let length = reader.u8()?;
+        data.extend(reader.bytes(length.into())?);
+    }
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_name_case_insensitively() {
+        assert_eq!(
+            Name::from_ascii("Google._DomainKey.vlayer.xyz.")
+                .unwrap()
+                .to_wire(),
+            b"\x06google\x0a_domainkey\x06vlayer\x03xyz\x00"
+        );
+    }
+
+    #[test]
+    fn displays_names() {
+        assert_eq!(Name::from_ascii("vlayer.xyz").unwrap().to_string(), "vlayer.xyz.");
+        assert_eq!(Name::root().to_string(), ".");
+    }
+
+    #[test]
+    fn checks_subdomains() {
+        let zone = Name::from_ascii("vlayer.xyz").unwrap();
+
+        assert!(
+            Name::from_ascii("google._domainkey.vlayer.xyz")
+                .unwrap()
+                .is_subdomain_of(&zone)
+        );
+        assert!(zone.is_subdomain_of(&zone));
+        assert!(zone.is_subdomain_of(&Name::root()));
+        assert!(
+            !Name::from_ascii("spoofed-vlayer.xyz")
+                .unwrap()
+                .is_subdomain_of(&zone)
+        );
+    }
+
+    #[test]
+    fn rejects_compressed_names() {
+        assert_eq!(
+            ResourceRecord::parse(b"\xc0\x0c\x00\x10\x00\x01\x00\x00\x00\x00\x00\x00")
+                .err()
+                .unwrap(),
+            invalid("compressed names are not supported")
+        );
+    }
+
+    #[test]
+    fn parses_resource_record() {
+        let record =
+            ResourceRecord::parse(b"\x03xyz\x00\x00\x10\x00\x01\x00\x00\x01\x2c\x00\x03\x02hi")
+                .unwrap();
+
+        assert_eq!(record.owner, Name::from_ascii("xyz").unwrap());
+        assert_eq!(record.record_type, TYPE_TXT);
+        assert_eq!(record.rdata, b"\x02hi");
+    }
+
+    #[test]
+    fn rejects_trailing_data() {
+        assert_eq!(
+            ResourceRecord::parse(b"\x00\x00\x10\x00\x01\x00\x00\x01\x2c\x00\x00\x00")
+                .err()
+                .unwrap(),
+            invalid("unexpected trailing data")
+        );
+    }
+
+    #[test]
+    fn joins_txt_strings() {
+        assert_eq!(txt_data(b"\x05v=DKI\x02M1").unwrap(), b"v=DKIM1");
+        assert_eq!(txt_data(b"\x05v=DK").unwrap_err(), invalid("unexpected end of data"));
+    }
+
+    #[test]
+    fn computes_key_tag() {
+        // Sum of big-endian 16-bit words, the odd byte being the high one
+        let dnskey = Dnskey::parse(&[1, 1, 3, 8, 3, 1, 0, 1, 5]).unwrap();
+
+        assert_eq!(dnskey.key_tag(), 0x0101 + 0x0308 + 0x0301 + 0x0001 + 0x0500);
+    }
+}
@@ -1,3 +1,6 @@
+pub(crate) mod dnssec;
+
+use dnssec::DnssecError;
 use rsa::{
     pkcs1v15,
     pkcs1v15::VerifyingKey,
@@ -7,7 +10,7 @@ use rsa::{
 };
 
 use crate::{
-    PublicKey, Signature, common, common::to_payload::ToPayload,
+    KeyId, PublicKey, Signature, common, common::to_payload::ToPayload,
     dns_over_https::types::Record as DNSRecord,
 };
 
@@ -19,6 +22,10 @@ pub enum RecordVerifierError {
     SignatureDecoding(#[source] signature::Error),
     #[error("Signature verification error")]
     SignatureVerification(#[from] signature::Error),
+    #[error("DNSSEC verification error: {0}")]
+    Dnssec(#[from] DnssecError),
+    #[error("Key id {0} doesn't match the public key")]
+    KeyIdMismatch(KeyId),
 }
 
 pub fn verify_signature(
@@ -62,6 +69,7 @@ mod test {
             signature,
             pub_key,
             valid_until,
+            ..
         } = sign_record(&Signer::default(), &RECORD, 123);
         verify_signature(&RECORD, valid_until, &pub_key, &signature).unwrap();
     }
@@ -72,6 +80,7 @@ mod test {
             signature,
             pub_key,
             valid_until,
+            ..
         } = sign_record(&Signer::default(), &RECORD, 123);
         let modified_record = DNSRecord {
             data: "World".into(),
@@ -85,6 +94,18 @@ mod test {
         );
     }
 
+    #[test]
+    fn fails_on_mismatched_key_id() {
+        let verification_data = VerificationData {
+            key_id: Some(KeyId([0xde; 32])),
+            ..sign_record(&Signer::default(), &RECORD, 123)
+        };
+        assert_eq!(
+            RECORD.verify(&verification_data).unwrap_err().to_string(),
+            format!("Key id {} doesn't match the public key", "de".repeat(32))
+        );
+    }
+
     #[test]
     fn fails_on_invalid_public_key() {
         assert_eq!(
@@ -21,6 +21,9 @@ pub enum ParsingError {
     #[error("Malformed request. First line mismatch after reconstruction")]
     MalformedRequestReconstructionMismatch,
 
+    #[error("HTTP method is redacted")]
+    RedactedHttpMethod,
+
     #[error("Invalid HTTP method")]
     InvalidHttpMethod(
         #[from]
@@ -37,9 +40,24 @@ pub enum ParsingError {
     #[error("Partial httparse error")]
     Partial,
 
+    #[error("Invalid Content-Length header: {0}")]
+    InvalidContentLength(String),
+
+    #[error("Invalid chunk size in chunked response body")]
+    InvalidChunkSize,
+
     #[error("Unsupported transfer encoding: {0}")]
     UnsupportedTransferEncoding(String),
 
+    #[error("Unsupported content encoding: {0}")]
+    UnsupportedContentEncoding(String),
+
+    #[error("Compressed response body can't be redacted")]
+    RedactionInCompressedBody,
+
+    #[error("Decoded response body exceeds {0} bytes")]
+    DecodedBodyTooLarge(usize),
+
     #[error("IO error: {0}")]
     StdIoError(
         #[from]
@@ -52,6 +70,9 @@ pub enum ParsingError {
     )]
     RedactionInResponseBody,
 
+    #[error("Redaction is only supported in JSON response bodies")]
+    RedactionInNonJsonBody,
+
     #[error(
         "Redaction in first line of the request is forbidden when UrlTestMode is set to Full. Use Prefix to allow redaction"
     )]
@@ -73,6 +94,9 @@ pub enum ParsingError {
         serde_json::Error,
     ),
 
+    #[error("Response header is missing or redacted: {0}")]
+    MissingResponseHeader(String),
+
     #[error("Invalid content-type: {0}")]
     InvalidContentType(String),
 
@@ -1,7 +1,8 @@
 use derive_new::new;
 
 use crate::{
-    errors::ParsingError, transcript_parser::parse_request_and_validate_redaction,
+    errors::ParsingError,
+    transcript_parser::{ParsedRequest, parse_request_and_validate_redaction, split_requests},
     web_proof::UrlTestMode,
 };
 
@@ -11,7 +12,15 @@ pub(crate) struct RequestTranscript {
 }
 
 impl RequestTranscript {
-    pub(crate) fn parse_url(self, url_test_mode: UrlTestMode) -> Result<String, ParsingError> {
+    /// Splits a transcript of a keep-alive session into one transcript per request.
+    pub(crate) fn split(self) -> Result<Vec<Self>, ParsingError> {
+        Ok(split_requests(&self.transcript)?
+            .into_iter()
+            .map(|range| Self::new(self.transcript[range].to_vec()))
+            .collect())
+    }
+
+    pub(crate) fn parse(self, url_test_mode: UrlTestMode) -> Result<ParsedRequest, ParsingError> {
         parse_request_and_validate_redaction(&self.transcript, url_test_mode)
     }
 }
@@ -28,15 +37,16 @@ mod tests {
     #[test]
     fn parse_real_url_with_single_slice_transcript() {
         let transcript = create_transcript("./testdata/sent_request.txt");
-        let url = transcript.parse_url(UrlTestMode::Full).unwrap();
-        assert_eq!(url, "https://api.x.com/1.1/account/settings.json");
+        let request = transcript.parse(UrlTestMode::Full).unwrap();
+        assert_eq!(request.method, http::Method::GET);
+        assert_eq!(request.url, "https://api.x.com/1.1/account/settings.json");
     }
 
     #[test]
     fn fail_redacted() {
         let transcript = create_transcript("./testdata/redacted_sent_request.txt");
         assert!(matches!(
-            transcript.parse_url(UrlTestMode::Full),
+            transcript.parse(UrlTestMode::Full),
             Err(ParsingError::Httparse(err)) if err.to_string() == "invalid header name"
         ));
     }
@@ -45,7 +55,7 @@ mod tests {
     fn fail_to_many_headers() {
         let transcript = create_transcript("./testdata/many_headers_sent_request.txt");
         assert!(matches!(
-            transcript.parse_url(UrlTestMode::Full),
+            transcript.parse(UrlTestMode::Full),
             Err(ParsingError::Httparse(err)) if err.to_string() == "too many headers"
         ));
     }
@@ -54,16 +64,31 @@ mod tests {
     fn fail_empty_transcript() {
         let transcript = RequestTranscript::new(vec![]);
         assert!(matches!(
-            transcript.parse_url(UrlTestMode::Full),
+            transcript.parse(UrlTestMode::Full),
             Err(ParsingError::NoHttpMethodInRequest)
         ));
     }
 
+    #[test]
+    fn split_keep_alive_session() {
+        let first = b"GET https://example.com/first HTTP/1.1\r\nHost: example.com\r\n\r\n";
+        let second = b"GET https://example.com/second HTTP/1.1\r\nHost: example.com\r\n\r\n";
+        let transcript = RequestTranscript::new([&first[..], second].concat());
+
+        let urls: Vec<_> = transcript
+            .split()
+            .unwrap()
+            .into_iter()
+            .map(|request| request.parse(UrlTestMode::Full).unwrap().url)
+            .collect();
+        assert_eq!(urls, ["https://example.com/first", "https://example.com/second"]);
+    }
+
     #[test]
     fn fail_not_utf8_transcript() {
         let transcript = RequestTranscript::new(vec![128]);
         assert!(matches!(
-            transcript.parse_url(UrlTestMode::Full),
+            transcript.parse(UrlTestMode::Full),
             Err(ParsingError::Httparse(httparse::Error::Token))
         ));
     }
@@ -1,21 +1,52 @@
+use std::ops::Range;
+
 use derive_new::new;
 
 use crate::{
-    errors::ParsingError, transcript_parser::parse_response_and_validate_redaction,
-    web_proof::BodyRedactionMode,
+    errors::ParsingError,
+    transcript_parser::{ParsedResponse, parse_response_and_validate_redaction, split_responses},
+    web_proof::{BodyRedactionMode, ContentTypes},
 };
 
 #[derive(Debug, new)]
 pub(crate) struct ResponseTranscript {
     pub(crate) transcript: Vec<u8>,
+    /// Ranges of `transcript` that were not authenticated by the notary.
+    pub(crate) redacted_ranges: Vec<Range<usize>>,
 }
 
 impl ResponseTranscript {
-    pub(crate) fn parse_body(
+    /// Splits a transcript of a keep-alive session into one transcript per response. Redacted
+    /// ranges are clipped to each response and made relative to its start.
+    pub(crate) fn split(self) -> Result<Vec<Self>, ParsingError> {
+        Ok(split_responses(&self.transcript)?
+            .into_iter()
+            .map(|message| {
+                let redacted_ranges = self
+                    .redacted_ranges
+                    .iter()
+                    .map(|range| {
+                        range.start.max(message.start) - message.start
+                            ..range.end.min(message.end).saturating_sub(message.start)
+                    })
+                    .filter(|range| !range.is_empty())
+                    .collect();
+                Self::new(self.transcript[message].to_vec(), redacted_ranges)
+            })
+            .collect())
+    }
+
+    pub(crate) fn parse(
         self,
         redaction_mode: BodyRedactionMode,
-    ) -> Result<String, ParsingError> {
-        parse_response_and_validate_redaction(&self.transcript, redaction_mode)
+        allowed_content_types: ContentTypes,
+    ) -> Result<ParsedResponse, ParsingError> {
+        parse_response_and_validate_redaction(
+            &self.transcript,
+            &self.redacted_ranges,
+            redaction_mode,
+            allowed_content_types,
+        )
     }
 }
 
@@ -32,10 +63,14 @@ mod tests {
             read_fixture("./testdata/received_response.txt")
                 .as_bytes()
                 .to_vec(),
+            vec![],
         );
 
         assert_eq!(
-            transcript.parse_body(BodyRedactionMode::Disabled).unwrap(),
+            transcript
+                .parse(BodyRedactionMode::Disabled, ContentTypes::JSON)
+                .unwrap()
+                .body,
             RESPONSE_BODY.to_string()
         );
     }
@@ -48,17 +83,18 @@ mod tests {
             read_fixture("./testdata/redacted_received_response.txt")
                 .as_bytes()
                 .to_vec(),
+            vec![],
         );
 
-        let body = transcript.parse_body(BodyRedactionMode::EnabledUnsafe);
-        assert_eq!(body.unwrap(), REDACTED_RESPONSE_BODY.to_string());
+        let response = transcript.parse(BodyRedactionMode::EnabledUnsafe, ContentTypes::JSON);
+        assert_eq!(response.unwrap().body, REDACTED_RESPONSE_BODY.to_string());
     }
 
     #[test]
     fn empty_response() {
-        let transcript = ResponseTranscript::new(vec![]);
+        let transcript = ResponseTranscript::new(vec![], vec![]);
         assert!(matches!(
-            transcript.parse_body(BodyRedactionMode::Disabled),
+            transcript.parse(BodyRedactionMode::Disabled, ContentTypes::JSON),
             Err(ParsingError::Partial)
         ));
     }
@@ -69,10 +105,11 @@ mod tests {
             read_fixture("./testdata/no_headers_response.txt")
                 .as_bytes()
                 .to_vec(),
+            vec![],
         );
 
         assert!(matches!(
-            transcript.parse_body(BodyRedactionMode::Disabled),
+            transcript.parse(BodyRedactionMode::Disabled, ContentTypes::JSON),
             Err(ParsingError::Httparse(httparse::Error::Version))
         ));
     }
@@ -83,10 +120,11 @@ mod tests {
             read_fixture("./testdata/no_body_response.txt")
                 .as_bytes()
                 .to_vec(),
+            vec![],
         );
 
         let err = transcript
-            .parse_body(BodyRedactionMode::Disabled)
+            .parse(BodyRedactionMode::Disabled, ContentTypes::JSON)
             .unwrap_err();
         assert!(
             matches!(err, ParsingError::Json(err) if err.to_string() == "EOF while parsing a value at line 1 column 0")
@@ -95,24 +133,43 @@ mod tests {
 
     #[test]
     fn error_not_utf8_transcript() {
-        let transcript = ResponseTranscript::new(vec![128]);
+        let transcript = ResponseTranscript::new(vec![128], vec![]);
 
         assert!(matches!(
-            transcript.parse_body(BodyRedactionMode::Disabled),
+            transcript.parse(BodyRedactionMode::Disabled, ContentTypes::JSON),
             Err(ParsingError::Httparse(httparse::Error::Version))
         ));
     }
 
+    #[test]
+    fn split_shifts_redacted_ranges() {
+        let first = b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\n{}";
+        let second = b"HTTP/1.1 200 OK\r\nContent-Length: 7\r\n\r\n{\"a\":1}";
+        let transcript = ResponseTranscript::new(
+            [&first[..], second].concat(),
+            vec![first.len() - 1..first.len() + 4, first.len() + 40..first.len() + 42],
+        );
+
+        let responses = transcript.split().unwrap();
+
+        assert_eq!(responses.len(), 2);
+        assert_eq!(responses[0].transcript, first);
+        assert_eq!(responses[0].redacted_ranges, vec![first.len() - 1..first.len()]);
+        assert_eq!(responses[1].transcript, second);
+        assert_eq!(responses[1].redacted_ranges, vec![0..4, 40..42]);
+    }
+
     #[test]
     fn parse_chunked_response_body() {
         let transcript = ResponseTranscript::new(
             read_fixture("./testdata/chunked_response.txt")
                 .as_bytes()
                 .to_vec(),
+            vec![],
         );
 
         assert_eq!(
-            transcript.parse_body(BodyRedactionMode::Disabled).unwrap(),
+            transcript.parse(BodyRedactionMode::Disabled, ContentTypes::JSON).unwrap().body,
             "{\"name\":\"Luke Skywalker\",\"height\":\"172\",\"mass\":\"77\",\"hair_color\":\"blond\",\"skin_color\":\"fair\",\"eye_color\":\"blue\",\"birth_year\":\"19BBY\",\"gender\":\"male\",\"homeworld\":\"https://swapi.dev/api/planets/1/\",\"films\":[\"https://swapi.dev/api/films/1/\",\"https://swapi.dev/api/films/2/\",\"https://swapi.dev/api/films/3/\",\"https://swapi.dev/api/films/6/\"],\"species\":[],\"vehicles\":[\"https://swapi.dev/api/vehicles/14/\",\"https://swapi.dev/api/vehicles/30/\"],\"starships\":[\"https://swapi.dev/api/starships/12/\",\"https://swapi.dev/api/starships/22/\"],\"created\":\"2014-12-09T13:50:51.644000Z\",\"edited\":\"2014-12-20T21:17:56.891000Z\",\"url\":\"https://swapi.dev/api/people/1/\"}".to_string()
         );
     }
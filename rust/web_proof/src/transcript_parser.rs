@@ -5,14 +5,18 @@ use crate::{
     utils::bytes::replace_bytes,
 };
 
+mod content_encoding;
 mod request;
 mod response;
+mod split;
 
-pub(crate) use request::parse_request_and_validate_redaction;
-pub(crate) use response::parse_response_and_validate_redaction;
+pub(crate) use request::{ParsedRequest, parse_request_and_validate_redaction};
+pub(crate) use response::{ParsedResponse, parse_response_and_validate_redaction};
+pub(crate) use split::{split_requests, split_responses};
 
 const MAX_HEADERS_NUMBER: usize = 40;
 const CONTENT_TYPE: &str = "Content-Type";
+const CONTENT_ENCODING: &str = "Content-Encoding";
 
 fn replace_redacted_bytes(input: &[u8], replacement_char: char) -> Vec<u8> {
     replace_bytes(input, REDACTED_BYTE_CODE, replacement_char as u8)
@@ -0,0 +1,139 @@
+use std::io::Read;
+
+use brotli_decompressor::Decompressor;
+use flate2::read::{GzDecoder, ZlibDecoder};
+
+use crate::errors::ParsingError;
+
+const BROTLI_BUFFER_SIZE: usize = 4096;
+
+/// Limit on the size of a decoded body. A small compressed body can expand to an arbitrary size,
+/// which would exhaust the memory of the host and the guest.
+pub(crate) const MAX_DECODED_BODY_SIZE: usize = 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ContentEncoding {
+    Identity,
+    Gzip,
+    /// zlib-wrapped deflate stream, as specified in RFC 9110
+    Deflate,
+    Brotli,
+}
+
+impl ContentEncoding {
+    pub(crate) fn from_header_value(value: &[u8]) -> Result<Self, ParsingError> {
+        let value = String::from_utf8_lossy(value);
+        match value.trim().to_ascii_lowercase().as_str() {
+            "identity" => Ok(Self::Identity),
+            "gzip" | "x-gzip" => Ok(Self::Gzip),
+            "deflate" => Ok(Self::Deflate),
+            "br" => Ok(Self::Brotli),
+            _ => Err(ParsingError::UnsupportedContentEncoding(value.to_string())),
+        }
+    }
+
+    pub(crate) fn decode(self, body: &[u8]) -> Result<Vec<u8>, ParsingError> {
+        if body.is_empty() {
+            return Ok(Vec::new());
+        }
+        match self {
+            Self::Identity => Ok(body.to_vec()),
+            Self::Gzip => read_limited(GzDecoder::new(body)),
+            Self::Deflate => read_limited(ZlibDecoder::new(body)),
+            Self::Brotli => read_limited(Decompressor::new(body, BROTLI_BUFFER_SIZE)),
+        }
+    }
+}
+
+fn read_limited(decoder: impl Read) -> Result<Vec<u8>, ParsingError> {
+    let mut decoded = Vec::new();
+    // One byte over the limit is enough to tell that the body exceeds it
+    decoder
+        .take(MAX_DECODED_BODY_SIZE as u64 + 1)
+        .read_to_end(&mut decoded)?;
+    if decoded.len() > MAX_DECODED_BODY_SIZE {
+        return Err(ParsingError::DecodedBodyTooLarge(MAX_DECODED_BODY_SIZE));
+    }
+    Ok(decoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use flate2::{
+        Compression,
+        write::{GzEncoder, ZlibEncoder},
+    };
+
+    use super::*;
+
+    const BODY: &[u8] = b"{\"name\":\"Gandalf\"}";
+
+    #[test]
+    fn parses_header_value() {
+        assert_eq!(ContentEncoding::from_header_value(b"GZIP").unwrap(), ContentEncoding::Gzip);
+        assert_eq!(ContentEncoding::from_header_value(b" br").unwrap(), ContentEncoding::Brotli);
+        assert_eq!(
+            ContentEncoding::from_header_value(b"gzip, br").unwrap_err(),
+            ParsingError::UnsupportedContentEncoding("gzip, br".to_string())
+        );
+    }
+
+    #[test]
+    fn decodes_gzip() {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(BODY).unwrap();
+        let encoded = encoder.finish().unwrap();
+
+        assert_eq!(ContentEncoding::Gzip.decode(&encoded).unwrap(), BODY);
+    }
+
+    #[test]
+    fn decodes_deflate() {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(BODY).unwrap();
+        let encoded = encoder.finish().unwrap();
+
+        assert_eq!(ContentEncoding::Deflate.decode(&encoded).unwrap(), BODY);
+    }
+
+    #[test]
+    fn decodes_brotli() {
+        // Single uncompressed meta-block followed by an empty last one
+        let encoded = [&[0x10, 0x01, 0x10][..], BODY, &[0x03]].concat();
+
+        assert_eq!(ContentEncoding::Brotli.decode(&encoded).unwrap(), BODY);
+    }
+
+    #[test]
+    fn decodes_body_of_max_size() {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&vec![0; MAX_DECODED_BODY_SIZE]).unwrap();
+        let encoded = encoder.finish().unwrap();
+
+        assert_eq!(ContentEncoding::Gzip.decode(&encoded).unwrap().len(), MAX_DECODED_BODY_SIZE);
+    }
+
+    #[test]
+    fn fails_on_decompression_bomb() {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::best());
+        encoder
+            .write_all(&vec![0; MAX_DECODED_BODY_SIZE + 1])
+            .unwrap();
+        let encoded = encoder.finish().unwrap();
+
+        assert_eq!(
+            ContentEncoding::Deflate.decode(&encoded).unwrap_err(),
+            ParsingError::DecodedBodyTooLarge(MAX_DECODED_BODY_SIZE)
+        );
+    }
+
+    #[test]
+    fn fails_on_corrupted_input() {
+        assert!(matches!(
+            ContentEncoding::Gzip.decode(b"not gzip"),
+            Err(ParsingError::StdIoError(_))
+        ));
+    }
+}
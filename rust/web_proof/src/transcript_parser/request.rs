@@ -34,7 +34,7 @@ impl ParsedRequest {
 pub(crate) fn parse_request_and_validate_redaction(
     request: &[u8],
     url_test_mode: UrlTestMode,
-) -> Result<String, ParsingError> {
+) -> Result<ParsedRequest, ParsingError> {
     let request_primary_replacement =
         replace_redacted_bytes(request, REDACTION_REPLACEMENT_CHAR_PRIMARY);
     let request_primary = parse_request(&request_primary_replacement)?;
@@ -53,6 +53,9 @@ pub(crate) fn parse_request_and_validate_redaction(
     {
         return Err(ParsingError::MalformedRequestReconstructionMismatch);
     }
+    if request_primary.method != request_secondary.method {
+        return Err(ParsingError::RedactedHttpMethod);
+    }
 
     validate_name_value_redaction(
         &request_primary.headers,
@@ -66,7 +69,7 @@ pub(crate) fn parse_request_and_validate_redaction(
         RedactionElementType::RequestUrlParam,
     )?;
 
-    Ok(request_primary.url)
+    Ok(request_primary)
 }
 
 fn split_first_line(request: &[u8]) -> Result<&[u8], ParsingError> {
@@ -147,7 +150,9 @@ mod tests {
             #[test]
             fn no_redaction() {
                 let request = b"GET https://example.com/test.json?param=value HTTP/1.1\r\ncontent-type: application/json\r\n\r\n";
-                let url = parse_request_and_validate_redaction(request, UrlTestMode::Full).unwrap();
+                let url = parse_request_and_validate_redaction(request, UrlTestMode::Full)
+                    .unwrap()
+                    .url;
                 assert_eq!(url, "https://example.com/test.json?param=value");
             }
 
@@ -161,7 +166,8 @@ mod tests {
                     );
                     let url =
                         parse_request_and_validate_redaction(request.as_bytes(), UrlTestMode::Full)
-                            .unwrap();
+                            .unwrap()
+                            .url;
                     assert_eq!(url, "https://example.com/test.json");
                 }
 
@@ -172,7 +178,8 @@ mod tests {
                     );
                     let url =
                         parse_request_and_validate_redaction(request.as_bytes(), UrlTestMode::Full)
-                            .unwrap();
+                            .unwrap()
+                            .url;
                     assert_eq!(url, "https://example.com/test.json");
                 }
 
@@ -183,7 +190,8 @@ mod tests {
                     );
                     let url =
                         parse_request_and_validate_redaction(request.as_bytes(), UrlTestMode::Full)
-                            .unwrap();
+                            .unwrap()
+                            .url;
                     assert_eq!(url, "https://example.com/test.json");
                 }
 
@@ -194,23 +202,26 @@ mod tests {
                     );
                     let url =
                         parse_request_and_validate_redaction(request.as_bytes(), UrlTestMode::Full)
-                            .unwrap();
+                            .unwrap()
+                            .url;
                     assert_eq!(url, "https://example.com/test.json");
                 }
 
                 #[test]
                 fn fully_redacted_header_value() {
                     let request = b"GET https://example.com/test.json HTTP/1.1\r\ncontent-type: \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\r\n\r\n{}\r\n";
-                    let url =
-                        parse_request_and_validate_redaction(request, UrlTestMode::Full).unwrap();
+                    let url = parse_request_and_validate_redaction(request, UrlTestMode::Full)
+                        .unwrap()
+                        .url;
                     assert_eq!(url, "https://example.com/test.json");
                 }
 
                 #[test]
                 fn fully_redacted_header_value_no_space_before_value() {
                     let request = b"GET https://example.com/test.json HTTP/1.1\r\ncontent-type:\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\r\n\r\n";
-                    let url =
-                        parse_request_and_validate_redaction(request, UrlTestMode::Full).unwrap();
+                    let url = parse_request_and_validate_redaction(request, UrlTestMode::Full)
+                        .unwrap()
+                        .url;
                     assert_eq!(url, "https://example.com/test.json");
                 }
             }
@@ -220,8 +231,9 @@ mod tests {
                 #[test]
                 fn url_param_no_redaction() {
                     let request = b"GET https://example.com/test.json?param=value HTTP/1.1\r\n\r\n";
-                    let url =
-                        parse_request_and_validate_redaction(request, UrlTestMode::Full).unwrap();
+                    let url = parse_request_and_validate_redaction(request, UrlTestMode::Full)
+                        .unwrap()
+                        .url;
                     assert_eq!(url, "https://example.com/test.json?param=value");
                 }
 
@@ -229,8 +241,9 @@ mod tests {
                 fn fully_redacted_url_param_value() {
                     let request =
                         b"GET https://example.com/test.json?param=\0\0\0\0\0 HTTP/1.1\r\n\r\n";
-                    let url =
-                        parse_request_and_validate_redaction(request, UrlTestMode::Prefix).unwrap();
+                    let url = parse_request_and_validate_redaction(request, UrlTestMode::Prefix)
+                        .unwrap()
+                        .url;
                     assert_eq!(url, "https://example.com/test.json?param=*****");
                 }
 
@@ -238,8 +251,9 @@ mod tests {
                 fn fully_redacted_multiple_url_param_values() {
                     let request =
                             b"GET https://example.com/test.json?param1=\0\0\0\0\0&param2=value2&param3=\0\0\0 HTTP/1.1\r\n\r\n";
-                    let url =
-                        parse_request_and_validate_redaction(request, UrlTestMode::Prefix).unwrap();
+                    let url = parse_request_and_validate_redaction(request, UrlTestMode::Prefix)
+                        .unwrap()
+                        .url;
                     assert_eq!(
                         url,
                         "https://example.com/test.json?param1=*****&param2=value2&param3=***"
@@ -251,6 +265,14 @@ mod tests {
         mod fail {
             use super::*;
 
+            #[test]
+            fn redacted_method() {
+                let request = b"\0\0\0 https://example.com/test.json HTTP/1.1\r\n\r\n";
+                let err =
+                    parse_request_and_validate_redaction(request, UrlTestMode::Prefix).unwrap_err();
+                assert_eq!(err, ParsingError::RedactedHttpMethod);
+            }
+
             mod header {
                 use super::*;
 
@@ -1,11 +1,12 @@
-use std::{io::Read, string::ToString};
+use std::{io::Read, ops::Range, string::ToString};
 
 use chunked_transfer::Decoder;
 use httparse::{EMPTY_HEADER, Header, Response, Status};
 use mime::Mime;
 
 use super::{
-    CONTENT_TYPE, MAX_HEADERS_NUMBER, REDACTED_BYTE_CODE, convert_headers, replace_redacted_bytes,
+    CONTENT_ENCODING, CONTENT_TYPE, MAX_HEADERS_NUMBER, REDACTED_BYTE_CODE,
+    content_encoding::ContentEncoding, convert_headers, replace_redacted_bytes,
 };
 use crate::{
     errors::ParsingError,
@@ -14,20 +15,35 @@ use crate::{
         RedactedTranscriptNameValue, RedactionElementType, validate_name_value_redaction,
     },
     utils::{bytes::all_match, json::json_to_redacted_transcript},
-    web_proof::BodyRedactionMode,
+    web_proof::{BodyRedactionMode, ContentType, ContentTypes},
 };
 
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) struct ParsedResponse {
+    /// Such as `200`.
+    pub(crate) status: u16,
+    /// Response headers whose values are not redacted.
+    pub(crate) headers: Vec<(String, String)>,
+    pub(crate) body: String,
+}
+
+/// `redacted_ranges` are the positions of the bytes in `response` that were not authenticated by the
+/// notary. Elsewhere, redacted bytes are told apart by being zeroed, but that doesn't work for
+/// compressed bodies, which legitimately contain zero bytes.
 pub(crate) fn parse_response_and_validate_redaction(
     response: &[u8],
+    redacted_ranges: &[Range<usize>],
     redaction_mode: BodyRedactionMode,
-) -> Result<String, ParsingError> {
+    allowed_content_types: ContentTypes,
+) -> Result<ParsedResponse, ParsingError> {
     let response_primary_replacement =
         replace_redacted_bytes(response, REDACTION_REPLACEMENT_CHAR_PRIMARY);
-    let (body_primary_offset, headers_primary) = parse_response(&response_primary_replacement)?;
+    let (body_primary_offset, status, headers_primary) =
+        parse_response(&response_primary_replacement)?;
 
     let response_secondary_replacement =
         replace_redacted_bytes(response, REDACTION_REPLACEMENT_CHAR_SECONDARY);
-    let (body_secondary_offset, headers_secondary) =
+    let (body_secondary_offset, _, headers_secondary) =
         parse_response(&response_secondary_replacement)?;
 
     validate_name_value_redaction(
@@ -36,96 +52,163 @@ pub(crate) fn parse_response_and_validate_redaction(
         RedactionElementType::ResponseHeader,
     )?;
 
-    validate_content_type_and_charset(&headers_primary)?;
+    let content_type = validate_content_type_and_charset(&headers_primary, allowed_content_types)?;
+    let headers = unredacted_headers(&headers_primary, &headers_secondary)?;
+
+    let encoding = content_encoding(&headers_primary)?;
+    if encoding != ContentEncoding::Identity {
+        if redacted_ranges
+            .iter()
+            .any(|range| range.end > body_primary_offset)
+        {
+            return Err(ParsingError::RedactionInCompressedBody);
+        }
+        let body = handle_chunked_transfer_encoding(
+            &convert_headers(&headers_primary),
+            &response[body_primary_offset..],
+        )?;
+        let body = String::from_utf8(encoding.decode(&body)?)?;
+        // Nothing is redacted in the body, so it only has to be valid JSON
+        if content_type == ContentType::Json {
+            json_to_redacted_transcript(&body)?;
+        }
+        return Ok(ParsedResponse {
+            status,
+            headers,
+            body,
+        });
+    }
 
     let body_primary = &response_primary_replacement[body_primary_offset..];
     let body_secondary = &response_secondary_replacement[body_secondary_offset..];
 
-    let body_primary = handle_chunked_transfer_encoding(
+    let body_primary = String::from_utf8(handle_chunked_transfer_encoding(
         &convert_headers(&headers_primary),
-        &String::from_utf8(body_primary.to_vec())?,
-    )?;
-    let body_secondary = handle_chunked_transfer_encoding(
+        body_primary,
+    )?)?;
+    let body_secondary = String::from_utf8(handle_chunked_transfer_encoding(
         &convert_headers(&headers_secondary),
-        &String::from_utf8(body_secondary.to_vec())?,
-    )?;
+        body_secondary,
+    )?)?;
 
-    validate_name_value_redaction(
-        &json_to_redacted_transcript(&body_primary)?,
-        &json_to_redacted_transcript(&body_secondary)?,
-        RedactionElementType::ResponseBody,
-    )?;
+    let original_body = &response[body_primary_offset..];
+    if content_type == ContentType::Json {
+        validate_name_value_redaction(
+            &json_to_redacted_transcript(&body_primary)?,
+            &json_to_redacted_transcript(&body_secondary)?,
+            RedactionElementType::ResponseBody,
+        )?;
+    } else if original_body.contains(&REDACTED_BYTE_CODE) {
+        // Without a structure to check it against, redaction could hide arbitrary parts of the body
+        return Err(ParsingError::RedactionInNonJsonBody);
+    }
 
     if redaction_mode == BodyRedactionMode::Disabled {
         if body_primary_offset != body_secondary_offset {
             return Err(ParsingError::RedactionInResponseBody);
         }
-        let original_body = &response[body_primary_offset..];
         if original_body.contains(&REDACTED_BYTE_CODE) {
             return Err(ParsingError::RedactionInResponseBody);
         }
     }
 
-    Ok(body_primary)
+    Ok(ParsedResponse {
+        status,
+        headers,
+        body: body_primary,
+    })
 }
 
-fn parse_response(response: &[u8]) -> Result<(usize, [Header; MAX_HEADERS_NUMBER]), ParsingError> {
+fn parse_response(
+    response: &[u8],
+) -> Result<(usize, u16, [Header; MAX_HEADERS_NUMBER]), ParsingError> {
     let mut headers = [EMPTY_HEADER; MAX_HEADERS_NUMBER];
     let mut res = Response::new(&mut headers);
     let body_index = match res.parse(response)? {
         Status::Complete(t) => t,
         Status::Partial => return Err(ParsingError::Partial),
     };
+    let status = res.code.ok_or(ParsingError::Partial)?;
 
-    Ok((body_index, headers))
+    Ok((body_index, status, headers))
 }
 
-fn validate_content_type_and_charset(headers: &[Header]) -> Result<(), ParsingError> {
-    let content_type_header = headers
+// Header values differ between the two replacements only if they are redacted
+fn unredacted_headers(
+    headers_primary: &[Header],
+    headers_secondary: &[Header],
+) -> Result<Vec<(String, String)>, ParsingError> {
+    headers_primary
         .iter()
-        .find(|header| header.name.eq_ignore_ascii_case(CONTENT_TYPE));
+        .zip(headers_secondary)
+        .filter(|(primary, secondary)| !primary.name.is_empty() && primary.value == secondary.value)
+        .map(|(header, _)| Ok((header.name.to_string(), String::from_utf8(header.value.to_vec())?)))
+        .collect()
+}
 
-    if let Some(header) = content_type_header {
-        let content_type = String::from_utf8_lossy(header.value).to_string();
-        if all_match(content_type.as_bytes(), REDACTION_REPLACEMENT_CHAR_PRIMARY as u8) {
-            return Ok(());
-        }
+/// Returns the content type of the body. Bodies without a content type, or with a redacted one,
+/// are treated as JSON, so they are rejected unless JSON is allowed.
+fn validate_content_type_and_charset(
+    headers: &[Header],
+    allowed_content_types: ContentTypes,
+) -> Result<ContentType, ParsingError> {
+    let content_type = headers
+        .iter()
+        .find(|header| header.name.eq_ignore_ascii_case(CONTENT_TYPE))
+        .map(|header| String::from_utf8_lossy(header.value).to_string())
+        .filter(|value| !all_match(value.as_bytes(), REDACTION_REPLACEMENT_CHAR_PRIMARY as u8));
 
-        let mime: Mime = content_type.parse()?;
+    let Some(content_type) = content_type else {
+        return if allowed_content_types.contains(ContentType::Json) {
+            Ok(ContentType::Json)
+        } else {
+            Err(ParsingError::MissingResponseHeader(CONTENT_TYPE.to_string()))
+        };
+    };
 
-        if mime.type_() != "application" || mime.subtype() != "json" {
-            return Err(ParsingError::InvalidContentType(content_type));
-        }
+    let mime: Mime = content_type.parse()?;
 
-        if let Some(charset) = mime.get_param("charset") {
-            if !charset.as_str().eq_ignore_ascii_case("utf-8") {
-                return Err(ParsingError::InvalidCharset(content_type));
-            }
+    let parsed_content_type = ContentType::from_mime(&mime)
+        .filter(|parsed| allowed_content_types.contains(*parsed))
+        .ok_or_else(|| ParsingError::InvalidContentType(content_type.clone()))?;
+
+    if let Some(charset) = mime.get_param("charset") {
+        if !charset.as_str().eq_ignore_ascii_case("utf-8") {
+            return Err(ParsingError::InvalidCharset(content_type));
         }
     }
-    Ok(())
+    Ok(parsed_content_type)
+}
+
+fn content_encoding(headers: &[Header]) -> Result<ContentEncoding, ParsingError> {
+    headers
+        .iter()
+        .find(|header| header.name.eq_ignore_ascii_case(CONTENT_ENCODING))
+        .map_or(Ok(ContentEncoding::Identity), |header| {
+            ContentEncoding::from_header_value(header.value)
+        })
 }
 
 fn handle_chunked_transfer_encoding(
     headers: &[RedactedTranscriptNameValue],
-    body: &str,
-) -> Result<String, ParsingError> {
+    body: &[u8],
+) -> Result<Vec<u8>, ParsingError> {
     let transfer_encoding_header = headers
         .iter()
         .find(|header| header.name.eq_ignore_ascii_case("Transfer-Encoding"));
 
     match transfer_encoding_header {
         Some(header) if header.value.eq_ignore_ascii_case(b"chunked") => {
-            let mut decoder = Decoder::new(body.as_bytes());
-            let mut decoded_body = String::new();
-            decoder.read_to_string(&mut decoded_body)?;
+            let mut decoder = Decoder::new(body);
+            let mut decoded_body = Vec::new();
+            decoder.read_to_end(&mut decoded_body)?;
             Ok(decoded_body)
         }
-        Some(header) if header.value.eq_ignore_ascii_case(b"identity") => Ok(body.to_string()),
+        Some(header) if header.value.eq_ignore_ascii_case(b"identity") => Ok(body.to_vec()),
         Some(header) => Err(ParsingError::UnsupportedTransferEncoding(
             String::from_utf8_lossy(header.value.as_ref()).to_string(),
         )),
-        None => Ok(body.to_string()),
+        None => Ok(body.to_vec()),
     }
 }
 
@@ -147,9 +230,12 @@ mod tests {
                     let response = b"HTTP/1.1 200 OK\r\ncontent-type: application/json\r\n\r\n{}";
                     let body = parse_response_and_validate_redaction(
                         response,
+                        &[],
                         BodyRedactionMode::Disabled,
+                        ContentTypes::JSON,
                     )
-                    .unwrap();
+                    .unwrap()
+                    .body;
                     assert_eq!(body, "{}");
                 }
 
@@ -159,9 +245,12 @@ mod tests {
                         b"HTTP/1.1 200 OK\r\nContent-Type: \0\0\0\0\0\0\0\0\0\0\r\n\r\n{}";
                     let body = parse_response_and_validate_redaction(
                         response,
+                        &[],
                         BodyRedactionMode::Disabled,
+                        ContentTypes::JSON,
                     )
-                    .unwrap();
+                    .unwrap()
+                    .body;
                     assert_eq!(body, "{}");
                 }
 
@@ -170,9 +259,12 @@ mod tests {
                     let response = b"HTTP/1.1 200 OK\r\nContent-Type: application/json; charset=UTF-8\r\n\r\n{}";
                     let body = parse_response_and_validate_redaction(
                         response,
+                        &[],
                         BodyRedactionMode::Disabled,
+                        ContentTypes::JSON,
                     )
-                    .unwrap();
+                    .unwrap()
+                    .body;
                     assert_eq!(body, "{}");
                 }
             }
@@ -199,9 +291,12 @@ mod tests {
                         + "}";
                     let body = parse_response_and_validate_redaction(
                         response.as_bytes(),
+                        &[],
                         BodyRedactionMode::Disabled,
+                        ContentTypes::JSON,
                     )
-                    .unwrap();
+                    .unwrap()
+                    .body;
                     assert_eq!(
                         body,
                         trim_start(
@@ -227,7 +322,9 @@ mod tests {
                         + "\r\n";
                     let err = parse_response_and_validate_redaction(
                         response.as_bytes(),
+                        &[],
                         BodyRedactionMode::Disabled,
+                        ContentTypes::JSON,
                     )
                     .unwrap_err();
                     assert!(
@@ -254,9 +351,12 @@ mod tests {
                         + "}";
                     let body = parse_response_and_validate_redaction(
                         response.as_bytes(),
+                        &[],
                         BodyRedactionMode::EnabledUnsafe,
+                        ContentTypes::JSON,
                     )
-                    .unwrap();
+                    .unwrap()
+                    .body;
                     assert_eq!(
                         body,
                         trim_start(
@@ -293,9 +393,12 @@ mod tests {
                         + "}";
                     let body = parse_response_and_validate_redaction(
                         response.as_bytes(),
+                        &[],
                         BodyRedactionMode::EnabledUnsafe,
+                        ContentTypes::JSON,
                     )
-                    .unwrap();
+                    .unwrap()
+                    .body;
                     assert_eq!(
                         body,
                         trim_start(
@@ -322,9 +425,12 @@ mod tests {
                         + "[{\"string\": \"\0\0\0\0\0\"}]";
                     let body = parse_response_and_validate_redaction(
                         response.as_bytes(),
+                        &[],
                         BodyRedactionMode::EnabledUnsafe,
+                        ContentTypes::JSON,
                     )
-                    .unwrap();
+                    .unwrap()
+                    .body;
                     assert_eq!(body, trim_start(r#"[{"string": "*****"}]"#));
                 }
             }
@@ -342,7 +448,9 @@ mod tests {
                         b"HTTP/1.1 200 OK\r\nContent-Type: text/plai\0\r\n\r\nHello, world!";
                     let err = parse_response_and_validate_redaction(
                         response,
+                        &[],
                         BodyRedactionMode::Disabled,
+                        ContentTypes::JSON,
                     )
                     .unwrap_err();
                     assert!(matches!(
@@ -360,7 +468,9 @@ mod tests {
                         b"HTTP/1.1 200 OK\r\nContent-Typ\0: text/plain\r\n\r\nHello, world!";
                     let err = parse_response_and_validate_redaction(
                         response,
+                        &[],
                         BodyRedactionMode::Disabled,
+                        ContentTypes::JSON,
                     )
                     .unwrap_err();
                     assert!(matches!(
@@ -375,7 +485,9 @@ mod tests {
                         b"HTTP/1.1 200 OK\r\n\0\0\0\0\0\0\0\0\0\0\0\0: text/plain\r\n\r\nHello, world!";
                     let err = parse_response_and_validate_redaction(
                         response,
+                        &[],
                         BodyRedactionMode::Disabled,
+                        ContentTypes::JSON,
                     )
                     .unwrap_err();
                     assert!(matches!(
@@ -390,7 +502,9 @@ mod tests {
                         b"HTTP/1.1 200 OK\r\n\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\r\n\r\nHello, world!";
                     let err = parse_response_and_validate_redaction(
                         response,
+                        &[],
                         BodyRedactionMode::Disabled,
+                        ContentTypes::JSON,
                     )
                     .unwrap_err();
                     assert!(matches!(err, ParsingError::Httparse(httparse::Error::HeaderName)));
@@ -418,7 +532,9 @@ mod tests {
                         + "}";
                     let err = parse_response_and_validate_redaction(
                         response.as_bytes(),
+                        &[],
                         BodyRedactionMode::Disabled,
+                        ContentTypes::JSON,
                     )
                     .unwrap_err();
                     assert!(
@@ -445,7 +561,9 @@ mod tests {
                         + "}";
                     let err = parse_response_and_validate_redaction(
                         response.as_bytes(),
+                        &[],
                         BodyRedactionMode::Disabled,
+                        ContentTypes::JSON,
                     )
                     .unwrap_err();
                     assert!(matches!(err, ParsingError::Json(_)));
@@ -461,7 +579,9 @@ mod tests {
                         + "{\"string\0\": \"Hello\"}";
                     let err = parse_response_and_validate_redaction(
                         response.as_bytes(),
+                        &[],
                         BodyRedactionMode::Disabled,
+                        ContentTypes::JSON,
                     )
                     .unwrap_err();
                     assert!(matches!(
@@ -480,7 +600,9 @@ mod tests {
                         + "}";
                     let err = parse_response_and_validate_redaction(
                         response.as_bytes(),
+                        &[],
                         BodyRedactionMode::Disabled,
+                        ContentTypes::JSON,
                     )
                     .unwrap_err();
                     assert!(
@@ -497,7 +619,9 @@ mod tests {
                         + "\r\n";
                     let err = parse_response_and_validate_redaction(
                         response.as_bytes(),
+                        &[],
                         BodyRedactionMode::Disabled,
+                        ContentTypes::JSON,
                     )
                     .unwrap_err();
                     assert!(
@@ -515,7 +639,9 @@ mod tests {
                         + "{\"object\": {\"nested_string\0\":\"Hello\"}}";
                     let err = parse_response_and_validate_redaction(
                         response.as_bytes(),
+                        &[],
                         BodyRedactionMode::Disabled,
+                        ContentTypes::JSON,
                     )
                     .unwrap_err();
                     assert!(matches!(
@@ -534,7 +660,9 @@ mod tests {
                         + "{\"\0\0\0\0\0\0\": \"Hello\"}";
                     let err = parse_response_and_validate_redaction(
                         response.as_bytes(),
+                        &[],
                         BodyRedactionMode::Disabled,
+                        ContentTypes::JSON,
                     )
                     .unwrap_err();
                     println!("{err:?}");
@@ -554,7 +682,9 @@ mod tests {
                         + "{\"\0\0\0\0\0\0\": {}}";
                     let err = parse_response_and_validate_redaction(
                         response.as_bytes(),
+                        &[],
                         BodyRedactionMode::Disabled,
+                        ContentTypes::JSON,
                     )
                     .unwrap_err();
                     assert!(matches!(
@@ -573,7 +703,9 @@ mod tests {
                         + "{\"\0\0\0\0\0\0\": []}";
                     let err = parse_response_and_validate_redaction(
                         response.as_bytes(),
+                        &[],
                         BodyRedactionMode::Disabled,
+                        ContentTypes::JSON,
                     )
                     .unwrap_err();
                     assert!(matches!(
@@ -590,7 +722,9 @@ mod tests {
                         + "\r\n";
                     let err = parse_response_and_validate_redaction(
                         response.as_bytes(),
+                        &[],
                         BodyRedactionMode::Disabled,
+                        ContentTypes::JSON,
                     )
                     .unwrap_err();
 
@@ -600,13 +734,86 @@ mod tests {
                     ));
                 }
 
+                #[test]
+                fn content_type_not_allowed() {
+                    let response = "".to_string()
+                        + "HTTP/1.1 200 OK\r\n"
+                        + "Content-Type: text/html\r\n"
+                        + "\r\n"
+                        + "<p>Hello</p>";
+                    let err = parse_response_and_validate_redaction(
+                        response.as_bytes(),
+                        &[],
+                        BodyRedactionMode::Disabled,
+                        ContentTypes::only(ContentType::Text),
+                    )
+                    .unwrap_err();
+
+                    assert!(matches!(
+                        err,
+                        ParsingError::InvalidContentType(err_string) if err_string == "text/html"
+                    ));
+                }
+
+                #[test]
+                fn missing_content_type_with_json_not_allowed() {
+                    let response = "".to_string() + "HTTP/1.1 200 OK\r\n" + "\r\n" + "Hello";
+                    let err = parse_response_and_validate_redaction(
+                        response.as_bytes(),
+                        &[],
+                        BodyRedactionMode::Disabled,
+                        ContentTypes::only(ContentType::Text),
+                    )
+                    .unwrap_err();
+
+                    assert_eq!(err, ParsingError::MissingResponseHeader(CONTENT_TYPE.to_string()));
+                }
+
+                #[test]
+                fn redacted_content_type_with_json_not_allowed() {
+                    let response = "".to_string()
+                        + "HTTP/1.1 200 OK\r\n"
+                        + "Content-Type: \0\0\0\0\0\0\0\0\0\0\r\n"
+                        + "\r\n"
+                        + "Hello";
+                    let err = parse_response_and_validate_redaction(
+                        response.as_bytes(),
+                        &[],
+                        BodyRedactionMode::Disabled,
+                        ContentTypes::only(ContentType::Text),
+                    )
+                    .unwrap_err();
+
+                    assert_eq!(err, ParsingError::MissingResponseHeader(CONTENT_TYPE.to_string()));
+                }
+
+                #[test]
+                fn redaction_in_non_json_body() {
+                    let response = "".to_string()
+                        + "HTTP/1.1 200 OK\r\n"
+                        + "Content-Type: text/plain\r\n"
+                        + "\r\n"
+                        + "Balance: \0\0\0";
+                    let err = parse_response_and_validate_redaction(
+                        response.as_bytes(),
+                        &[],
+                        BodyRedactionMode::EnabledUnsafe,
+                        ContentTypes::only(ContentType::Text),
+                    )
+                    .unwrap_err();
+
+                    assert_eq!(err, ParsingError::RedactionInNonJsonBody);
+                }
+
                 #[test]
                 fn invalid_content_type_charset_utf16() {
                     let response = b"HTTP/1.1 200 OK\r\nContent-Type: application/json; charset=UTF-16\r\n\r\n{}";
 
                     let err = parse_response_and_validate_redaction(
                         response,
+                        &[],
                         BodyRedactionMode::Disabled,
+                        ContentTypes::JSON,
                     )
                     .unwrap_err();
 
@@ -624,7 +831,9 @@ mod tests {
                         + "\r\n";
                     let err = parse_response_and_validate_redaction(
                         response.as_bytes(),
+                        &[],
                         BodyRedactionMode::Disabled,
+                        ContentTypes::JSON,
                     )
                     .unwrap_err();
 
@@ -653,7 +862,9 @@ mod tests {
                         + "}";
                     let err = parse_response_and_validate_redaction(
                         response.as_bytes(),
+                        &[],
                         BodyRedactionMode::Disabled,
+                        ContentTypes::JSON,
                     )
                     .unwrap_err();
                     assert_eq!(err, ParsingError::RedactionInResponseBody);
@@ -662,6 +873,206 @@ mod tests {
         }
     }
 
+    mod content_type {
+        use super::*;
+
+        #[test]
+        fn html_body() {
+            let response = "".to_string()
+                + "HTTP/1.1 200 OK\r\n"
+                + "Content-Type: text/html; charset=utf-8\r\n"
+                + "\r\n"
+                + "<html><body><p>Hello</p></body></html>";
+            let body = parse_response_and_validate_redaction(
+                response.as_bytes(),
+                &[],
+                BodyRedactionMode::Disabled,
+                ContentTypes::JSON.with(ContentType::Html),
+            )
+            .unwrap()
+            .body;
+
+            assert_eq!(body, "<html><body><p>Hello</p></body></html>");
+        }
+
+        #[test]
+        fn plain_text_body() {
+            let response = "".to_string()
+                + "HTTP/1.1 200 OK\r\n"
+                + "Content-Type: text/plain\r\n"
+                + "\r\n"
+                + "Hello, world!";
+            let body = parse_response_and_validate_redaction(
+                response.as_bytes(),
+                &[],
+                BodyRedactionMode::Disabled,
+                ContentTypes::only(ContentType::Text),
+            )
+            .unwrap()
+            .body;
+
+            assert_eq!(body, "Hello, world!");
+        }
+
+        #[test]
+        fn form_url_encoded_body() {
+            let response = "".to_string()
+                + "HTTP/1.1 200 OK\r\n"
+                + "Content-Type: application/x-www-form-urlencoded\r\n"
+                + "\r\n"
+                + "name=Alice&age=30";
+            let body = parse_response_and_validate_redaction(
+                response.as_bytes(),
+                &[],
+                BodyRedactionMode::Disabled,
+                ContentTypes::only(ContentType::FormUrlEncoded),
+            )
+            .unwrap()
+            .body;
+
+            assert_eq!(body, "name=Alice&age=30");
+        }
+    }
+
+    mod content_encoding {
+        use std::io::Write;
+
+        use flate2::{Compression, write::GzEncoder};
+
+        use super::*;
+
+        const BODY: &str = "{\"name\":\"Gandalf\"}";
+
+        fn gzip_response() -> Vec<u8> {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(BODY.as_bytes()).unwrap();
+            let compressed = encoder.finish().unwrap();
+
+            let head = "".to_string()
+                + "HTTP/1.1 200 OK\r\n"
+                + "Content-Type: application/json\r\n"
+                + "Content-Encoding: gzip\r\n"
+                + &format!("Content-Length: {}\r\n", compressed.len())
+                + "\r\n";
+            [head.as_bytes(), &compressed].concat()
+        }
+
+        #[test]
+        fn gzip_body() {
+            let body = parse_response_and_validate_redaction(
+                &gzip_response(),
+                &[],
+                BodyRedactionMode::Disabled,
+                ContentTypes::JSON,
+            )
+            .unwrap()
+            .body;
+
+            assert_eq!(body, BODY);
+        }
+
+        #[test]
+        fn redacted_gzip_body() {
+            let response = gzip_response();
+            let err = parse_response_and_validate_redaction(
+                &response,
+                &[response.len() - 4..response.len()],
+                BodyRedactionMode::EnabledUnsafe,
+                ContentTypes::JSON,
+            )
+            .unwrap_err();
+
+            assert_eq!(err, ParsingError::RedactionInCompressedBody);
+        }
+
+        #[test]
+        fn redaction_outside_of_gzip_body() {
+            let response = gzip_response();
+            let body = parse_response_and_validate_redaction(
+                &response,
+                &[0..4],
+                BodyRedactionMode::Disabled,
+                ContentTypes::JSON,
+            )
+            .unwrap()
+            .body;
+
+            assert_eq!(body, BODY);
+        }
+
+        #[test]
+        fn invalid_json_in_gzip_body() {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(b"{\"name\":").unwrap();
+            let compressed = encoder.finish().unwrap();
+            let head = "".to_string()
+                + "HTTP/1.1 200 OK\r\n"
+                + "Content-Type: application/json\r\n"
+                + "Content-Encoding: gzip\r\n"
+                + "\r\n";
+            let response = [head.as_bytes(), &compressed].concat();
+
+            let err = parse_response_and_validate_redaction(
+                &response,
+                &[],
+                BodyRedactionMode::Disabled,
+                ContentTypes::JSON,
+            )
+            .unwrap_err();
+
+            assert!(matches!(err, ParsingError::Json(_)));
+        }
+
+        #[test]
+        fn unsupported_encoding() {
+            let response = "".to_string()
+                + "HTTP/1.1 200 OK\r\n"
+                + "Content-Encoding: zstd\r\n"
+                + "\r\n"
+                + "{}";
+            let err = parse_response_and_validate_redaction(
+                response.as_bytes(),
+                &[],
+                BodyRedactionMode::Disabled,
+                ContentTypes::JSON,
+            )
+            .unwrap_err();
+
+            assert_eq!(err, ParsingError::UnsupportedContentEncoding("zstd".to_string()));
+        }
+    }
+
+    mod status_and_headers {
+        use super::*;
+
+        #[test]
+        fn returns_status_and_unredacted_headers() {
+            let response = "".to_string()
+                + "HTTP/1.1 404 Not Found\r\n"
+                + "Content-Type: application/json\r\n"
+                + "ETag: \"33a64df5\"\r\n"
+                + "Set-Cookie: \0\0\0\0\0\0\0\0\r\n"
+                + "\r\n"
+                + "{}";
+            let parsed = parse_response_and_validate_redaction(
+                response.as_bytes(),
+                &[],
+                BodyRedactionMode::Disabled,
+                ContentTypes::JSON,
+            )
+            .unwrap();
+
+            assert_eq!(parsed.status, 404);
+            assert_eq!(
+                parsed.headers,
+                vec![
+                    ("Content-Type".to_string(), "application/json".to_string()),
+                    ("ETag".to_string(), "\"33a64df5\"".to_string()),
+                ]
+            );
+        }
+    }
+
     fn trim_start(input: &str) -> String {
         input
             .lines()
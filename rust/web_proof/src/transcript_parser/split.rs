@@ -0,0 +1,266 @@
+use std::ops::Range;
+
+use httparse::{EMPTY_HEADER, Header, Request, Response, Status, parse_chunk_size};
+
+use super::{MAX_HEADERS_NUMBER, replace_redacted_bytes};
+use crate::{errors::ParsingError, redaction::REDACTION_REPLACEMENT_CHAR_PRIMARY};
+
+const CONTENT_LENGTH: &str = "Content-Length";
+const TRANSFER_ENCODING: &str = "Transfer-Encoding";
+
+enum BodyLength {
+    Fixed(usize),
+    Chunked,
+    UntilEnd,
+}
+
+/// Splits a transcript of sent data into consecutive HTTP requests.
+pub(crate) fn split_requests(transcript: &[u8]) -> Result<Vec<Range<usize>>, ParsingError> {
+    split_messages(transcript, request_head)
+}
+
+/// Splits a transcript of received data into consecutive HTTP responses.
+pub(crate) fn split_responses(transcript: &[u8]) -> Result<Vec<Range<usize>>, ParsingError> {
+    split_messages(transcript, response_head)
+}
+
+// Redacted bytes are replaced so that message heads stay parsable. Message boundaries are derived
+// from framing headers and chunk sizes only, so redaction elsewhere doesn't affect the split.
+fn split_messages(
+    transcript: &[u8],
+    parse_head: fn(&[u8]) -> Result<(usize, BodyLength), ParsingError>,
+) -> Result<Vec<Range<usize>>, ParsingError> {
+    let transcript = replace_redacted_bytes(transcript, REDACTION_REPLACEMENT_CHAR_PRIMARY);
+    let mut messages = Vec::new();
+    let mut start = 0;
+    loop {
+        let (head_length, body_length) = parse_head(&transcript[start..])?;
+        let body_start = start + head_length;
+        let end = match body_length {
+            BodyLength::Fixed(length) => body_start
+                .checked_add(length)
+                .ok_or_else(|| ParsingError::InvalidContentLength(length.to_string()))?,
+            BodyLength::Chunked => body_start + chunked_body_length(&transcript[body_start..])?,
+            BodyLength::UntilEnd => transcript.len(),
+        };
+        if end > transcript.len() {
+            return Err(ParsingError::Partial);
+        }
+        messages.push(start..end);
+        start = end;
+        if start == transcript.len() {
+            return Ok(messages);
+        }
+    }
+}
+
+fn request_head(message: &[u8]) -> Result<(usize, BodyLength), ParsingError> {
+    let mut headers = [EMPTY_HEADER; MAX_HEADERS_NUMBER];
+    let mut request = Request::new(&mut headers);
+    let Status::Complete(head_length) = request.parse(message)? else {
+        return Err(ParsingError::Partial);
+    };
+    Ok((head_length, body_length(request.headers, BodyLength::Fixed(0))?))
+}
+
+fn response_head(message: &[u8]) -> Result<(usize, BodyLength), ParsingError> {
+    let mut headers = [EMPTY_HEADER; MAX_HEADERS_NUMBER];
+    let mut response = Response::new(&mut headers);
+    let Status::Complete(head_length) = response.parse(message)? else {
+        return Err(ParsingError::Partial);
+    };
+    // Responses without a body, as defined in RFC 9112, section 6.3
+    let body_length = match response.code {
+        Some(100..=199 | 204 | 304) => BodyLength::Fixed(0),
+        _ => body_length(response.headers, BodyLength::UntilEnd)?,
+    };
+    Ok((head_length, body_length))
+}
+
+fn body_length(headers: &[Header], default: BodyLength) -> Result<BodyLength, ParsingError> {
+    let find_header = |name: &str| {
+        headers
+            .iter()
+            .find(|header| header.name.eq_ignore_ascii_case(name))
+    };
+
+    if find_header(TRANSFER_ENCODING)
+        .is_some_and(|header| header.value.eq_ignore_ascii_case(b"chunked"))
+    {
+        return Ok(BodyLength::Chunked);
+    }
+    let Some(content_length) = find_header(CONTENT_LENGTH) else {
+        return Ok(default);
+    };
+    let content_length = String::from_utf8_lossy(content_length.value);
+    content_length
+        .trim()
+        .parse()
+        .map(BodyLength::Fixed)
+        .map_err(|_| ParsingError::InvalidContentLength(content_length.to_string()))
+}
+
+fn chunked_body_length(body: &[u8]) -> Result<usize, ParsingError> {
+    let mut position = 0;
+    loop {
+        let Status::Complete((size_line_length, chunk_size)) =
+            parse_chunk_size(&body[position..]).map_err(|_| ParsingError::InvalidChunkSize)?
+        else {
+            return Err(ParsingError::Partial);
+        };
+        position += size_line_length;
+        if chunk_size == 0 {
+            return Ok(position + trailer_length(&body[position..])?);
+        }
+        let chunk_size = usize::try_from(chunk_size).map_err(|_| ParsingError::InvalidChunkSize)?;
+        // Chunk data is followed by CRLF
+        position = chunk_size
+            .checked_add(2)
+            .and_then(|length| position.checked_add(length))
+            .ok_or(ParsingError::InvalidChunkSize)?;
+        if position > body.len() {
+            return Err(ParsingError::Partial);
+        }
+    }
+}
+
+// Trailer fields after the last chunk, terminated by an empty line
+fn trailer_length(trailer: &[u8]) -> Result<usize, ParsingError> {
+    let mut position = 0;
+    loop {
+        let line_length = trailer[position..]
+            .windows(2)
+            .position(|window| window == b"\r\n")
+            .ok_or(ParsingError::Partial)?;
+        position += line_length + 2;
+        if line_length == 0 {
+            return Ok(position);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split<'a>(
+        transcript: &'a [u8],
+        splitter: fn(&[u8]) -> Result<Vec<Range<usize>>, ParsingError>,
+    ) -> Vec<&'a [u8]> {
+        splitter(transcript)
+            .unwrap()
+            .into_iter()
+            .map(|range| &transcript[range])
+            .collect()
+    }
+
+    mod requests {
+        use super::*;
+
+        const LOGIN: &[u8] =
+            b"POST https://example.com/login HTTP/1.1\r\nContent-Length: 10\r\n\r\nuser=frodo";
+        const ACCOUNT: &[u8] =
+            b"GET https://example.com/account HTTP/1.1\r\nCookie: session=\0\0\0\0\r\n\r\n";
+
+        #[test]
+        fn single_request() {
+            assert_eq!(split(ACCOUNT, split_requests), vec![ACCOUNT]);
+        }
+
+        #[test]
+        fn requests_with_and_without_body() {
+            let transcript = [LOGIN, ACCOUNT].concat();
+
+            assert_eq!(split(&transcript, split_requests), vec![LOGIN, ACCOUNT]);
+        }
+
+        #[test]
+        fn empty_transcript() {
+            assert_eq!(split_requests(b"").unwrap_err(), ParsingError::Partial);
+        }
+
+        #[test]
+        fn body_shorter_than_content_length() {
+            let transcript =
+                b"POST https://example.com/login HTTP/1.1\r\nContent-Length: 20\r\n\r\nuser=frodo";
+
+            assert_eq!(split_requests(transcript).unwrap_err(), ParsingError::Partial);
+        }
+
+        #[test]
+        fn redacted_content_length() {
+            let transcript =
+                b"POST https://example.com/login HTTP/1.1\r\nContent-Length: \0\r\n\r\nuser=frodo";
+
+            assert_eq!(
+                split_requests(transcript).unwrap_err(),
+                ParsingError::InvalidContentLength("*".to_string())
+            );
+        }
+
+        #[test]
+        fn content_length_overflow() {
+            let transcript = b"POST https://example.com/login HTTP/1.1\r\nContent-Length: 18446744073709551615\r\n\r\nuser=frodo";
+
+            assert_eq!(
+                split_requests(transcript).unwrap_err(),
+                ParsingError::InvalidContentLength("18446744073709551615".to_string())
+            );
+        }
+    }
+
+    mod responses {
+        use super::*;
+
+        const NO_CONTENT: &[u8] =
+            b"HTTP/1.1 204 No Content\r\nSet-Cookie: \0\0\0\0\0\0\0\0\0\0\0\0\r\n\r\n";
+        const FIXED: &[u8] = b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\n{}";
+        const CHUNKED: &[u8] = b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n4\r\n{\"a\"\r\n3\r\n:1}\r\n0\r\n\r\n";
+        const UNTIL_END: &[u8] = b"HTTP/1.1 200 OK\r\n\r\n{\"a\":1}";
+
+        #[test]
+        fn single_response() {
+            assert_eq!(split(UNTIL_END, split_responses), vec![UNTIL_END]);
+        }
+
+        #[test]
+        fn many_responses() {
+            let transcript = [NO_CONTENT, FIXED, CHUNKED, UNTIL_END].concat();
+
+            assert_eq!(
+                split(&transcript, split_responses),
+                vec![NO_CONTENT, FIXED, CHUNKED, UNTIL_END]
+            );
+        }
+
+        #[test]
+        fn chunked_response_with_trailer() {
+            let chunked = b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n2\r\n{}\r\n0\r\nExpires: never\r\n\r\n";
+            let transcript = [&chunked[..], FIXED].concat();
+
+            assert_eq!(split(&transcript, split_responses), vec![&chunked[..], FIXED]);
+        }
+
+        #[test]
+        fn redacted_chunk_size() {
+            let transcript =
+                b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n\0\r\n{}\r\n0\r\n\r\n";
+
+            assert_eq!(split_responses(transcript).unwrap_err(), ParsingError::InvalidChunkSize);
+        }
+
+        #[test]
+        fn chunk_size_overflow() {
+            let transcript = b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\nffffffffffffffff\r\n{}\r\n0\r\n\r\n";
+
+            assert_eq!(split_responses(transcript).unwrap_err(), ParsingError::InvalidChunkSize);
+        }
+
+        #[test]
+        fn incomplete_chunked_body() {
+            let transcript = b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n2\r\n{}\r\n";
+
+            assert_eq!(split_responses(transcript).unwrap_err(), ParsingError::Partial);
+        }
+    }
+}
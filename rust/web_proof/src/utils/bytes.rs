@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 pub(crate) fn replace_bytes(input: &[u8], from: u8, to: u8) -> Vec<u8> {
     input
         .iter()
@@ -9,6 +11,23 @@ pub(crate) fn all_match(input: &[u8], target: u8) -> bool {
     input.iter().all(|&c| c == target)
 }
 
+/// Returns the ranges of positions at which the inputs differ. Both inputs must have the same length.
+pub(crate) fn diff_ranges(left: &[u8], right: &[u8]) -> Vec<Range<usize>> {
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    for (index, _) in left
+        .iter()
+        .zip(right)
+        .enumerate()
+        .filter(|(_, (l, r))| l != r)
+    {
+        match ranges.last_mut() {
+            Some(last) if last.end == index => last.end += 1,
+            _ => ranges.push(index..index + 1),
+        }
+    }
+    ranges
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -34,6 +53,18 @@ mod tests {
         assert_eq!(replace_bytes(&[0, 1, 0, 2], 0, 255), &[255, 1, 255, 2]);
     }
 
+    #[test]
+    fn test_diff_ranges() {
+        // Identical inputs
+        assert_eq!(diff_ranges(b"abcd", b"abcd"), vec![]);
+
+        // Adjacent differences are merged
+        assert_eq!(diff_ranges(b"abcdef", b"aXXdeX"), vec![1..3, 5..6]);
+
+        // Everything differs
+        assert_eq!(diff_ranges(&[0, 0], &[255, 255]), vec![0..2]);
+    }
+
     #[test]
     fn test_all_match() {
         // All elements match
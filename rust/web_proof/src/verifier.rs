@@ -1,5 +1,5 @@
 use k256::PublicKey;
-use pkcs8::{EncodePublicKey, LineEnding};
+use pkcs8::{DecodePublicKey, EncodePublicKey, LineEnding};
 use thiserror::Error;
 use tlsn_core::signing::VerifyingKey;
 use url::{ParseError, Url};
@@ -24,9 +24,15 @@ pub enum WebProofError {
     #[error("No host found in the URL")]
     NoHostFoundInUrl,
 
+    #[error("Web proof contains {0} requests but {1} responses")]
+    ExchangeCountMismatch(usize, usize),
+
     #[error("Host name extracted from url: {0} is different from server name: {1}")]
     HostNameMismatch(String, String),
 
+    #[error("Notary public key is not trusted: {0}")]
+    UntrustedNotary(String),
+
     #[error("Public key conversion from sec1 format error: {0}")]
     ConversionFromSec1Format(#[from] k256::elliptic_curve::Error),
 
@@ -34,19 +40,76 @@ pub enum WebProofError {
     ConversionToPemFormat(#[from] pkcs8::spki::Error),
 }
 
-pub fn verify_and_parse(web_proof: WebProof, config: Config) -> Result<Web, WebProofError> {
-    let (request, response, server_name, notary_pub_key) = web_proof.verify()?;
+pub fn verify_and_parse(web_proof: WebProof, config: Config) -> Result<Vec<Web>, WebProofError> {
+    let (request, response, server_name, notary_pub_key, notarization_time) = web_proof.verify()?;
 
-    let web = Web {
-        url: request.parse_url(config.url_test_mode)?,
-        server_name: server_name.to_string(),
-        body: response.parse_body(config.body_redaction_mode)?,
-        notary_pub_key: to_pem_format(&notary_pub_key)?,
-    };
+    let requests = request.split()?;
+    let responses = response.split()?;
+    if requests.len() != responses.len() {
+        return Err(WebProofError::ExchangeCountMismatch(requests.len(), responses.len()));
+    }
+    let notary_pub_key = to_pem_format(&notary_pub_key)?;
+    if let Some(trusted_notary_keys) = &config.trusted_notary_keys {
+        verify_notary_trusted(&notary_pub_key, trusted_notary_keys)?;
+    }
+
+    requests
+        .into_iter()
+        .zip(responses)
+        .map(|(request, response)| {
+            let request = request.parse(config.url_test_mode)?;
+            let response =
+                response.parse(config.body_redaction_mode, config.allowed_content_types)?;
+
+            let web = Web {
+                url: request.url,
+                server_name: server_name.to_string(),
+                body: response.body,
+                notary_pub_key: notary_pub_key.clone(),
+                status: response.status,
+                method: request.method.to_string(),
+                headers: select_headers(&response.headers, &config.response_headers)?,
+                notarization_time,
+            };
+
+            verify_server_name(server_name.as_str(), &web.url)?;
 
-    verify_server_name(server_name.as_str(), &web.url)?;
+            Ok(web)
+        })
+        .collect()
+}
+
+fn select_headers(
+    headers: &[(String, String)],
+    names: &[String],
+) -> Result<Vec<(String, String)>, ParsingError> {
+    names
+        .iter()
+        .map(|name| {
+            headers
+                .iter()
+                .find(|(header_name, _)| header_name.eq_ignore_ascii_case(name))
+                .map(|(_, value)| (name.clone(), value.clone()))
+                .ok_or_else(|| ParsingError::MissingResponseHeader(name.clone()))
+        })
+        .collect()
+}
 
-    Ok(web)
+// Keys are compared after decoding, so differences in PEM formatting don't matter
+fn verify_notary_trusted(
+    notary_pub_key: &str,
+    trusted_notary_keys: &[String],
+) -> Result<(), WebProofError> {
+    let notary_key = PublicKey::from_public_key_pem(notary_pub_key)
+        .map_err(|_| WebProofError::UntrustedNotary(notary_pub_key.to_string()))?;
+    let is_trusted = trusted_notary_keys
+        .iter()
+        .any(|key| PublicKey::from_public_key_pem(key).is_ok_and(|key| key == notary_key));
+    if is_trusted {
+        Ok(())
+    } else {
+        Err(WebProofError::UntrustedNotary(notary_pub_key.to_string()))
+    }
 }
 
 fn verify_server_name(server_name: &str, url: &str) -> Result<(), WebProofError> {
@@ -78,7 +141,7 @@ mod tests {
     use crate::{
         fixtures::load_web_proof_fixture,
         redaction::RedactionElementType,
-        web_proof::{BodyRedactionMode, UrlTestMode},
+        web_proof::{BodyRedactionMode, ContentTypes, UrlTestMode},
     };
 
     const TEST_URL: &str =
@@ -87,16 +150,26 @@ mod tests {
     const CONFIG: Config = Config {
         body_redaction_mode: BodyRedactionMode::Disabled,
         url_test_mode: UrlTestMode::Full,
+        allowed_content_types: ContentTypes::JSON,
+        response_headers: vec![],
+        trusted_notary_keys: None,
     };
 
     mod verify_and_parse {
         use k256::PublicKey;
-        use pkcs8::DecodePublicKey;
         use serde_json::Value;
 
         use super::*;
         use crate::fixtures::{NOTARY_PUB_KEY_PEM_EXAMPLE, read_fixture};
 
+        fn verify_single_exchange(web_proof: WebProof, config: Config) -> Web {
+            let [web] = verify_and_parse(web_proof, config)
+                .unwrap()
+                .try_into()
+                .unwrap();
+            web
+        }
+
         const WEB_PROOF_IDENTITY_NAME_CHANGED: &str =
             include_str!(".././testdata/web_proof_identity_name_changed.json");
 
@@ -104,7 +177,7 @@ mod tests {
         fn correct_url_extracted() {
             let web_proof = load_web_proof_fixture();
 
-            let web = verify_and_parse(web_proof, CONFIG).unwrap();
+            let web = verify_single_exchange(web_proof, CONFIG);
 
             assert_eq!(web.url, TEST_URL);
         }
@@ -124,7 +197,7 @@ mod tests {
         fn correct_server_name_extracted() {
             let web_proof = load_web_proof_fixture();
 
-            let web = verify_and_parse(web_proof, CONFIG).unwrap();
+            let web = verify_single_exchange(web_proof, CONFIG);
 
             assert_eq!(web.server_name, "lotr-api.online");
         }
@@ -133,7 +206,7 @@ mod tests {
         fn correct_body_extracted() {
             let web_proof = load_web_proof_fixture();
 
-            let web = verify_and_parse(web_proof, CONFIG).unwrap();
+            let web = verify_single_exchange(web_proof, CONFIG);
 
             assert_eq!(
                 web.body,
@@ -144,7 +217,7 @@ mod tests {
         #[test]
         fn correct_notary_pub_key() {
             let web_proof = load_web_proof_fixture();
-            let web = verify_and_parse(web_proof, CONFIG).unwrap();
+            let web = verify_single_exchange(web_proof, CONFIG);
 
             assert_eq!(
                 PublicKey::from_public_key_pem(&web.notary_pub_key).unwrap(),
@@ -152,16 +225,93 @@ mod tests {
             );
         }
 
+        #[test]
+        fn correct_status_and_method() {
+            let web_proof = load_web_proof_fixture();
+            let web = verify_single_exchange(web_proof, CONFIG);
+
+            assert_eq!(web.status, 200);
+            assert_eq!(web.method, "GET");
+            assert!(web.headers.is_empty());
+        }
+
+        #[test]
+        fn selected_response_headers() {
+            let web_proof = load_web_proof_fixture();
+            let config = Config {
+                response_headers: vec!["content-type".to_string()],
+                ..CONFIG
+            };
+
+            let web = verify_single_exchange(web_proof, config);
+
+            let [(name, value)] = web.headers.as_slice() else {
+                panic!("expected a single header, got {:?}", web.headers);
+            };
+            assert_eq!(name, "content-type");
+            assert_eq!(value, "application/json;charset=utf-8");
+        }
+
+        #[test]
+        fn missing_response_header() {
+            let web_proof = load_web_proof_fixture();
+            let config = Config {
+                response_headers: vec!["X-Not-There".to_string()],
+                ..CONFIG
+            };
+
+            assert!(matches!(
+                verify_and_parse(web_proof, config).err().unwrap(),
+                WebProofError::Parsing(ParsingError::MissingResponseHeader(name)) if name == "X-Not-There"
+            ));
+        }
+
+        #[test]
+        fn trusted_notary() {
+            let config = Config {
+                trusted_notary_keys: Some(vec![NOTARY_PUB_KEY_PEM_EXAMPLE.to_string()]),
+                ..CONFIG
+            };
+
+            assert!(verify_and_parse(load_web_proof_fixture(), config).is_ok());
+        }
+
+        #[test]
+        fn untrusted_notary() {
+            let config = Config {
+                trusted_notary_keys: Some(vec![]),
+                ..CONFIG
+            };
+
+            assert!(matches!(
+                verify_and_parse(load_web_proof_fixture(), config).err().unwrap(),
+                WebProofError::UntrustedNotary(key) if key.starts_with("-----BEGIN PUBLIC KEY-----")
+            ));
+        }
+
+        #[test]
+        fn malformed_notary_key_is_untrusted() {
+            let trusted_notary_keys = vec![NOTARY_PUB_KEY_PEM_EXAMPLE.to_string()];
+
+            assert!(matches!(
+                verify_notary_trusted("not a key", &trusted_notary_keys).unwrap_err(),
+                WebProofError::UntrustedNotary(key) if key == "not a key"
+            ));
+        }
+
         #[test]
         fn success_all_redaction_turned_on() {
             let config = Config {
                 body_redaction_mode: BodyRedactionMode::EnabledUnsafe,
                 url_test_mode: UrlTestMode::Prefix,
+                allowed_content_types: ContentTypes::JSON,
+                response_headers: vec![],
+                trusted_notary_keys: None,
             };
             let web_proof = read_fixture("./testdata/web_proof_all_redaction_types.json");
             let web_proof: WebProof = serde_json::from_str(&web_proof).unwrap();
 
-            let web = verify_and_parse(web_proof, config).unwrap();
+            let web = verify_single_exchange(web_proof, config);
 
             let body = &web.body;
             let parsed: Value = serde_json::from_str(body).unwrap();
@@ -188,6 +338,9 @@ mod tests {
                 verify_and_parse(web_proof, Config {
                     body_redaction_mode: BodyRedactionMode::Disabled,
                     url_test_mode: UrlTestMode::Prefix,
+                    allowed_content_types: ContentTypes::JSON,
+                    response_headers: vec![],
+                    trusted_notary_keys: None,
                 }).err().unwrap(),
                 WebProofError::Parsing(ParsingError::PartiallyRedactedValue(RedactionElementType::RequestUrlParam, err)) if err == "param1: v*****"
             ),);
@@ -202,6 +355,9 @@ mod tests {
                 verify_and_parse(web_proof, Config {
                     body_redaction_mode: BodyRedactionMode::Disabled,
                     url_test_mode: UrlTestMode::Prefix,
+                    allowed_content_types: ContentTypes::JSON,
+                    response_headers: vec![],
+                    trusted_notary_keys: None,
                 }).err().unwrap(),
                 WebProofError::Parsing(ParsingError::PartiallyRedactedValue(RedactionElementType::RequestHeader, err)) if err == "connection: c****"
             ));
@@ -216,6 +372,9 @@ mod tests {
                 verify_and_parse(web_proof, Config {
                     body_redaction_mode: BodyRedactionMode::Disabled,
                     url_test_mode: UrlTestMode::Prefix,
+                    allowed_content_types: ContentTypes::JSON,
+                    response_headers: vec![],
+                    trusted_notary_keys: None,
                 }).err().unwrap(),
                 WebProofError::Parsing(ParsingError::PartiallyRedactedValue(RedactionElementType::ResponseHeader, err)) if err == "Date: ****************************T"
             ));
@@ -230,6 +389,9 @@ mod tests {
                 verify_and_parse(web_proof, Config {
                     body_redaction_mode: BodyRedactionMode::Disabled,
                     url_test_mode: UrlTestMode::Prefix,
+                    allowed_content_types: ContentTypes::JSON,
+                    response_headers: vec![],
+                    trusted_notary_keys: None,
                 }).err().unwrap(),
                 WebProofError::Parsing(ParsingError::PartiallyRedactedValue(RedactionElementType::ResponseBody, err)) if err == "$.name: T***********"
             ));
@@ -270,14 +432,13 @@ mod tests {
     }
 
     mod to_pem_format {
-        use pkcs8::DecodePublicKey;
 
         use super::*;
         use crate::fixtures::NOTARY_PUB_KEY_PEM_EXAMPLE;
 
         #[test]
         fn success() {
-            let (_, _, _, verifying_key) = load_web_proof_fixture().verify().unwrap();
+            let (_, _, _, verifying_key, _) = load_web_proof_fixture().verify().unwrap();
 
             let pem = to_pem_format(&verifying_key).unwrap();
 
@@ -6,42 +6,89 @@ pub struct Web {
     pub server_name: String,
     pub body: String,
     pub notary_pub_key: String,
+    /// Such as `200`.
+    pub status: u16,
+    /// Such as `GET`.
+    pub method: String,
+    /// Response headers selected in [`crate::web_proof::Config::response_headers`], in the same order.
+    pub headers: Vec<(String, String)>,
+    /// UNIX timestamp (in seconds) of the notarized TLS connection.
+    pub notarization_time: u64,
 }
 
 impl Web {
     pub fn abi_encode(self) -> Vec<u8> {
-        let data = DynSolValue::FixedArray(vec![
-            self.url.into(),
-            self.server_name.into(),
-            self.body.into(),
-            self.notary_pub_key.into(),
-        ]);
-        data.abi_encode()
+        DynSolValue::from(self).abi_encode()
+    }
+}
+
+/// Encodes all exchanges of a web proof as a single dynamic array, in transcript order.
+pub fn abi_encode_exchanges(webs: Vec<Web>) -> Vec<u8> {
+    DynSolValue::Array(webs.into_iter().map(Into::into).collect()).abi_encode()
+}
+
+impl From<Web> for DynSolValue {
+    fn from(web: Web) -> Self {
+        let headers = web
+            .headers
+            .into_iter()
+            .map(|(name, value)| DynSolValue::Tuple(vec![name.into(), value.into()]))
+            .collect();
+        DynSolValue::Tuple(vec![
+            web.url.into(),
+            web.server_name.into(),
+            web.body.into(),
+            web.notary_pub_key.into(),
+            web.status.into(),
+            web.method.into(),
+            DynSolValue::Array(headers),
+            web.notarization_time.into(),
+        ])
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use alloy_primitives::hex;
+    use alloy_primitives::{U256, hex};
 
     use super::*;
     use crate::fixtures::NOTARY_PUB_KEY_PEM_EXAMPLE;
 
     #[test]
     fn test_abi_encoding() {
-        // generated with `cast abi-encode "test(string[4])" "[https://api.x.com/1.1/account/settings.json,api.x.com,body,'-----BEGIN PUBLIC KEY-----
+        // generated with `cast abi-encode "test((string,string,string,string,uint16,string,(string,string)[],uint64))" "(https://api.x.com/1.1/account/settings.json,api.x.com,body,'-----BEGIN PUBLIC KEY-----
         // MDYwEAYHKoZIzj0CAQYFK4EEAAoDIgADe0jxnBObaIj7Xjg6TXLCM1GG/VhY5650
         // OrS/jgcbBuc=
-        // -----END PUBLIC KEY-----']"`
-        let expected_encoding: Vec<u8> = hex!("0x0000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000e000000000000000000000000000000000000000000000000000000000000001200000000000000000000000000000000000000000000000000000000000000160000000000000000000000000000000000000000000000000000000000000002b68747470733a2f2f6170692e782e636f6d2f312e312f6163636f756e742f73657474696e67732e6a736f6e00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096170692e782e636f6d00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004626f64790000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000812d2d2d2d2d424547494e205055424c4943204b45592d2d2d2d2d0a4d445977454159484b6f5a497a6a3043415159464b34454541416f444967414465306a786e424f6261496a37586a673654584c434d3147472f566859353635300a4f72532f6a6763624275633d0a2d2d2d2d2d454e44205055424c4943204b45592d2d2d2d2d00000000000000000000000000000000000000000000000000000000000000").to_vec();
+        // -----END PUBLIC KEY-----',200,GET,[(Date,'Tue, 01 Jul 2025 12:00:00 GMT')],1751371200)"`
+        let expected_encoding: Vec<u8> = hex!("0x00000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000016000000000000000000000000000000000000000000000000000000000000001a000000000000000000000000000000000000000000000000000000000000001e000000000000000000000000000000000000000000000000000000000000000c800000000000000000000000000000000000000000000000000000000000002a000000000000000000000000000000000000000000000000000000000000002e0000000000000000000000000000000000000000000000000000000006863cdc0000000000000000000000000000000000000000000000000000000000000002b68747470733a2f2f6170692e782e636f6d2f312e312f6163636f756e742f73657474696e67732e6a736f6e00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096170692e782e636f6d00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004626f64790000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000812d2d2d2d2d424547494e205055424c4943204b45592d2d2d2d2d0a4d445977454159484b6f5a497a6a3043415159464b34454541416f444967414465306a786e424f6261496a37586a673654584c434d3147472f566859353635300a4f72532f6a6763624275633d0a2d2d2d2d2d454e44205055424c4943204b45592d2d2d2d2d0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000034745540000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000044461746500000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001d5475652c203031204a756c20323032352031323a30303a303020474d54000000").to_vec();
+
+        let web = test_web();
 
-        let web = Web {
+        assert_eq!(expected_encoding, web.abi_encode());
+    }
+
+    #[test]
+    fn test_abi_encoding_exchanges() {
+        let single = test_web().abi_encode();
+        // Array offset and length, followed by the offset of the only element
+        let mut expected_encoding = [0x20, 1, 0x20]
+            .map(|word| U256::from(word).to_be_bytes::<32>())
+            .concat();
+        expected_encoding.extend_from_slice(&single[32..]);
+
+        assert_eq!(expected_encoding, abi_encode_exchanges(vec![test_web()]));
+    }
+
+    fn test_web() -> Web {
+        Web {
             url: "https://api.x.com/1.1/account/settings.json".to_string(),
             server_name: "api.x.com".to_string(),
             body: "body".to_string(),
             notary_pub_key: NOTARY_PUB_KEY_PEM_EXAMPLE.to_string(),
-        };
-
-        assert_eq!(expected_encoding, web.abi_encode());
+            status: 200,
+            method: "GET".to_string(),
+            headers: vec![("Date".to_string(), "Tue, 01 Jul 2025 12:00:00 GMT".to_string())],
+            notarization_time: 1_751_371_200,
+        }
     }
 }
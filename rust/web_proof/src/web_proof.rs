@@ -1,5 +1,6 @@
 use std::convert::TryFrom;
 
+use mime::Mime;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use tlsn_core::{
@@ -9,7 +10,10 @@ use tlsn_core::{
     signing::VerifyingKey,
 };
 
-use crate::{request_transcript::RequestTranscript, response_transcript::ResponseTranscript};
+use crate::{
+    request_transcript::RequestTranscript, response_transcript::ResponseTranscript,
+    utils::bytes::diff_ranges,
+};
 
 #[derive(Deserialize, Serialize, PartialEq, Debug)]
 #[serde(deny_unknown_fields)]
@@ -27,10 +31,14 @@ pub struct PresentationJSON {
 }
 
 impl WebProof {
+    /// Besides the transcripts, server name and notary key, returns the time of the TLS connection
+    /// (UNIX timestamp in seconds) as attested by the notary.
     pub(crate) fn verify(
         self,
-    ) -> Result<(RequestTranscript, ResponseTranscript, ServerName, VerifyingKey), VerificationError>
-    {
+    ) -> Result<
+        (RequestTranscript, ResponseTranscript, ServerName, VerifyingKey, u64),
+        VerificationError,
+    > {
         let provider = CryptoProvider::default();
 
         let presentation = Presentation::try_from(self)?;
@@ -39,16 +47,25 @@ impl WebProof {
         let PresentationOutput {
             transcript,
             server_name,
+            connection_info,
             ..
         } = presentation.verify(&provider)?;
 
-        let transcript = transcript.ok_or(VerificationError::EmptyTranscript)?;
+        let mut transcript = transcript.ok_or(VerificationError::EmptyTranscript)?;
+
+        // Unauthenticated bytes are zeroed. Filling them with a different value tells them apart from
+        // genuine zero bytes.
+        let sent = transcript.sent_unsafe().to_vec();
+        let received = transcript.received_unsafe().to_vec();
+        transcript.set_unauthed(u8::MAX);
+        let received_redacted_ranges = diff_ranges(&received, transcript.received_unsafe());
 
         Ok((
-            RequestTranscript::new(transcript.sent_unsafe().to_vec()),
-            ResponseTranscript::new(transcript.received_unsafe().to_vec()),
+            RequestTranscript::new(sent),
+            ResponseTranscript::new(received, received_redacted_ranges),
             server_name.ok_or(VerificationError::NoServerName)?,
             verifying_key,
+            connection_info.time,
         ))
     }
 }
@@ -65,22 +82,106 @@ pub enum BodyRedactionMode {
     EnabledUnsafe,
 }
 
+/// Content type of a response body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentType {
+    Json,
+    Html,
+    Text,
+    FormUrlEncoded,
+}
+
+impl ContentType {
+    pub(crate) fn from_mime(mime: &Mime) -> Option<Self> {
+        match (mime.type_().as_str(), mime.subtype().as_str()) {
+            ("application", "json") => Some(Self::Json),
+            ("text", "html") => Some(Self::Html),
+            ("text", "plain") => Some(Self::Text),
+            ("application", "x-www-form-urlencoded") => Some(Self::FormUrlEncoded),
+            _ => None,
+        }
+    }
+
+    const fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// Allow-list of response content types. Only JSON is allowed by default, and collecting an empty
+/// list gives the default too.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContentTypes(u8);
+
+impl ContentTypes {
+    pub const JSON: Self = Self::only(ContentType::Json);
+    pub const ALL: Self = Self::JSON
+        .with(ContentType::Html)
+        .with(ContentType::Text)
+        .with(ContentType::FormUrlEncoded);
+
+    pub const fn only(content_type: ContentType) -> Self {
+        Self(content_type.bit())
+    }
+
+    #[must_use]
+    pub const fn with(self, content_type: ContentType) -> Self {
+        Self(self.0 | content_type.bit())
+    }
+
+    pub const fn contains(self, content_type: ContentType) -> bool {
+        self.0 & content_type.bit() != 0
+    }
+}
+
+impl Default for ContentTypes {
+    fn default() -> Self {
+        Self::JSON
+    }
+}
+
+impl FromIterator<ContentType> for ContentTypes {
+    fn from_iter<I: IntoIterator<Item = ContentType>>(iter: I) -> Self {
+        match iter.into_iter().fold(Self(0), Self::with) {
+            Self(0) => Self::default(),
+            content_types => content_types,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Config {
     pub body_redaction_mode: BodyRedactionMode,
     pub url_test_mode: UrlTestMode,
+    pub allowed_content_types: ContentTypes,
+    /// Names of the response headers to expose. Matched case-insensitively; each one must be
+    /// present and not redacted.
+    pub response_headers: Vec<String>,
+    /// PEM-encoded public keys of the notaries whose proofs are accepted. `None` accepts any
+    /// notary, leaving the check to the Prover contract.
+    pub trusted_notary_keys: Option<Vec<String>>,
 }
 
 impl Config {
     pub fn new(
         body_redaction_mode: impl Into<BodyRedactionMode>,
         url_test_mode: impl Into<UrlTestMode>,
+        allowed_content_types: impl Into<ContentTypes>,
+        response_headers: Vec<String>,
     ) -> Self {
         Self {
             body_redaction_mode: body_redaction_mode.into(),
             url_test_mode: url_test_mode.into(),
+            allowed_content_types: allowed_content_types.into(),
+            response_headers,
+            trusted_notary_keys: None,
         }
     }
+
+    #[must_use]
+    pub fn with_trusted_notary_keys(mut self, trusted_notary_keys: Option<Vec<String>>) -> Self {
+        self.trusted_notary_keys = trusted_notary_keys;
+        self
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
@@ -166,7 +267,7 @@ mod tests {
     #[test]
     fn success_verification() {
         let proof = load_web_proof_fixture();
-        let (request, response, _, _) = proof.verify().unwrap();
+        let (request, response, _, _, _) = proof.verify().unwrap();
 
         assert_snapshot!("sent_request", String::from_utf8(request.transcript).unwrap());
         assert_snapshot!("received_response", String::from_utf8(response.transcript).unwrap());
@@ -175,20 +276,69 @@ mod tests {
     #[test]
     fn success_get_server_name() {
         let proof = load_web_proof_fixture();
-        let (_, _, server_name, _) = proof.verify().unwrap();
+        let (_, _, server_name, _, _) = proof.verify().unwrap();
         assert_eq!(server_name.as_str(), "lotr-api.online");
     }
 
+    #[test]
+    fn success_get_notarization_time() {
+        // `Date` header of the response in the fixture: Wed, 11 Jun 2025 14:24:07 GMT
+        const RESPONSE_DATE: u64 = 1_749_651_847;
+
+        let proof = load_web_proof_fixture();
+        let (_, _, _, _, notarization_time) = proof.verify().unwrap();
+        assert!(notarization_time.abs_diff(RESPONSE_DATE) < 60);
+    }
+
     #[test]
     fn success_get_notary_verifying_key() {
         let proof = load_web_proof_fixture();
-        let (_, _, _, verifying_key) = proof.verify().unwrap();
+        let (_, _, _, verifying_key, _) = proof.verify().unwrap();
         assert_eq!(
             PublicKey::from_sec1_bytes(verifying_key.data.as_ref()).unwrap(),
             PublicKey::from_public_key_pem(NOTARY_PUB_KEY_PEM_EXAMPLE).unwrap()
         );
     }
 
+    mod content_types {
+        use super::*;
+
+        #[test]
+        fn json_only_by_default() {
+            let allowed = ContentTypes::default();
+
+            assert!(allowed.contains(ContentType::Json));
+            assert!(!allowed.contains(ContentType::Html));
+        }
+
+        #[test]
+        fn collects_from_iterator() {
+            let allowed: ContentTypes =
+                [ContentType::Html, ContentType::Text].into_iter().collect();
+
+            assert!(allowed.contains(ContentType::Html));
+            assert!(allowed.contains(ContentType::Text));
+            assert!(!allowed.contains(ContentType::Json));
+            assert!(!allowed.contains(ContentType::FormUrlEncoded));
+        }
+
+        #[test]
+        fn empty_list_is_default() {
+            let allowed: ContentTypes = std::iter::empty().collect();
+
+            assert_eq!(allowed, ContentTypes::default());
+        }
+
+        #[test]
+        fn content_type_from_mime() {
+            let html: Mime = "text/html; charset=utf-8".parse().unwrap();
+            let xml: Mime = "application/xml".parse().unwrap();
+
+            assert_eq!(ContentType::from_mime(&html), Some(ContentType::Html));
+            assert_eq!(ContentType::from_mime(&xml), None);
+        }
+    }
+
     #[test]
     fn deserialize_presentation() {
         let web_proof: WebProof = load_web_proof_fixture();
@@ -11,17 +11,20 @@ const SMALL_EMAIL: &str = include_str!(concat!("../../../assets/email.eml"));
 fn fixture() -> Bytes {
     let email: UnverifiedEmail = UnverifiedEmail {
         email: SMALL_EMAIL.to_string(),
-        dnsRecord: SolDnsRecord {
+        dnsRecords: vec![SolDnsRecord {
             name: "google._domainkey.vlayer.xyz".into(),
             recordType: 16,
             data: DNS_RECORD.into(),
             ttl: 0,
-        },
-        verificationData: SolVerificationData {
+        }],
+        verificationData: vec![SolVerificationData {
             validUntil: 0,
             signature: Default::default(),
             pubKey: Default::default(),
-        },
+            keyId: Default::default(),
+            dnssecProof: Default::default(),
+        }],
+        requiredSignedHeaders: vec![],
     };
 
     email.abi_encode().into()